@@ -111,7 +111,7 @@ where
     let input = read_input(&input)?;
     let reader = metadata::Reader::filter(input, &include, &exclude, &config);
 
-    winmd::verify(reader)?;
+    let warnings = winmd::verify(reader, &mut config)?;
 
     match extension(&output) {
         "rdl" => rdl::from_reader(reader, config, &output)?,
@@ -120,13 +120,21 @@ where
         _ => return Err(Error::new("output extension must be one of winmd/rdl/rs")),
     }
 
+    let mut message = String::new();
+
+    for warning in warnings {
+        message.push_str(&format!("  warning: {warning}\n"));
+    }
+
     let elapsed = time.elapsed().as_secs_f32();
 
     if elapsed > 0.1 {
-        Ok(format!("  Finished writing `{}` in {:.2}s", output, time.elapsed().as_secs_f32()))
+        message.push_str(&format!("  Finished writing `{}` in {:.2}s", output, time.elapsed().as_secs_f32()));
     } else {
-        Ok(format!("  Finished writing `{}`", output,))
+        message.push_str(&format!("  Finished writing `{}`", output,));
     }
+
+    Ok(message)
 }
 
 fn filter_input(input: &[&str], extensions: &[&str]) -> Result<Vec<String>> {
@@ -222,7 +230,7 @@ fn read_rdl_file(path: &str) -> Result<metadata::File> {
 }
 
 fn read_winmd_file(path: &str) -> Result<metadata::File> {
-    read_file_bytes(path).and_then(|bytes| metadata::File::new(bytes).ok_or_else(|| Error::new("failed to read .winmd format").with_path(path)))
+    read_file_bytes(path).and_then(|bytes| metadata::File::new(bytes).map_err(|error| Error::new(&format!("failed to read .winmd format: {error}")).with_path(path)))
 }
 
 fn write_to_file<C: AsRef<[u8]>>(path: &str, contents: C) -> Result<()> {
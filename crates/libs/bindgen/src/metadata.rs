@@ -1,6 +1,19 @@
 use std::collections::*;
 pub use windows_metadata::*;
 
+// The code generators read from process-wide readers created by `Reader::filter`, so everything they read is `'static`.
+pub type Attribute = windows_metadata::Attribute<'static>;
+pub type Field = windows_metadata::Field<'static>;
+pub type HasAttribute = windows_metadata::HasAttribute<'static>;
+pub type Item = windows_metadata::Item<'static>;
+pub type MethodDef = windows_metadata::MethodDef<'static>;
+pub type MethodDefSig = windows_metadata::MethodDefSig<'static>;
+pub type Param = windows_metadata::Param<'static>;
+pub type Type = windows_metadata::Type<'static>;
+pub type TypeDef = windows_metadata::TypeDef<'static>;
+pub type TypeName = windows_metadata::TypeName<'static>;
+pub type Value = windows_metadata::Value<'static>;
+
 #[derive(Clone)]
 pub struct Interface {
     pub ty: Type,
@@ -733,6 +746,18 @@ pub fn type_def_is_handle(row: TypeDef) -> bool {
     row.has_attribute("NativeTypedefAttribute")
 }
 
+/// Returns the architectures supported by a type, function or constant, or `None` if it isn't restricted to specific
+/// architectures.
+pub fn architectures<R: HasAttributes<'static>>(row: R) -> Option<i32> {
+    match row.find_attribute("SupportedArchitectureAttribute")?.args().first()? {
+        (_, Value::EnumDef(_, value) | Value::EnumRef(_, value)) => match **value {
+            Value::I32(value) => Some(value),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 pub fn type_def_guid(row: TypeDef) -> Option<Guid> {
     row.find_attribute("GuidAttribute").map(|attribute| Guid::from_args(&attribute.args()))
 }
@@ -811,3 +836,92 @@ pub fn type_def_vtables(row: TypeDef) -> Vec<Type> {
     }
     result
 }
+
+/// Collects the types that `item` depends on, directly or indirectly.
+pub fn item_collect_standalone(item: Item, set: &mut BTreeSet<Type>) {
+    match item {
+        Item::Type(def) => type_collect_standalone(&Type::TypeDef(def, vec![]), set),
+        Item::Const(def) => type_collect_standalone(&def.ty(None).to_const_type(), set),
+        Item::Fn(def, namespace) => {
+            let signature = method_def_signature(namespace, def, &[]);
+            type_collect_standalone(&signature.return_type, set);
+            signature.params.iter().for_each(|param| type_collect_standalone(&param.ty, set));
+        }
+    }
+}
+
+/// Collects `ty` along with the types that it depends on, directly or indirectly.
+pub fn type_collect_standalone(ty: &Type, set: &mut BTreeSet<Type>) {
+    let ty = ty.to_underlying_type();
+    if !set.insert(ty.clone()) {
+        return;
+    }
+
+    let Type::TypeDef(def, generics) = ty.to_underlying_type() else {
+        return;
+    };
+
+    // Ensure that we collect all the typedefs of the same name. We need to
+    // do this in the case where the user specifies a top level item that
+    // references a typedef by name, but that name resolves to more than 1
+    // Type based on target architecture (typically)
+    //
+    // Note this is a bit overeager as we can collect a typedef that is used
+    // by one architecture but not by another
+    let type_name = def.type_name();
+    if !type_name.namespace.is_empty() {
+        for row in def.reader().get_type_def(type_name.namespace, type_name.name) {
+            if def != row {
+                type_collect_standalone(&Type::TypeDef(row, Vec::new()), set);
+            }
+        }
+    }
+
+    for generic in &generics {
+        type_collect_standalone(generic, set);
+    }
+    for field in def.fields() {
+        let ty = field.ty(Some(def));
+        if let Type::TypeDef(def, _) = &ty {
+            if def.namespace().is_empty() {
+                continue;
+            }
+        }
+        type_collect_standalone(&ty, set);
+    }
+    for method in def.methods() {
+        // Skip delegate pseudo-constructors.
+        if method.name() == ".ctor" {
+            continue;
+        }
+        let signature = method_def_signature(def.namespace(), method, &generics);
+        type_collect_standalone(&signature.return_type, set);
+        signature.params.iter().for_each(|param| type_collect_standalone(&param.ty, set));
+    }
+    for interface in type_interfaces(&ty) {
+        type_collect_standalone(&interface.ty, set);
+    }
+    if def.kind() == TypeKind::Struct && def.fields().next().is_none() && type_def_guid(def).is_some() {
+        set.insert(Type::GUID);
+    }
+
+    type_collect_standalone_nested(def, set);
+}
+
+fn type_collect_standalone_nested(td: TypeDef, set: &mut BTreeSet<Type>) {
+    for nested in td.reader().nested_types(td) {
+        type_collect_standalone_nested(nested, set);
+
+        for field in nested.fields() {
+            let ty = field.ty(Some(nested));
+            if let Type::TypeDef(def, _) = ty.to_underlying_type() {
+                // Skip the fields that actually refer to the anonymous nested
+                // type, otherwise it will get added to the typeset and emitted
+                if def.namespace().is_empty() {
+                    continue;
+                }
+            }
+            type_collect_standalone(&ty, set);
+        }
+    }
+}
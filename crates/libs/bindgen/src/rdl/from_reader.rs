@@ -191,15 +191,45 @@ impl Writer {
     fn enum_def(&self, def: metadata::TypeDef) -> TokenStream {
         let name = to_ident(def.name());
 
-        quote! {
-            struct #name {
+        let repr = match def.underlying_type() {
+            metadata::Type::I32 => quote! {},
+            ty => {
+                let ty = self.ty(&ty);
+                quote! { #[repr(#ty)] }
+            }
+        };
+
+        // The special `value__` field that gives the underlying type of the enum has no constant.
+        let variants = def.fields().filter_map(|field| {
+            let name = to_ident(field.name());
 
+            let value: TokenStream = match field.constant()?.value() {
+                metadata::Value::I8(value) => value.to_string(),
+                metadata::Value::U8(value) => value.to_string(),
+                metadata::Value::I16(value) => value.to_string(),
+                metadata::Value::U16(value) => value.to_string(),
+                metadata::Value::I32(value) => value.to_string(),
+                metadata::Value::U32(value) => value.to_string(),
+                metadata::Value::I64(value) => value.to_string(),
+                metadata::Value::U64(value) => value.to_string(),
+                rest => unimplemented!("{rest:?}"),
+            }
+            .into();
+
+            Some(quote! { #name = #value })
+        });
+
+        quote! {
+            #repr
+            enum #name {
+                #(#variants),*
             }
         }
     }
 
     fn struct_def(&self, def: metadata::TypeDef) -> TokenStream {
         let name = to_ident(def.name());
+        let arch = self.arch(def);
 
         let fields = def.fields().map(|field| {
             let name = to_ident(field.name());
@@ -210,6 +240,7 @@ impl Writer {
         });
 
         quote! {
+            #arch
             struct #name {
                 #(#fields),*
             }
@@ -237,6 +268,8 @@ impl Writer {
 
     fn interface_def(&self, def: metadata::TypeDef) -> TokenStream {
         let name = to_ident(def.name());
+        let arch = self.arch(def);
+        let guid = self.guid(def);
         let generics = &metadata::type_def_generics(def);
         let implements = self.implements(def, generics);
 
@@ -262,12 +295,34 @@ impl Writer {
         let generics = self.generics(generics);
 
         quote! {
+            #arch
+            #guid
             interface #name #generics #implements {
                 #(#methods)*
             }
         }
     }
 
+    fn arch(&self, def: metadata::TypeDef) -> TokenStream {
+        let Some(arch) = metadata::architectures(def) else {
+            return quote! {};
+        };
+
+        let names = [(1, "X86"), (2, "X64"), (4, "Arm64")].into_iter().filter(|(flag, _)| arch & flag != 0).map(|(_, name)| to_ident(name));
+
+        quote! { #[arch(#(#names)|*)] }
+    }
+
+    fn guid(&self, def: metadata::TypeDef) -> TokenStream {
+        let Some(guid) = metadata::type_def_guid(def) else {
+            return quote! {};
+        };
+
+        let guid: TokenStream = format!("0x{:08x}_{:04x}_{:04x}_{:02x}{:02x}_{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}", guid.0, guid.1, guid.2, guid.3, guid.4, guid.5, guid.6, guid.7, guid.8, guid.9, guid.10).into();
+
+        quote! { #[guid(#guid)] }
+    }
+
     fn generics(&self, generics: &[metadata::Type]) -> TokenStream {
         if generics.is_empty() {
             quote! {}
@@ -84,6 +84,10 @@ pub struct Enum {
     pub winrt: bool,
     pub name: String,
     pub item: syn::ItemEnum,
+    /// The underlying type named by a `#[repr]` attribute, which is `i32` by default.
+    pub ty: winmd::Type,
+    /// The name and value of each variant, where a variant without a discriminant follows on from the previous one.
+    pub variants: Vec<(String, winmd::Value)>,
 }
 
 #[derive(Clone, Debug)]
@@ -97,6 +101,8 @@ pub struct Struct {
     pub winrt: bool,
     pub name: String,
     pub attributes: Vec<syn::Attribute>,
+    /// The architectures named by an `#[arch]` attribute, if any.
+    pub arch: Option<i32>,
     pub span: proc_macro2::Span,
     pub fields: Vec<Field>,
 }
@@ -129,6 +135,10 @@ pub struct Interface {
     pub name: String,
     pub generics: Vec<String>,
     pub attributes: Vec<syn::Attribute>,
+    /// The architectures named by an `#[arch]` attribute, if any.
+    pub arch: Option<i32>,
+    /// The GUID given by a `#[guid]` attribute, if any.
+    pub guid: Option<u128>,
     pub extends: Vec<syn::TypePath>,
     pub methods: Vec<syn::TraitItemFn>,
 }
@@ -153,6 +163,86 @@ fn winrt(input: syn::parse::ParseStream<'_>) -> syn::Result<bool> {
     Err(syn::Error::new(input.span(), "A single `#![win32]` or `#![winrt]` attribute required"))
 }
 
+/// Parses the arguments of the attribute with the given name, if any. Other attributes are ignored.
+fn attribute<T>(attributes: &[syn::Attribute], name: &str, parse: fn(syn::parse::ParseStream<'_>) -> syn::Result<T>) -> syn::Result<Option<T>> {
+    attributes.iter().find(|attribute| attribute.path().is_ident(name)).map(|attribute| attribute.parse_args_with(parse)).transpose()
+}
+
+/// Parses the architectures of an `#[arch(X86 | X64)]` attribute into the flags of the `Architecture` enum used by
+/// the `SupportedArchitectureAttribute`.
+fn arch(input: syn::parse::ParseStream<'_>) -> syn::Result<i32> {
+    let mut arch = 0;
+
+    loop {
+        let ident = input.parse::<syn::Ident>()?;
+
+        arch |= match ident.to_string().as_str() {
+            "X86" => 1,
+            "X64" => 2,
+            "Arm64" => 4,
+            _ => return Err(syn::Error::new(ident.span(), "expected `X86`, `X64` or `Arm64`")),
+        };
+
+        if input.is_empty() {
+            return Ok(arch);
+        }
+
+        input.parse::<syn::Token![|]>()?;
+    }
+}
+
+/// Parses the underlying type of a `#[repr(u32)]` attribute on an enum.
+fn repr(input: syn::parse::ParseStream<'_>) -> syn::Result<winmd::Type> {
+    let ident = input.parse::<syn::Ident>()?;
+
+    match ident.to_string().as_str() {
+        "i8" => Ok(winmd::Type::I8),
+        "u8" => Ok(winmd::Type::U8),
+        "i16" => Ok(winmd::Type::I16),
+        "u16" => Ok(winmd::Type::U16),
+        "i32" => Ok(winmd::Type::I32),
+        "u32" => Ok(winmd::Type::U32),
+        "i64" => Ok(winmd::Type::I64),
+        "u64" => Ok(winmd::Type::U64),
+        _ => Err(syn::Error::new(ident.span(), "expected an integer type")),
+    }
+}
+
+/// Parses an enum discriminant, which must be an integer literal. The range is checked against the underlying type
+/// by `enum_value`.
+fn discriminant(expr: &syn::Expr) -> syn::Result<i128> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(lit), .. }) => lit.base10_parse(),
+        syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), expr, .. }) => match &**expr {
+            syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(lit), .. }) => lit.base10_parse::<i128>().map(|value| -value),
+            _ => Err(syn::Error::new(expr.span(), "expected an integer literal")),
+        },
+        _ => Err(syn::Error::new(expr.span(), "expected an integer literal")),
+    }
+}
+
+/// Converts an enum discriminant to a value of the enum's underlying type.
+fn enum_value(ty: &winmd::Type, value: i128, span: proc_macro2::Span) -> syn::Result<winmd::Value> {
+    let converted = match ty {
+        winmd::Type::I8 => i8::try_from(value).map(winmd::Value::I8).ok(),
+        winmd::Type::U8 => u8::try_from(value).map(winmd::Value::U8).ok(),
+        winmd::Type::I16 => i16::try_from(value).map(winmd::Value::I16).ok(),
+        winmd::Type::U16 => u16::try_from(value).map(winmd::Value::U16).ok(),
+        winmd::Type::I32 => i32::try_from(value).map(winmd::Value::I32).ok(),
+        winmd::Type::U32 => u32::try_from(value).map(winmd::Value::U32).ok(),
+        winmd::Type::I64 => i64::try_from(value).map(winmd::Value::I64).ok(),
+        winmd::Type::U64 => u64::try_from(value).map(winmd::Value::U64).ok(),
+        rest => unimplemented!("{rest:?}"),
+    };
+
+    converted.ok_or_else(|| syn::Error::new(span, format!("discriminant `{value}` doesn't fit in the underlying type of the enum")))
+}
+
+/// Parses the GUID of a `#[guid(0x00000000_0000_0000_0000_000000000000)]` attribute.
+fn guid(input: syn::parse::ParseStream<'_>) -> syn::Result<u128> {
+    input.parse::<syn::LitInt>()?.base10_parse()
+}
+
 impl syn::parse::Parse for File {
     fn parse(input: syn::parse::ParseStream<'_>) -> syn::Result<Self> {
         let mut references = vec![];
@@ -279,7 +369,9 @@ impl Interface {
         while !content.is_empty() {
             methods.push(content.parse()?);
         }
-        Ok(Self { winrt, attributes, generics, extends, name, methods })
+        let arch = attribute(&attributes, "arch", arch)?;
+        let guid = attribute(&attributes, "guid", guid)?;
+        Ok(Self { winrt, attributes, arch, guid, generics, extends, name, methods })
     }
 }
 
@@ -306,7 +398,8 @@ impl Struct {
             });
         }
 
-        Ok(Self { winrt, name, attributes, span, fields })
+        let arch = attribute(&attributes, "arch", arch)?;
+        Ok(Self { winrt, name, attributes, arch, span, fields })
     }
 }
 
@@ -315,7 +408,25 @@ impl Enum {
         let mut item: syn::ItemEnum = input.parse()?;
         item.attrs = attributes;
         let name = item.ident.to_string();
-        Ok(Self { winrt, name, item })
+        let ty = attribute(&item.attrs, "repr", repr)?.unwrap_or(winmd::Type::I32);
+        let mut variants = vec![];
+        let mut next = 0;
+
+        for variant in &item.variants {
+            if !matches!(variant.fields, syn::Fields::Unit) {
+                return Err(syn::Error::new(variant.span(), "enum variants can't have fields"));
+            }
+
+            let value = match &variant.discriminant {
+                Some((_, expr)) => discriminant(expr)?,
+                None => next,
+            };
+
+            variants.push((variant.ident.to_string(), enum_value(&ty, value, variant.span())?));
+            next = value + 1;
+        }
+
+        Ok(Self { winrt, name, item, ty, variants })
     }
 }
 
@@ -78,6 +78,12 @@ fn write_interface(writer: &mut winmd::Writer, namespace: &str, name: &str, memb
         TypeNamespace: writer.strings.insert(namespace),
     });
 
+    write_arch(writer, member.arch);
+
+    if let Some(guid) = member.guid {
+        write_guid(writer, member.winrt, guid);
+    }
+
     for (number, generic) in member.generics.iter().enumerate() {
         writer.tables.GenericParam.push(writer::GenericParam {
             Number: number as u16,
@@ -99,28 +105,99 @@ fn write_interface(writer: &mut winmd::Writer, namespace: &str, name: &str, memb
         writer.tables.InterfaceImpl.push(writer::InterfaceImpl { Class: writer.tables.TypeDef.len() as u32 - 1, Interface: reference });
     }
 
-    for method in &member.methods {
-        let signature = syn_signature(namespace, &member.generics, &method.sig);
+    let methods: Vec<(String, winmd::Signature)> = member.methods.iter().map(|method| (method.sig.ident.to_string(), syn_signature(namespace, &member.generics, &method.sig))).collect();
+
+    // .rdl can't express properties and events, so those of WinRT interfaces are inferred from their accessors.
+    let (properties, events) = if member.winrt {
+        (
+            infer_members(&methods, ("get_", metadata::MethodSemanticsAttributes::Getter), ("put_", metadata::MethodSemanticsAttributes::Setter), |signature| if signature.params.is_empty() && signature.return_type != winmd::Type::Void { Some(&signature.return_type) } else { None }),
+            infer_members(&methods, ("add_", metadata::MethodSemanticsAttributes::AddOn), ("remove_", metadata::MethodSemanticsAttributes::RemoveOn), |signature| match signature.params.as_slice() {
+                [param] => Some(&param.ty),
+                _ => None,
+            }),
+        )
+    } else {
+        (vec![], vec![])
+    };
+
+    let first_method = writer.tables.MethodDef.len();
 
+    for (index, (name, signature)) in methods.iter().enumerate() {
         let params: Vec<winmd::Type> = signature.params.iter().map(|param| param.ty.clone()).collect();
 
         let signature_blob = writer.insert_method_sig(metadata::MethodCallAttributes(0), &signature.return_type, &params);
 
-        let flags = metadata::MethodAttributes::Abstract | metadata::MethodAttributes::HideBySig | metadata::MethodAttributes::HideBySig | metadata::MethodAttributes::NewSlot | metadata::MethodAttributes::Public | metadata::MethodAttributes::Virtual;
+        let mut flags = metadata::MethodAttributes::Abstract | metadata::MethodAttributes::HideBySig | metadata::MethodAttributes::HideBySig | metadata::MethodAttributes::NewSlot | metadata::MethodAttributes::Public | metadata::MethodAttributes::Virtual;
 
-        writer.tables.MethodDef.push(winmd::MethodDef {
-            RVA: 0,
-            ImplFlags: 0,
-            Flags: flags.0,
-            Name: writer.strings.insert(&method.sig.ident.to_string()),
-            Signature: signature_blob,
-            ParamList: writer.tables.Param.len() as u32,
-        });
+        if properties.iter().chain(&events).any(|member| member.methods.iter().any(|(_, method)| *method == index)) {
+            flags |= metadata::MethodAttributes::SpecialName;
+        }
+
+        writer.tables.MethodDef.push(winmd::MethodDef { RVA: 0, ImplFlags: 0, Flags: flags.0, Name: writer.strings.insert(name), Signature: signature_blob, ParamList: writer.tables.Param.len() as u32 });
 
         for (sequence, param) in signature.params.iter().enumerate() {
             writer.tables.Param.push(winmd::Param { Flags: 0, Sequence: (sequence + 1) as u16, Name: writer.strings.insert(&param.name) });
         }
     }
+
+    if !properties.is_empty() {
+        writer.tables.PropertyMap.push(writer::PropertyMap { Parent: writer.tables.TypeDef.len() as u32 - 1, PropertyList: writer.tables.Property.len() as u32 });
+
+        for property in properties {
+            let signature = writer.insert_property_sig(&property.ty);
+            let association = writer::HasSemantics::Property(writer.tables.Property.len() as u32).encode();
+            writer.tables.Property.push(writer::Property { Flags: 0, Name: writer.strings.insert(&property.name), Type: signature });
+
+            for (semantics, method) in property.methods {
+                writer.tables.MethodSemantics.push(writer::MethodSemantics { Semantics: semantics.0, Method: (first_method + method) as u32, Association: association });
+            }
+        }
+    }
+
+    if !events.is_empty() {
+        writer.tables.EventMap.push(writer::EventMap { Parent: writer.tables.TypeDef.len() as u32 - 1, EventList: writer.tables.Event.len() as u32 });
+
+        for event in events {
+            let event_type = writer.insert_type_def_or_ref(event.ty);
+            let association = writer::HasSemantics::Event(writer.tables.Event.len() as u32).encode();
+            writer.tables.Event.push(writer::Event { EventFlags: 0, Name: writer.strings.insert(&event.name), EventType: event_type });
+
+            for (semantics, method) in event.methods {
+                writer.tables.MethodSemantics.push(writer::MethodSemantics { Semantics: semantics.0, Method: (first_method + method) as u32, Association: association });
+            }
+        }
+    }
+}
+
+/// A property or event inferred from its accessors, which are identified by their index in the interface's methods.
+struct Member {
+    name: String,
+    ty: winmd::Type,
+    methods: Vec<(metadata::MethodSemanticsAttributes, usize)>,
+}
+
+/// Infers members from accessor names. The `primary` accessor is required and its signature, as accepted by `ty`,
+/// defines the type of the member while the `secondary` accessor is optional and must take a single parameter.
+fn infer_members(methods: &[(String, winmd::Signature)], primary: (&str, metadata::MethodSemanticsAttributes), secondary: (&str, metadata::MethodSemanticsAttributes), ty: fn(&winmd::Signature) -> Option<&winmd::Type>) -> Vec<Member> {
+    let mut members: Vec<Member> = vec![];
+
+    for (index, (name, signature)) in methods.iter().enumerate() {
+        if let (Some(name), Some(ty)) = (name.strip_prefix(primary.0), ty(signature)) {
+            if !members.iter().any(|member| member.name == name) {
+                members.push(Member { name: name.to_string(), ty: ty.clone(), methods: vec![(primary.1, index)] });
+            }
+        }
+    }
+
+    for (index, (name, signature)) in methods.iter().enumerate() {
+        if let Some(member) = name.strip_prefix(secondary.0).and_then(|name| members.iter_mut().find(|member| member.name == name)) {
+            if signature.params.len() == 1 {
+                member.methods.push((secondary.1, index));
+            }
+        }
+    }
+
+    members
 }
 
 fn write_struct(writer: &mut winmd::Writer, namespace: &str, name: &str, member: &Struct) {
@@ -141,6 +218,8 @@ fn write_struct(writer: &mut winmd::Writer, namespace: &str, name: &str, member:
         TypeNamespace: writer.strings.insert(namespace),
     });
 
+    write_arch(writer, member.arch);
+
     for field in &member.fields {
         let flags = metadata::FieldAttributes::Public;
         let ty = syn_type(namespace, &[], &field.ty);
@@ -150,7 +229,58 @@ fn write_struct(writer: &mut winmd::Writer, namespace: &str, name: &str, member:
     }
 }
 
-fn write_enum(_writer: &mut winmd::Writer, _namespace: &str, _name: &str, _member: &Enum) {}
+/// Restricts the type definition that was just written to the given architectures, if any.
+fn write_arch(writer: &mut winmd::Writer, arch: Option<i32>) {
+    if let Some(arch) = arch {
+        let parent = writer::HasAttribute::TypeDef(writer.tables.TypeDef.len() as u32 - 1);
+        let architecture = winmd::TypeName { namespace: "Windows.Win32.Foundation.Metadata".to_string(), name: "Architecture".to_string(), generics: vec![] };
+        let args = [(metadata::ArgKind::Fixed, String::new(), winmd::Value::Enum(architecture, Box::new(winmd::Value::I32(arch))))];
+        writer.insert_attribute(parent, "Windows.Win32.Foundation.Metadata", "SupportedArchitectureAttribute", &args);
+    }
+}
+
+/// Gives the type definition that was just written the GUID, which is split into the fields of the GUID structure.
+fn write_guid(writer: &mut winmd::Writer, winrt: bool, guid: u128) {
+    let parent = writer::HasAttribute::TypeDef(writer.tables.TypeDef.len() as u32 - 1);
+    let namespace = if winrt { "Windows.Foundation.Metadata" } else { "Windows.Win32.Foundation.Metadata" };
+    let mut args = vec![winmd::Value::U32((guid >> 96) as u32), winmd::Value::U16((guid >> 80) as u16), winmd::Value::U16((guid >> 64) as u16)];
+    args.extend((guid as u64).to_be_bytes().map(winmd::Value::U8));
+    let args: Vec<_> = args.into_iter().map(|value| (metadata::ArgKind::Fixed, String::new(), value)).collect();
+    writer.insert_attribute(parent, namespace, "GuidAttribute", &args);
+}
+
+fn write_enum(writer: &mut winmd::Writer, namespace: &str, name: &str, member: &Enum) {
+    let mut flags = metadata::TypeAttributes::Public | metadata::TypeAttributes::Sealed;
+
+    if member.winrt {
+        flags |= metadata::TypeAttributes::WindowsRuntime
+    }
+
+    let extends = writer.insert_type_ref("System", "Enum");
+
+    writer.tables.TypeDef.push(winmd::TypeDef {
+        Extends: extends,
+        FieldList: writer.tables.Field.len() as u32,
+        MethodList: writer.tables.MethodDef.len() as u32,
+        Flags: flags.0,
+        TypeName: writer.strings.insert(name),
+        TypeNamespace: writer.strings.insert(namespace),
+    });
+
+    // The underlying type of an enum is given by the type of its special `value__` field.
+    let flags = metadata::FieldAttributes::Private | metadata::FieldAttributes::SpecialName | metadata::FieldAttributes::RTSpecialName;
+    let signature = writer.insert_field_sig(&member.ty);
+    writer.tables.Field.push(winmd::Field { Flags: flags.0, Name: writer.strings.insert("value__"), Signature: signature });
+
+    let flags = metadata::FieldAttributes::Public | metadata::FieldAttributes::Static | metadata::FieldAttributes::Literal | metadata::FieldAttributes::HasDefault;
+    let signature = writer.insert_field_sig(&winmd::Type::TypeRef(winmd::TypeName { namespace: namespace.to_string(), name: name.to_string(), generics: vec![] }));
+
+    for (variant, value) in &member.variants {
+        let row = writer.tables.Field.len() as u32;
+        writer.tables.Field.push(winmd::Field { Flags: flags.0, Name: writer.strings.insert(variant), Signature: signature });
+        writer.insert_constant(writer::HasConstant::Field(row), value);
+    }
+}
 
 fn write_class(writer: &mut winmd::Writer, namespace: &str, name: &str, member: &Class) {
     let flags = metadata::TypeAttributes::Public | metadata::TypeAttributes::Sealed | metadata::TypeAttributes::WindowsRuntime;
@@ -124,7 +124,7 @@ fn signature_cfg_combine(writer: &Writer, signature: &metadata::MethodDefSig, cf
     signature.params.iter().for_each(|param| type_cfg_combine(writer, param, cfg));
 }
 
-fn cfg_add_attributes<R: AsRow + Into<metadata::HasAttribute>>(cfg: &mut Cfg, row: R) {
+fn cfg_add_attributes<R: AsRow<'static> + Into<metadata::HasAttribute>>(cfg: &mut Cfg, row: R) {
     for attribute in row.attributes() {
         match attribute.name() {
             "SupportedArchitectureAttribute" => {
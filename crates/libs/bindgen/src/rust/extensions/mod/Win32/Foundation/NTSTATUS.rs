@@ -12,6 +12,7 @@ impl NTSTATUS {
         ::windows_core::HRESULT(self.0 | 0x1000_0000)
     }
     #[inline]
+    #[track_caller]
     pub fn ok(self) -> ::windows_core::Result<()> {
         if self.is_ok() {
             Ok(())
@@ -4,6 +4,7 @@ impl VARIANT_BOOL {
         self.0 != 0
     }
     #[inline]
+    #[track_caller]
     pub fn ok(self) -> ::windows_core::Result<()> {
         if self.as_bool() {
             Ok(())
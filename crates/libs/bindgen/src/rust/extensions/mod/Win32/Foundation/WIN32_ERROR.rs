@@ -21,6 +21,7 @@ impl WIN32_ERROR {
         }
     }
     #[inline]
+    #[track_caller]
     pub fn ok(self) -> ::windows_core::Result<()> {
         if self.is_ok() {
             Ok(())
@@ -35,6 +36,7 @@ impl ::core::convert::From<WIN32_ERROR> for ::windows_core::HRESULT {
     }
 }
 impl ::core::convert::From<WIN32_ERROR> for ::windows_core::Error {
+    #[track_caller]
     fn from(value: WIN32_ERROR) -> Self {
         value.to_hresult().into()
     }
@@ -1,5 +1,4 @@
 use super::*;
-use metadata::AsRow;
 
 pub fn standalone_imp(writer: &Writer) -> String {
     let mut types = std::collections::BTreeSet::new();
@@ -7,7 +6,7 @@ pub fn standalone_imp(writer: &Writer) -> String {
     let mut constants = std::collections::BTreeSet::new();
 
     for item in writer.reader.items() {
-        item_collect_standalone(item.clone(), &mut types);
+        metadata::item_collect_standalone(item.clone(), &mut types);
 
         match item {
             metadata::Item::Type(_) => {}
@@ -114,90 +113,3 @@ impl SortedTokens {
         self.0.entry(key.to_string()).or_default().combine(&tokens);
     }
 }
-
-fn item_collect_standalone(item: metadata::Item, set: &mut std::collections::BTreeSet<metadata::Type>) {
-    match item {
-        metadata::Item::Type(def) => type_collect_standalone(&metadata::Type::TypeDef(def, vec![]), set),
-        metadata::Item::Const(def) => type_collect_standalone(&def.ty(None).to_const_type(), set),
-        metadata::Item::Fn(def, namespace) => {
-            let signature = metadata::method_def_signature(namespace, def, &[]);
-            type_collect_standalone(&signature.return_type, set);
-            signature.params.iter().for_each(|param| type_collect_standalone(&param.ty, set));
-        }
-    }
-}
-// TODO: remove or move to riddle
-fn type_collect_standalone(ty: &metadata::Type, set: &mut std::collections::BTreeSet<metadata::Type>) {
-    let ty = ty.to_underlying_type();
-    if !set.insert(ty.clone()) {
-        return;
-    }
-
-    let metadata::Type::TypeDef(def, generics) = ty.to_underlying_type() else {
-        return;
-    };
-
-    // Ensure that we collect all the typedefs of the same name. We need to
-    // do this in the case where the user specifies a top level item that
-    // references a typedef by name, but that name resolves to more than 1
-    // Type based on target architecture (typically)
-    //
-    // Note this is a bit overeager as we can collect a typedef that is used
-    // by one architecture but not by another
-    let type_name = def.type_name();
-    if !type_name.namespace.is_empty() {
-        for row in def.reader().get_type_def(type_name.namespace, type_name.name) {
-            if def != row {
-                type_collect_standalone(&metadata::Type::TypeDef(row, Vec::new()), set);
-            }
-        }
-    }
-
-    for generic in &generics {
-        type_collect_standalone(generic, set);
-    }
-    for field in def.fields() {
-        let ty = field.ty(Some(def));
-        if let metadata::Type::TypeDef(def, _) = &ty {
-            if def.namespace().is_empty() {
-                continue;
-            }
-        }
-        type_collect_standalone(&ty, set);
-    }
-    for method in def.methods() {
-        // Skip delegate pseudo-constructors.
-        if method.name() == ".ctor" {
-            continue;
-        }
-        let signature = metadata::method_def_signature(def.namespace(), method, &generics);
-        type_collect_standalone(&signature.return_type, set);
-        signature.params.iter().for_each(|param| type_collect_standalone(&param.ty, set));
-    }
-    for interface in metadata::type_interfaces(&ty) {
-        type_collect_standalone(&interface.ty, set);
-    }
-    if def.kind() == metadata::TypeKind::Struct && def.fields().next().is_none() && metadata::type_def_guid(def).is_some() {
-        set.insert(metadata::Type::GUID);
-    }
-
-    type_collect_standalone_nested(def, set);
-}
-
-fn type_collect_standalone_nested(td: metadata::TypeDef, set: &mut std::collections::BTreeSet<metadata::Type>) {
-    for nested in td.reader().nested_types(td) {
-        type_collect_standalone_nested(nested, set);
-
-        for field in nested.fields() {
-            let ty = field.ty(Some(nested));
-            if let metadata::Type::TypeDef(def, _) = ty.to_underlying_type() {
-                // Skip the fields that actually refer to the anonymous nested
-                // type, otherwise it will get added to the typeset and emitted
-                if def.namespace().is_empty() {
-                    continue;
-                }
-            }
-            type_collect_standalone(&ty, set);
-        }
-    }
-}
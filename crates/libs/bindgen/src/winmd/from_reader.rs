@@ -1,99 +1,376 @@
 use super::*;
+use metadata::HasAttributes;
+use std::collections::HashMap;
 
-pub fn from_reader(reader: &metadata::Reader, config: std::collections::BTreeMap<&str, &str>, output: &str) -> Result<()> {
-    let mut writer = Writer::new(output);
-
-    // TODO: do we need any configuration values for winmd generation?
-    // Maybe per-namespace winmd files for namespace-splitting - be sure to use
-    // the same key as for winmd generation.
+pub fn from_reader(reader: &'static metadata::Reader, mut config: std::collections::BTreeMap<&str, &str>, output: &str) -> Result<()> {
+    let split = Split::new(&mut config)?;
+    let merge = config.remove("merge").is_some();
+    let standalone = config.remove("standalone").is_some();
 
     if let Some((key, _)) = config.first_key_value() {
         return Err(Error::new(&format!("invalid configuration value `{key}`")));
     }
 
+    let name = output.rsplit_once(['/', '\\']).map_or(output, |(_, name)| name);
+    let main = name.rsplit_once('.').map_or(name, |(name, _)| name);
+
     // TODO: just use the reader directly since we now have everything in the reader, there's no need to abstract
     // away the source format. Few reprs is always better.
 
+    let mut defs = vec![];
+    let mut apis = Vec::<(metadata::TypeDef, Members)>::new();
+    let mut closure = std::collections::BTreeSet::new();
+
     for item in reader.items() {
-        // TODO: cover all variants
-        let metadata::Item::Type(def) = item else {
-            continue;
-        };
-
-        let generics = &metadata::type_def_generics(def);
-
-        let extends = if let Some(extends) = def.extends() { writer.insert_type_ref(extends.namespace, extends.name) } else { 0 };
-
-        writer.tables.TypeDef.push(TypeDef {
-            Extends: extends,
-            FieldList: writer.tables.Field.len() as u32,
-            Flags: def.flags().0,
-            MethodList: writer.tables.MethodDef.len() as u32,
-            TypeName: writer.strings.insert(def.name()),
-            TypeNamespace: writer.strings.insert(def.namespace()),
+        if standalone {
+            metadata::item_collect_standalone(item.clone(), &mut closure);
+
+            match &item {
+                metadata::Item::Type(_) => {}
+                metadata::Item::Fn(method, _) => {
+                    attribute_types(reader, *method, &mut closure);
+                    method.params().for_each(|param| attribute_types(reader, param, &mut closure));
+                }
+                metadata::Item::Const(field) => attribute_types(reader, *field, &mut closure),
+            }
+        }
+
+        match item {
+            metadata::Item::Type(def) => defs.push(def),
+            metadata::Item::Fn(method, _) => apis_members(&mut apis, method.parent()).methods.push(method),
+            metadata::Item::Const(field) => {
+                let parent = field.parent();
+
+                // The literals of unscoped enums are written along with their enums.
+                if parent.name() == "Apis" {
+                    apis_members(&mut apis, parent).fields.push(field);
+                }
+            }
+        }
+    }
+
+    if standalone {
+        defs.extend(standalone_defs(reader, closure));
+        defs.sort();
+        defs.dedup();
+    }
+
+    let defs = if merge { merge::merge(reader, defs)? } else { defs.into_iter().map(|def| merge::Merged { def, architectures: None }).collect() };
+
+    // Types are grouped by the assembly of the file they're written to, with `None` being the main output.
+    let mut files = std::collections::BTreeMap::<Option<String>, Vec<(merge::Merged, Option<Members>)>>::new();
+    let mut assemblies = HashMap::<String, HashMap<String, String>>::new();
+
+    for merged in defs {
+        let def = merged.def;
+        let assembly = split.assembly(def).filter(|assembly| assembly != main);
+        assemblies.entry(def.namespace().to_string()).or_default().insert(def.name().to_string(), assembly.clone().unwrap_or_else(|| main.to_string()));
+        files.entry(assembly).or_default().push((merged, None));
+    }
+
+    let apis = if merge { merge::merge_apis(apis)? } else { apis };
+
+    // Functions and constants aren't referred to by other types so they don't need to be added to `assemblies`.
+    for (def, members) in apis {
+        let assembly = split.assembly(def).filter(|assembly| assembly != main);
+        files.entry(assembly).or_default().push((merge::Merged { def, architectures: None }, Some(members)));
+    }
+
+    if files.is_empty() {
+        files.insert(None, vec![]);
+    }
+
+    for (assembly, defs) in files {
+        let path = assembly.as_ref().map_or_else(|| output.to_string(), |assembly| format!("{}/{assembly}.winmd", directory(output)));
+        let mut writer = Writer::new(&path);
+
+        // References to types in other files must resolve to those files rather than the current one.
+        let current = assembly.as_deref().unwrap_or(main);
+        writer.assemblies = assemblies.iter().map(|(namespace, names)| (namespace.clone(), names.iter().filter(|(_, assembly)| *assembly != current).map(|(name, assembly)| (name.clone(), assembly.clone())).collect())).collect();
+
+        for (merged, members) in defs {
+            write_type_def(&mut writer, merged, members);
+        }
+
+        write_to_file(&path, writer.into_stream()).map_err(|err| err.with_path(&path))?;
+    }
+
+    Ok(())
+}
+
+/// The functions and constants of an `Apis` class that were selected by the filter.
+#[derive(Default)]
+pub struct Members {
+    pub fields: Vec<metadata::Field>,
+    pub methods: Vec<metadata::MethodDef>,
+}
+
+fn apis_members(apis: &mut Vec<(metadata::TypeDef, Members)>, def: metadata::TypeDef) -> &mut Members {
+    let index = apis.iter().position(|(existing, _)| *existing == def).unwrap_or_else(|| {
+        apis.push((def, Members::default()));
+        apis.len() - 1
+    });
+
+    &mut apis[index].1
+}
+
+/// Returns the definitions of the types in `closure`, which is the set of types the selected items depend on.
+fn standalone_defs(reader: &'static metadata::Reader, mut closure: std::collections::BTreeSet<metadata::Type>) -> Vec<metadata::TypeDef> {
+    // The reader replaces core types like `PWSTR` with built-in types, so their definitions (and in turn the types
+    // those depend on) are looked up by the names the writer refers to them by. Types such as `System.Guid` aren't
+    // defined by any input and are simply referenced.
+    let mut core = vec![];
+    let mut attributed = std::collections::BTreeSet::new();
+
+    loop {
+        let len = closure.len();
+
+        for ty in closure.clone() {
+            if let Some(def) = core_type_name(&ty).and_then(|type_name| reader.get_type_def(type_name.namespace, type_name.name).next()) {
+                if !core.contains(&def) {
+                    core.push(def);
+                    metadata::type_collect_standalone(&metadata::Type::TypeDef(def, vec![]), &mut closure);
+                }
+            }
+        }
+
+        for def in closure.clone().into_iter().filter_map(|ty| if let metadata::Type::TypeDef(def, _) = ty { Some(def) } else { None }).chain(core.clone()) {
+            if attributed.insert(def) {
+                attribute_types(reader, def, &mut closure);
+                def.fields().for_each(|field| attribute_types(reader, field, &mut closure));
+
+                for method in def.methods() {
+                    attribute_types(reader, method, &mut closure);
+                    method.params().for_each(|param| attribute_types(reader, param, &mut closure));
+                }
+            }
+        }
+
+        if closure.len() == len {
+            break;
+        }
+    }
+
+    // Nested types are only reachable through their outer types.
+    closure.into_iter().filter_map(|ty| if let metadata::Type::TypeDef(def, _) = ty { Some(def) } else { None }).filter(|def| !def.namespace().is_empty()).chain(core).collect()
+}
+
+/// Collects the definitions of the attributes applied to `row`, along with the enums that their arguments refer to, so
+/// that standalone metadata describes its attributes as well.
+fn attribute_types<R: HasAttributes<'static>>(reader: &'static metadata::Reader, row: R, closure: &mut std::collections::BTreeSet<metadata::Type>) {
+    for attribute in row.attributes() {
+        let type_name = attribute.type_name();
+        reader.get_type_def(type_name.namespace, type_name.name).for_each(|def| metadata::type_collect_standalone(&metadata::Type::TypeDef(def, vec![]), closure));
+        attribute.args().iter().for_each(|(_, value)| value_types(reader, value, closure));
+    }
+}
+
+fn value_types(reader: &'static metadata::Reader, value: &metadata::Value, closure: &mut std::collections::BTreeSet<metadata::Type>) {
+    match value {
+        metadata::Value::EnumDef(def, _) => metadata::type_collect_standalone(&metadata::Type::TypeDef(*def, vec![]), closure),
+        metadata::Value::EnumRef(type_name, _) => reader.get_type_def(type_name.namespace, type_name.name).for_each(|def| metadata::type_collect_standalone(&metadata::Type::TypeDef(def, vec![]), closure)),
+        metadata::Value::Array(ty, values) => {
+            metadata::type_collect_standalone(ty, closure);
+            values.iter().for_each(|value| value_types(reader, value, closure));
+        }
+        metadata::Value::Object(value) => value_types(reader, value, closure),
+        _ => {}
+    }
+}
+
+fn core_type_name(ty: &metadata::Type) -> Option<metadata::TypeName> {
+    match ty {
+        metadata::Type::HRESULT => Some(metadata::TypeName::HResult),
+        metadata::Type::IUnknown => Some(metadata::TypeName::IUnknown),
+        metadata::Type::BSTR => Some(metadata::TypeName::BSTR),
+        metadata::Type::PSTR | metadata::Type::PCSTR => Some(metadata::TypeName::PSTR),
+        metadata::Type::PWSTR | metadata::Type::PCWSTR => Some(metadata::TypeName::PWSTR),
+        _ => None,
+    }
+}
+
+fn write_type_def(writer: &mut Writer, merged: merge::Merged, members: Option<Members>) {
+    let def = merged.def;
+    let generics = &metadata::type_def_generics(def);
+
+    let extends = if let Some(extends) = def.extends() { writer.insert_type_ref(extends.namespace, extends.name) } else { 0 };
+
+    writer.tables.TypeDef.push(TypeDef {
+        Extends: extends,
+        FieldList: writer.tables.Field.len() as u32,
+        Flags: def.flags().0,
+        MethodList: writer.tables.MethodDef.len() as u32,
+        TypeName: writer.strings.insert(def.name()),
+        TypeNamespace: writer.strings.insert(def.namespace()),
+    });
+
+    let parent = HasAttribute::TypeDef(writer.tables.TypeDef.len() as u32 - 1);
+
+    for attribute in def.attributes() {
+        let type_name = attribute.type_name();
+        let mut args = winmd_args(attribute);
+
+        // Merged definitions support the architectures of each of the definitions they replace.
+        if let (Some(architectures), "SupportedArchitectureAttribute", Some((_, _, Value::Enum(_, value)))) = (merged.architectures, type_name.name, args.first_mut()) {
+            **value = Value::I32(architectures);
+        }
+
+        writer.insert_attribute(parent.clone(), type_name.namespace, type_name.name, &args);
+    }
+
+    for generic in def.generics() {
+        writer.tables.GenericParam.push(GenericParam {
+            Number: generic.number(), // TODO: isn't this just going to be incremental?
+            Flags: 0,
+            Owner: TypeOrMethodDef::TypeDef(writer.tables.TypeDef.len() as u32 - 1).encode(),
+            Name: writer.strings.insert(generic.name()),
         });
+    }
+
+    for interface in metadata::type_def_interfaces(def, generics) {
+        let reference = writer.insert_type_def_or_ref(winmd_type(&interface.ty));
 
-        for generic in def.generics() {
-            writer.tables.GenericParam.push(GenericParam {
-                Number: generic.number(), // TODO: isn't this just going to be incremental?
-                Flags: 0,
-                Owner: TypeOrMethodDef::TypeDef(writer.tables.TypeDef.len() as u32 - 1).encode(),
-                Name: writer.strings.insert(generic.name()),
-            });
+        writer.tables.InterfaceImpl.push(InterfaceImpl { Class: writer.tables.TypeDef.len() as u32 - 1, Interface: reference });
+    }
+
+    // TODO: if the class is "Apis" then should we sort the fields (constants) and methods (functions) for stability
+
+    // An `Apis` class only includes the functions and constants that were selected.
+    let (fields, selected_methods) = match members {
+        Some(members) => (members.fields, members.methods),
+        None => (def.fields().collect(), def.methods().collect()),
+    };
+
+    for field in fields {
+        let ty = winmd_type(&field.ty(Some(def)));
+        let signature = writer.insert_field_sig(&ty);
+
+        let row = writer.tables.Field.len() as u32;
+        insert_attributes(writer, HasAttribute::Field(row), field);
+        writer.tables.Field.push(Field { Flags: field.flags().0, Name: writer.strings.insert(field.name()), Signature: signature });
+
+        if let Some(constant) = field.constant() {
+            writer.insert_constant(HasConstant::Field(row), &winmd_value(&constant.value()));
         }
+    }
 
-        for interface in metadata::type_def_interfaces(def, generics) {
-            let ty = winmd_type(&interface.ty);
+    let properties = type_def_properties(def, generics);
+    let events = type_def_events(def, generics);
+    let mut methods = HashMap::new();
 
-            let reference = match &ty {
-                Type::TypeRef(type_name) if type_name.generics.is_empty() => writer.insert_type_ref(&type_name.namespace, &type_name.name),
-                Type::TypeRef(_) => writer.insert_type_spec(ty),
-                Type::IUnknown => writer.insert_type_ref("Windows.Win32.System.Com", "IUnknown"),
-                Type::IInspectable => writer.insert_type_ref("Windows.Win32.System.WinRT", "IInspectable"),
-                rest => unimplemented!("{rest:?}"),
-            };
+    for method in selected_methods {
+        let mut flags = method.flags();
 
-            writer.tables.InterfaceImpl.push(InterfaceImpl { Class: writer.tables.TypeDef.len() as u32 - 1, Interface: reference });
+        // Property and event accessors are marked as such, as MIDL does, even if the source metadata didn't.
+        if properties.iter().chain(&events).any(|member| member.methods.iter().any(|(_, accessor)| *accessor == method)) {
+            flags |= metadata::MethodAttributes::SpecialName;
         }
 
-        // TODO: if the class is "Apis" then should we sort the fields (constants) and methods (functions) for stability
+        let signature = method.signature(generics);
+        let return_type = winmd_type(&signature.return_type);
+        let param_types: Vec<Type> = signature.params.iter().map(winmd_type).collect();
 
-        for field in def.fields() {
-            let ty = winmd_type(&field.ty(Some(def)));
-            let signature = writer.insert_field_sig(&ty);
+        let signature = writer.insert_method_sig(signature.call_flags, &return_type, &param_types);
 
-            writer.tables.Field.push(Field { Flags: field.flags().0, Name: writer.strings.insert(field.name()), Signature: signature });
+        let row = writer.tables.MethodDef.len() as u32;
+        methods.insert(method, row);
+        insert_attributes(writer, HasAttribute::MethodDef(row), method);
+
+        if let Some(impl_map) = method.impl_map() {
+            writer.insert_impl_map(row, impl_map.flags().0 as u16, impl_map.scope().name(), impl_map.import_name());
         }
 
-        for method in def.methods() {
-            let signature = method.signature(generics);
-            let return_type = winmd_type(&signature.return_type);
-            let param_types: Vec<Type> = signature.params.iter().map(winmd_type).collect();
+        writer.tables.MethodDef.push(MethodDef {
+            RVA: 0,
+            ImplFlags: method.impl_flags().0,
+            Flags: flags.0,
+            Name: writer.strings.insert(method.name()),
+            Signature: signature,
+            ParamList: writer.tables.Param.len() as u32,
+        });
 
-            let signature = writer.insert_method_sig(signature.call_flags, &return_type, &param_types);
+        for param in method.params() {
+            let parent = HasAttribute::Param(writer.tables.Param.len() as u32);
+            insert_attributes(writer, parent, param);
+            writer.tables.Param.push(Param { Flags: param.flags().0, Sequence: param.sequence(), Name: writer.strings.insert(param.name()) });
+        }
+    }
 
-            writer.tables.MethodDef.push(MethodDef {
-                RVA: 0,
-                ImplFlags: method.impl_flags().0,
-                Flags: method.flags().0,
-                Name: writer.strings.insert(method.name()),
-                Signature: signature,
-                ParamList: writer.tables.Param.len() as u32,
-            });
+    if !properties.is_empty() {
+        writer.tables.PropertyMap.push(PropertyMap { Parent: writer.tables.TypeDef.len() as u32 - 1, PropertyList: writer.tables.Property.len() as u32 });
 
-            for param in method.params() {
-                writer.tables.Param.push(Param { Flags: param.flags().0, Sequence: param.sequence(), Name: writer.strings.insert(param.name()) });
+        for property in properties {
+            let signature = writer.insert_property_sig(&property.ty);
+            let association = HasSemantics::Property(writer.tables.Property.len() as u32).encode();
+            writer.tables.Property.push(Property { Flags: property.flags, Name: writer.strings.insert(&property.name), Type: signature });
+
+            // Accessors that weren't selected have no row to refer to, so their semantics are left out.
+            for (semantics, method) in property.methods.into_iter().filter_map(|(semantics, method)| Some((semantics, *methods.get(&method)?))) {
+                writer.tables.MethodSemantics.push(MethodSemantics { Semantics: semantics.0, Method: method, Association: association });
             }
         }
     }
 
-    // TODO: In theory, `config` could instruct this function to balance the types across a number of winmd files
-    // like mdmerge supports for namespace-splitting.
-    write_to_file(output, writer.into_stream()).map_err(|err| err.with_path(output))
+    if !events.is_empty() {
+        writer.tables.EventMap.push(EventMap { Parent: writer.tables.TypeDef.len() as u32 - 1, EventList: writer.tables.Event.len() as u32 });
+
+        for event in events {
+            let event_type = writer.insert_type_def_or_ref(event.ty);
+            let association = HasSemantics::Event(writer.tables.Event.len() as u32).encode();
+            writer.tables.Event.push(Event { EventFlags: event.flags, Name: writer.strings.insert(&event.name), EventType: event_type });
+
+            for (semantics, method) in event.methods.into_iter().filter_map(|(semantics, method)| Some((semantics, *methods.get(&method)?))) {
+                writer.tables.MethodSemantics.push(MethodSemantics { Semantics: semantics.0, Method: method, Association: association });
+            }
+        }
+    }
+}
+
+/// Copies the custom attributes of `row` to `parent`, which must be the row most recently added for it.
+fn insert_attributes<R: HasAttributes<'static>>(writer: &mut Writer, parent: HasAttribute, row: R) {
+    for attribute in row.attributes() {
+        let type_name = attribute.type_name();
+        writer.insert_attribute(parent.clone(), type_name.namespace, type_name.name, &winmd_args(attribute));
+    }
+}
+
+/// Invalid arguments are rejected by `verify`, so reading stops at the first one rather than writing it.
+pub fn winmd_args(attribute: metadata::Attribute) -> Vec<(metadata::ArgKind, String, Value)> {
+    attribute.kinded_args().iter().take_while(|(_, _, value)| !matches!(value, metadata::Value::Invalid)).map(|(kind, name, value)| (*kind, name.to_string(), winmd_value(value))).collect()
+}
+
+/// A property or event along with its accessor methods.
+struct Member {
+    flags: u16,
+    name: String,
+    ty: Type,
+    methods: Vec<(metadata::MethodSemanticsAttributes, metadata::MethodDef)>,
+}
+
+fn type_def_properties(def: metadata::TypeDef, generics: &[metadata::Type]) -> Vec<Member> {
+    def.properties()
+        .map(|property| Member {
+            flags: property.flags().0,
+            name: property.name().to_string(),
+            ty: winmd_type(&property.ty(generics)),
+            methods: property.methods().map(|semantics| (semantics.flags(), semantics.method())).collect(),
+        })
+        .collect()
+}
+
+fn type_def_events(def: metadata::TypeDef, generics: &[metadata::Type]) -> Vec<Member> {
+    def.events()
+        .map(|event| Member {
+            flags: event.flags().0,
+            name: event.name().to_string(),
+            ty: winmd_type(&event.ty(generics)),
+            methods: event.methods().map(|semantics| (semantics.flags(), semantics.method())).collect(),
+        })
+        .collect()
 }
 
 // TODO: keep the basic type conversion
-fn winmd_type(ty: &metadata::Type) -> Type {
+pub fn winmd_type(ty: &metadata::Type) -> Type {
     match ty {
         metadata::Type::Void => Type::Void,
         metadata::Type::Bool => Type::Bool,
@@ -121,6 +398,7 @@ fn winmd_type(ty: &metadata::Type) -> Type {
         metadata::Type::PCWSTR => Type::PCWSTR,
         metadata::Type::BSTR => Type::BSTR,
         metadata::Type::Type => Type::Type,
+        metadata::Type::TypeRef(type_name) => Type::TypeRef(winmd_type_name(*type_name)),
         metadata::Type::TypeDef(def, generics) => Type::TypeRef(TypeName { namespace: def.namespace().to_string(), name: def.name().to_string(), generics: generics.iter().map(winmd_type).collect() }),
         metadata::Type::GenericParam(generic) => Type::GenericParam(generic.number()),
         metadata::Type::ConstRef(ty) => Type::ConstRef(Box::new(winmd_type(ty))),
@@ -132,3 +410,32 @@ fn winmd_type(ty: &metadata::Type) -> Type {
         rest => unimplemented!("{rest:?}"),
     }
 }
+
+fn winmd_value(value: &metadata::Value) -> Value {
+    match value {
+        metadata::Value::Bool(value) => Value::Bool(*value),
+        metadata::Value::Char(value) => Value::Char(*value),
+        metadata::Value::U8(value) => Value::U8(*value),
+        metadata::Value::I8(value) => Value::I8(*value),
+        metadata::Value::U16(value) => Value::U16(*value),
+        metadata::Value::I16(value) => Value::I16(*value),
+        metadata::Value::U32(value) => Value::U32(*value),
+        metadata::Value::I32(value) => Value::I32(*value),
+        metadata::Value::U64(value) => Value::U64(*value),
+        metadata::Value::I64(value) => Value::I64(*value),
+        metadata::Value::F32(value) => Value::F32(*value),
+        metadata::Value::F64(value) => Value::F64(*value),
+        metadata::Value::String(value) => Value::String(value.clone()),
+        metadata::Value::TypeName(type_name) => Value::TypeName(winmd_type_name(*type_name)),
+        metadata::Value::EnumDef(def, value) => Value::Enum(winmd_type_name(def.type_name()), Box::new(winmd_value(value))),
+        metadata::Value::EnumRef(type_name, value) => Value::Enum(winmd_type_name(*type_name), Box::new(winmd_value(value))),
+        metadata::Value::Array(ty, values) => Value::Array(winmd_type(ty), values.iter().map(winmd_value).collect()),
+        metadata::Value::Object(value) => Value::Object(Box::new(winmd_value(value))),
+        metadata::Value::Null(ty) => Value::Null(winmd_type(ty)),
+        metadata::Value::Invalid => unreachable!("invalid arguments are skipped by `winmd_args`"),
+    }
+}
+
+fn winmd_type_name(type_name: metadata::TypeName) -> TypeName {
+    TypeName { namespace: type_name.namespace.to_string(), name: type_name.name.to_string(), generics: vec![] }
+}
@@ -0,0 +1,222 @@
+use super::*;
+use from_reader::{winmd_args, winmd_type, Members};
+use metadata::HasAttributes;
+use std::fmt::Write;
+
+/// A type definition chosen to represent its name in the merged output.
+pub struct Merged {
+    pub def: metadata::TypeDef,
+    /// The architectures to write in place of those of the definition's `SupportedArchitectureAttribute`, if any.
+    pub architectures: Option<i32>,
+}
+
+/// Reconciles type definitions that share a name, typically because they were defined by more than one input.
+///
+/// Identical definitions are written once, with their `SupportedArchitectureAttribute`s combined. Definitions that
+/// differ are kept side by side as long as they support disjoint sets of architectures, otherwise they conflict.
+pub fn merge(reader: &'static metadata::Reader, defs: Vec<metadata::TypeDef>) -> Result<Vec<Merged>> {
+    let mut groups = std::collections::BTreeMap::<metadata::TypeName, Vec<metadata::TypeDef>>::new();
+
+    for def in defs {
+        groups.entry(def.type_name()).or_default().push(def);
+    }
+
+    let mut merged = vec![];
+    let mut conflicts = vec![];
+
+    for (type_name, defs) in groups {
+        // Definitions with the same shape are interchangeable apart from the architectures they support.
+        let mut variants: Vec<(String, Vec<metadata::TypeDef>)> = vec![];
+
+        for def in defs {
+            let shape = shape(reader, def);
+
+            if let Some((_, defs)) = variants.iter_mut().find(|(existing, _)| *existing == shape) {
+                defs.push(def);
+            } else {
+                variants.push((shape, vec![def]));
+            }
+        }
+
+        let variants: Vec<Merged> = variants.into_iter().map(|(_, defs)| merge_identical(defs)).collect();
+
+        if variants.len() > 1 && !disjoint(&variants) {
+            conflicts.push(type_name.to_string());
+        } else {
+            merged.extend(variants);
+        }
+    }
+
+    if conflicts.is_empty() {
+        Ok(merged)
+    } else {
+        Err(Error::new(&format!("conflicting definitions of `{}`", conflicts.join("`, `"))))
+    }
+}
+
+/// Combines the functions and constants of the `Apis` classes that share a namespace into a single class.
+///
+/// Functions and constants that share a name are written once if they're identical, or side by side as long as they
+/// support disjoint sets of architectures, otherwise they conflict.
+pub fn merge_apis(apis: Vec<(metadata::TypeDef, Members)>) -> Result<Vec<(metadata::TypeDef, Members)>> {
+    let mut merged = Vec::<(metadata::TypeDef, Members)>::new();
+    let mut conflicts = vec![];
+
+    for (def, members) in apis {
+        let index = merged.iter().position(|(existing, _)| existing.type_name() == def.type_name()).unwrap_or_else(|| {
+            merged.push((def, Members::default()));
+            merged.len() - 1
+        });
+
+        let existing = &mut merged[index].1;
+
+        for field in members.fields {
+            if !merge_member(&mut existing.fields, field, |field| field.name(), |field| field_shape(field, def)) {
+                conflicts.push(format!("{}.{}", def.namespace(), field.name()));
+            }
+        }
+
+        for method in members.methods {
+            if !merge_member(&mut existing.methods, method, |method| method.name(), |method| method_shape(method, &[])) {
+                conflicts.push(format!("{}.{}", def.namespace(), method.name()));
+            }
+        }
+    }
+
+    if conflicts.is_empty() {
+        Ok(merged)
+    } else {
+        Err(Error::new(&format!("conflicting definitions of `{}`", conflicts.join("`, `"))))
+    }
+}
+
+/// Adds `member` to `members` unless an identical member is already present, returning `false` if it conflicts with a
+/// different member of the same name.
+fn merge_member<R: HasAttributes<'static> + Copy>(members: &mut Vec<R>, member: R, name: fn(R) -> &'static str, shape: impl Fn(R) -> String) -> bool {
+    let existing: Vec<R> = members.iter().copied().filter(|existing| name(*existing) == name(member)).collect();
+
+    if existing.is_empty() {
+        members.push(member);
+        return true;
+    }
+
+    let member_shape = shape(member);
+
+    if existing.iter().any(|existing| shape(*existing) == member_shape) {
+        return true;
+    }
+
+    let disjoint = existing.iter().chain(std::iter::once(&member)).try_fold(0, |all, row| {
+        let architectures = metadata::architectures(*row)?;
+        (all & architectures == 0).then_some(all | architectures)
+    });
+
+    if disjoint.is_some() {
+        members.push(member);
+    }
+
+    disjoint.is_some()
+}
+
+fn merge_identical(defs: Vec<metadata::TypeDef>) -> Merged {
+    // A definition that isn't restricted to specific architectures covers all of the others.
+    if let Some(def) = defs.iter().find(|def| metadata::architectures(**def).is_none()) {
+        return Merged { def: *def, architectures: None };
+    }
+
+    let architectures = defs.iter().filter_map(|def| metadata::architectures(*def)).fold(0, |all, architectures| all | architectures);
+    Merged { def: defs[0], architectures: (defs.len() > 1).then_some(architectures) }
+}
+
+fn disjoint(variants: &[Merged]) -> bool {
+    let mut all = 0;
+
+    for variant in variants {
+        let Some(architectures) = variant.architectures.or_else(|| metadata::architectures(variant.def)) else {
+            return false;
+        };
+
+        if all & architectures != 0 {
+            return false;
+        }
+
+        all |= architectures;
+    }
+
+    true
+}
+
+/// Describes everything about `def` that is written to the output, apart from its `SupportedArchitectureAttribute`,
+/// in a form that can be compared across input files.
+fn shape(reader: &'static metadata::Reader, def: metadata::TypeDef) -> String {
+    let generics = &metadata::type_def_generics(def);
+    let mut shape = format!("{:?} {:?}", def.flags().0, def.extends());
+
+    if let Some(layout) = def.class_layout() {
+        _ = write!(shape, " layout {} {}", layout.packing_size(), layout.class_size());
+    }
+
+    for generic in def.generics() {
+        _ = write!(shape, " <{}>", generic.name());
+    }
+
+    for interface in metadata::type_def_interfaces(def, generics) {
+        _ = write!(shape, " : {:?}", winmd_type(&interface.ty));
+    }
+
+    for attribute in def.attributes().filter(|attribute| attribute.name() != "SupportedArchitectureAttribute") {
+        write_attribute(&mut shape, attribute);
+    }
+
+    for field in def.fields() {
+        shape.push_str(&field_shape(field, def));
+    }
+
+    for method in def.methods() {
+        shape.push_str(&method_shape(method, generics));
+    }
+
+    for property in def.properties() {
+        _ = write!(shape, "\nproperty {} {} {:?}", property.flags().0, property.name(), winmd_type(&property.ty(generics)));
+        property.methods().for_each(|semantics| _ = write!(shape, " {} {}", semantics.flags().0, semantics.method().name()));
+    }
+
+    for event in def.events() {
+        _ = write!(shape, "\nevent {} {} {:?}", event.flags().0, event.name(), winmd_type(&event.ty(generics)));
+        event.methods().for_each(|semantics| _ = write!(shape, " {} {}", semantics.flags().0, semantics.method().name()));
+    }
+
+    for nested in reader.nested_types(def) {
+        _ = write!(shape, "\nnested {} {{\n{}\n}}", nested.name(), self::shape(reader, nested));
+    }
+
+    shape
+}
+
+fn field_shape(field: metadata::Field, def: metadata::TypeDef) -> String {
+    let mut shape = format!("\nfield {} {} {:?} {:?}", field.flags().0, field.name(), winmd_type(&field.ty(Some(def))), field.constant().map(|constant| constant.value()));
+    field.attributes().for_each(|attribute| write_attribute(&mut shape, attribute));
+    shape
+}
+
+fn method_shape(method: metadata::MethodDef, generics: &[metadata::Type]) -> String {
+    let signature = method.signature(generics);
+    let params: Vec<Type> = signature.params.iter().map(winmd_type).collect();
+    let mut shape = format!("\nmethod {} {} {} {} {:?} {:?}", method.flags().0, method.impl_flags().0, method.name(), signature.call_flags.0, winmd_type(&signature.return_type), params);
+    method.attributes().for_each(|attribute| write_attribute(&mut shape, attribute));
+
+    if let Some(impl_map) = method.impl_map() {
+        _ = write!(shape, " import {} {} {}", impl_map.flags().0, impl_map.scope().name(), impl_map.import_name());
+    }
+
+    for param in method.params() {
+        _ = write!(shape, "\nparam {} {} {}", param.flags().0, param.sequence(), param.name());
+        param.attributes().for_each(|attribute| write_attribute(&mut shape, attribute));
+    }
+
+    shape
+}
+
+fn write_attribute(shape: &mut String, attribute: metadata::Attribute) {
+    _ = write!(shape, " #[{}{:?}]", attribute.type_name(), winmd_args(attribute));
+}
@@ -1,7 +1,10 @@
 mod from_reader;
+mod merge;
+mod split;
 mod verify;
 pub mod writer;
 use super::*;
 pub use from_reader::from_reader;
+use split::Split;
 pub use verify::verify;
 pub use writer::*;
@@ -0,0 +1,76 @@
+use super::*;
+use metadata::HasAttributes;
+use std::collections::BTreeMap;
+
+/// Decides which file each type is written to when the output is split across multiple winmd files.
+pub struct Split {
+    mode: Mode,
+    /// Namespace prefixes and the assemblies they're written to, longest prefix first.
+    prefixes: Vec<(String, String)>,
+}
+
+#[derive(PartialEq, Eq)]
+enum Mode {
+    None,
+    Namespace,
+    Contract,
+}
+
+impl Split {
+    /// Removes the `split` configuration value, which may be `namespace` or `contract`, along with any
+    /// `split:<namespace>=<assembly>` values that map a namespace and its children to a specific assembly.
+    pub fn new(config: &mut BTreeMap<&str, &str>) -> Result<Self> {
+        let mode = match config.remove("split") {
+            None => Mode::None,
+            Some("namespace") => Mode::Namespace,
+            Some("contract") => Mode::Contract,
+            _ => return Err(Error::new("configuration value `split` must be `namespace` or `contract`")),
+        };
+
+        let mut prefixes = vec![];
+
+        for (key, value) in config.iter() {
+            if let Some(prefix) = key.strip_prefix("split:") {
+                if prefix.is_empty() || value.is_empty() {
+                    return Err(Error::new(&format!("configuration value `{key}` must map a namespace to an assembly")));
+                }
+
+                prefixes.push((prefix.to_string(), value.to_string()));
+            }
+        }
+
+        config.retain(|key, _| !key.starts_with("split:"));
+        prefixes.sort_by_key(|(prefix, _)| std::cmp::Reverse(prefix.len()));
+
+        Ok(Self { mode, prefixes })
+    }
+
+    /// Returns the assembly of the file that `def` should be written to or `None` if it belongs in the main output.
+    pub fn assembly(&self, def: metadata::TypeDef) -> Option<String> {
+        let namespace = def.namespace();
+
+        if let Some((_, assembly)) = self.prefixes.iter().find(|(prefix, _)| namespace == *prefix || namespace.strip_prefix(prefix).is_some_and(|rest| rest.starts_with('.'))) {
+            return Some(assembly.clone());
+        }
+
+        match self.mode {
+            Mode::None => None,
+            Mode::Namespace => Some(namespace.to_string()),
+            Mode::Contract => contract(def),
+        }
+    }
+}
+
+/// Returns the name of the API contract that `def` belongs to. Contracts are themselves types marked with the
+/// `ApiContractAttribute` and are written alongside the types they contain.
+fn contract(def: metadata::TypeDef) -> Option<String> {
+    if def.find_attribute("ApiContractAttribute").is_some() {
+        return Some(def.type_name().to_string());
+    }
+
+    match def.find_attribute("ContractVersionAttribute")?.args().first()? {
+        (_, metadata::Value::TypeName(type_name)) => Some(type_name.to_string()),
+        (_, metadata::Value::String(name)) => Some(name.clone()),
+        _ => None,
+    }
+}
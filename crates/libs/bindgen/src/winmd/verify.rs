@@ -1,6 +1,12 @@
 use super::*;
+use metadata::HasAttributes;
 
-pub fn verify(reader: &metadata::Reader) -> Result<()> {
+/// Checks the filtered metadata before any output is written, returning any warnings that were asked for.
+///
+/// Errors are problems that would produce invalid output, such as missing type definitions or pointers in WinRT
+/// structs, while warnings are problems that can be written as is but probably weren't intended. Warnings are only
+/// returned with the `warnings` configuration value, while the `strict` configuration value treats them as errors.
+pub fn verify(reader: &'static metadata::Reader, config: &mut std::collections::BTreeMap<&str, &str>) -> Result<Vec<String>> {
     let unused: Vec<&str> = reader.unused().collect();
 
     if !unused.is_empty() {
@@ -13,30 +19,238 @@ pub fn verify(reader: &metadata::Reader) -> Result<()> {
         return Err(Error::new(&message));
     }
 
+    let strict = config.remove("strict").is_some();
+    let report = config.remove("warnings").is_some();
+    let mut verifier = Verifier::default();
+
     for item in reader.items() {
-        // TODO: cover all variants
-        let metadata::Item::Type(def) = item else {
-            continue;
-        };
+        match item {
+            metadata::Item::Type(def) => verifier.type_def(def),
+            metadata::Item::Fn(method, _) => {
+                let signature = method.signature(&[]);
 
-        let generics = &metadata::type_def_generics(def);
+                // Functions and constants are often filtered from metadata that depends on other metadata, such as the
+                // Wdk without Win32, and references to types that aren't available are written as is.
+                verifier.ty(&signature.return_type, method.name(), Severity::Warning);
+                signature.params.iter().for_each(|param| verifier.ty(param, method.name(), Severity::Warning));
+            }
+            metadata::Item::Const(field) => verifier.ty(&field.ty(None), field.name(), Severity::Warning),
+        }
+    }
+
+    verifier.guids();
 
-        def.fields().try_for_each(|field| not_type_ref(&field.ty(Some(def))))?;
+    let (errors, warnings): (Vec<_>, Vec<_>) = verifier.diagnostics.into_iter().partition(|(severity, _)| strict || *severity == Severity::Error);
 
-        def.methods().try_for_each(|method| {
-            let sig = method.signature(generics);
-            not_type_ref(&sig.return_type)?;
+    if !errors.is_empty() {
+        let mut message = "invalid metadata".to_string();
 
-            sig.params.iter().try_for_each(not_type_ref)
-        })?;
+        for (_, error) in errors {
+            message.push_str(&format!("\n  {error}"));
+        }
+
+        return Err(Error::new(&message));
     }
 
-    Ok(())
+    if report {
+        Ok(warnings.into_iter().map(|(_, warning)| warning).collect())
+    } else {
+        Ok(vec![])
+    }
 }
 
-fn not_type_ref(ty: &metadata::Type) -> Result<()> {
-    if let metadata::Type::TypeRef(ty) = ty {
-        return Err(Error::new(&format!("missing type definition `{}`", ty)));
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Severity {
+    Warning,
+    Error,
+}
+
+#[derive(Default)]
+struct Verifier {
+    diagnostics: Vec<(Severity, String)>,
+    /// The types that declare each GUID and whether they're WinRT types.
+    guids: std::collections::BTreeMap<String, std::collections::BTreeMap<String, bool>>,
+}
+
+impl Verifier {
+    fn error(&mut self, message: String) {
+        self.diagnostics.push((Severity::Error, message));
+    }
+
+    fn warning(&mut self, message: String) {
+        self.diagnostics.push((Severity::Warning, message));
     }
-    Ok(())
+
+    fn report(&mut self, severity: Severity, message: String) {
+        self.diagnostics.push((severity, message));
+    }
+
+    fn type_def(&mut self, def: metadata::TypeDef) {
+        let type_name = def.type_name();
+        let winrt = def.flags().contains(metadata::TypeAttributes::WindowsRuntime);
+        let generics = &metadata::type_def_generics(def);
+
+        self.attributes(def, &type_name.to_string());
+
+        // WinRT generic types encode their arity in their names, as in `IVector`1`.
+        if let Some((_, arity)) = def.name().rsplit_once('`') {
+            if arity.parse() != Ok(def.generics().count()) {
+                self.error(format!("`{type_name}` has {} generic parameters", def.generics().count()));
+            }
+        }
+
+        let mut fields = std::collections::BTreeSet::new();
+
+        for field in def.fields() {
+            let name = format!("{type_name}.{}", field.name());
+            self.attributes(field, &name);
+
+            if !fields.insert(field.name()) {
+                self.error(format!("duplicate field `{name}`"));
+            }
+
+            let ty = field.ty(Some(def));
+            self.ty(&ty, &name, Severity::Error);
+
+            if winrt && def.kind() == metadata::TypeKind::Struct && matches!(ty, metadata::Type::MutPtr(..) | metadata::Type::ConstPtr(..) | metadata::Type::Win32Array(..)) {
+                self.error(format!("WinRT struct field `{name}` can't be a pointer or fixed-size array"));
+            }
+        }
+
+        let mut methods = std::collections::BTreeMap::<&str, Vec<metadata::MethodDef>>::new();
+
+        for method in def.methods() {
+            let name = format!("{type_name}.{}", method.name());
+            self.attributes(method, &name);
+            method.params().for_each(|param| self.attributes(param, &format!("{name}.{}", param.name())));
+
+            let signature = method.signature(generics);
+            self.ty(&signature.return_type, &name, Severity::Error);
+            signature.params.iter().for_each(|param| self.ty(param, &name, Severity::Error));
+
+            methods.entry(method.name()).or_default().push(method);
+        }
+
+        if def.kind() != metadata::TypeKind::Enum && def.has_attribute("FlagsAttribute") {
+            self.warning(format!("`FlagsAttribute` on `{type_name}`, which isn't an enum"));
+        }
+
+        match def.kind() {
+            metadata::TypeKind::Interface => self.overloads(def, methods),
+            metadata::TypeKind::Enum => self.enum_def(def),
+            metadata::TypeKind::Class if winrt && !def.flags().contains(metadata::TypeAttributes::Sealed) && !def.has_attribute("ComposableAttribute") => {
+                self.warning(format!("WinRT class `{type_name}` isn't sealed or composable"));
+            }
+            _ => {}
+        }
+
+        if def.kind() == metadata::TypeKind::Interface || (winrt && def.kind() == metadata::TypeKind::Delegate) {
+            // Some Win32 callback interfaces have no GUID, so this is only a warning. In .rdl, a GUID is given by `#[guid]`.
+            match def.find_attribute("GuidAttribute").filter(guid_args) {
+                Some(attribute) => _ = self.guids.entry(format!("{:?}", metadata::Guid::from_args(&attribute.args()))).or_default().insert(type_name.to_string(), winrt),
+                None => self.warning(format!("`{type_name}` has no GUID")),
+            }
+        }
+    }
+
+    /// Checks that methods sharing a name have distinct overload names.
+    fn overloads(&mut self, def: metadata::TypeDef, methods: std::collections::BTreeMap<&str, Vec<metadata::MethodDef>>) {
+        let winrt = def.flags().contains(metadata::TypeAttributes::WindowsRuntime);
+
+        for (name, methods) in methods.into_iter().filter(|(_, methods)| methods.len() > 1) {
+            let mut overloads = std::collections::BTreeSet::new();
+
+            let distinct = methods.iter().all(|method| {
+                let overload = match method.find_attribute("OverloadAttribute").map(|attribute| attribute.args()).as_deref() {
+                    Some([(_, metadata::Value::String(overload))]) => overload.clone(),
+                    _ => name.to_string(),
+                };
+
+                overloads.insert(overload)
+            });
+
+            if !distinct {
+                self.report(if winrt { Severity::Error } else { Severity::Warning }, format!("duplicate method `{}.{name}`", def.type_name()));
+            }
+        }
+    }
+
+    fn enum_def(&mut self, def: metadata::TypeDef) {
+        let type_name = def.type_name();
+
+        if def.fields().next().is_none() {
+            self.error(format!("enum `{type_name}` has no underlying type"));
+            return;
+        }
+
+        let underlying_type = def.underlying_type();
+
+        let valid = if def.flags().contains(metadata::TypeAttributes::WindowsRuntime) { matches!(underlying_type, metadata::Type::I32 | metadata::Type::U32) } else { matches!(underlying_type, metadata::Type::I8 | metadata::Type::U8 | metadata::Type::I16 | metadata::Type::U16 | metadata::Type::I32 | metadata::Type::U32 | metadata::Type::I64 | metadata::Type::U64) };
+
+        if !valid {
+            self.error(format!("enum `{type_name}` has an invalid underlying type `{underlying_type:?}`"));
+            return;
+        }
+
+        for field in def.fields() {
+            if let Some(constant) = field.constant() {
+                if constant.ty() != underlying_type {
+                    self.error(format!("enumerator `{type_name}.{}` doesn't match the enum's underlying type", field.name()));
+                }
+            }
+        }
+    }
+
+    fn attributes<R: HasAttributes<'static>>(&mut self, row: R, name: &str) {
+        let mut names = std::collections::BTreeSet::new();
+
+        for attribute in row.attributes() {
+            let attribute_name = attribute.name();
+
+            // These attributes describe a single property of the row they're applied to.
+            if matches!(attribute_name, "GuidAttribute" | "FlagsAttribute" | "ScopedEnumAttribute" | "NativeTypedefAttribute" | "SupportedArchitectureAttribute" | "OverloadAttribute" | "DefaultOverloadAttribute") && !names.insert(attribute_name) {
+                self.error(format!("duplicate `{attribute_name}` on `{name}`"));
+            }
+
+            if attribute_name == "GuidAttribute" && !guid_args(&attribute) {
+                self.error(format!("`{name}` has an invalid `GuidAttribute`"));
+            }
+
+            if attribute.args().iter().any(|(_, value)| matches!(value, metadata::Value::Invalid)) {
+                self.error(format!("`{name}` has an unreadable `{attribute_name}` argument"));
+            }
+        }
+    }
+
+    /// Checks that `ty`, which is used by `name`, refers to types that are defined and instantiated correctly. The
+    /// `missing` severity applies to references to types that aren't defined by any input.
+    fn ty(&mut self, ty: &metadata::Type, name: &str, missing: Severity) {
+        match ty {
+            metadata::Type::TypeRef(type_name) => self.report(missing, format!("missing type definition `{type_name}` used by `{name}`")),
+            metadata::Type::TypeDef(def, generics) => {
+                if !generics.is_empty() && generics.len() != def.generics().count() {
+                    self.error(format!("`{}` has {} generic parameters but `{name}` provides {}", def.type_name(), def.generics().count(), generics.len()));
+                }
+
+                generics.iter().for_each(|ty| self.ty(ty, name, missing));
+            }
+            metadata::Type::MutPtr(ty, _) | metadata::Type::ConstPtr(ty, _) | metadata::Type::Win32Array(ty, _) | metadata::Type::WinrtArray(ty) | metadata::Type::WinrtArrayRef(ty) | metadata::Type::ConstRef(ty) => self.ty(ty, name, missing),
+            _ => {}
+        }
+    }
+
+    /// Checks that each GUID is declared by only one type.
+    fn guids(&mut self) {
+        for (guid, types) in std::mem::take(&mut self.guids) {
+            if types.len() > 1 {
+                let severity = if types.values().any(|winrt| *winrt) { Severity::Error } else { Severity::Warning };
+                self.report(severity, format!("GUID `{guid}` is shared by `{}`", types.keys().cloned().collect::<Vec<_>>().join("`, `")));
+            }
+        }
+    }
+}
+
+fn guid_args(attribute: &metadata::Attribute) -> bool {
+    let args = attribute.args();
+    args.len() == 11 && matches!(args[0].1, metadata::Value::U32(_)) && args[1..3].iter().all(|(_, value)| matches!(value, metadata::Value::U16(_))) && args[3..].iter().all(|(_, value)| matches!(value, metadata::Value::U8(_)))
 }
@@ -56,6 +56,22 @@ impl HasConstant {
     }
 }
 
+/// A `HasSemantics` is an index into a certain table used to identify the property or event associated with a row in the `MethodSemantics` table.
+#[derive(Clone)]
+pub enum HasSemantics {
+    Event(u32),
+    Property(u32),
+}
+
+impl HasSemantics {
+    pub fn encode(&self) -> u32 {
+        match self {
+            Self::Event(row) => (row + 1) << 1,
+            Self::Property(row) => ((row + 1) << 1) + 1,
+        }
+    }
+}
+
 /// A `TypeOrMethodDef` is an index into a certain table used to locate the owner of a generic parameter.
 #[derive(Clone)]
 pub enum TypeOrMethodDef {
@@ -69,3 +85,95 @@ impl TypeOrMethodDef {
         }
     }
 }
+
+/// A `HasAttribute` is an index into a certain table used to identify the parent of a row in the `CustomAttribute` table.
+#[derive(Clone)]
+pub enum HasAttribute {
+    MethodDef(u32),
+    Field(u32),
+    TypeRef(u32),
+    TypeDef(u32),
+    Param(u32),
+    InterfaceImpl(u32),
+    MemberRef(u32),
+    Module(u32),
+    Property(u32),
+    Event(u32),
+    TypeSpec(u32),
+    Assembly(u32),
+    GenericParam(u32),
+}
+
+impl HasAttribute {
+    pub fn encode(&self) -> u32 {
+        match self {
+            Self::MethodDef(row) => (row + 1) << 5,
+            Self::Field(row) => ((row + 1) << 5) + 1,
+            Self::TypeRef(row) => ((row + 1) << 5) + 2,
+            Self::TypeDef(row) => ((row + 1) << 5) + 3,
+            Self::Param(row) => ((row + 1) << 5) + 4,
+            Self::InterfaceImpl(row) => ((row + 1) << 5) + 5,
+            Self::MemberRef(row) => ((row + 1) << 5) + 6,
+            Self::Module(row) => ((row + 1) << 5) + 7,
+            Self::Property(row) => ((row + 1) << 5) + 9,
+            Self::Event(row) => ((row + 1) << 5) + 10,
+            Self::TypeSpec(row) => ((row + 1) << 5) + 13,
+            Self::Assembly(row) => ((row + 1) << 5) + 14,
+            Self::GenericParam(row) => ((row + 1) << 5) + 19,
+        }
+    }
+}
+
+/// A `MemberRefParent` is an index into a certain table used to identify the owner of a row in the `MemberRef` table.
+#[derive(Clone)]
+pub enum MemberRefParent {
+    TypeDef(u32),
+    TypeRef(u32),
+    ModuleRef(u32),
+    MethodDef(u32),
+    TypeSpec(u32),
+}
+
+impl MemberRefParent {
+    pub fn encode(&self) -> u32 {
+        match self {
+            Self::TypeDef(row) => (row + 1) << 3,
+            Self::TypeRef(row) => ((row + 1) << 3) + 1,
+            Self::ModuleRef(row) => ((row + 1) << 3) + 2,
+            Self::MethodDef(row) => ((row + 1) << 3) + 3,
+            Self::TypeSpec(row) => ((row + 1) << 3) + 4,
+        }
+    }
+}
+
+/// An `AttributeType` is an index into a certain table used to locate the constructor of a custom attribute.
+#[derive(Clone)]
+pub enum AttributeType {
+    MethodDef(u32),
+    MemberRef(u32),
+}
+
+impl AttributeType {
+    pub fn encode(&self) -> u32 {
+        match self {
+            Self::MethodDef(row) => ((row + 1) << 3) + 2,
+            Self::MemberRef(row) => ((row + 1) << 3) + 3,
+        }
+    }
+}
+
+/// A `MemberForwarded` is an index into a certain table used to identify the field or method imported by a row in the `ImplMap` table.
+#[derive(Clone)]
+pub enum MemberForwarded {
+    Field(u32),
+    MethodDef(u32),
+}
+
+impl MemberForwarded {
+    pub fn encode(&self) -> u32 {
+        match self {
+            Self::Field(row) => (row + 1) << 1,
+            Self::MethodDef(row) => ((row + 1) << 1) + 1,
+        }
+    }
+}
@@ -5,6 +5,7 @@ mod strings;
 mod tables;
 mod traits;
 mod r#type;
+mod value;
 
 use super::*;
 use blobs::Blobs;
@@ -14,6 +15,7 @@ use std::collections::HashMap;
 use strings::Strings;
 pub use tables::*;
 use traits::*;
+pub use value::*;
 
 pub struct Writer {
     pub blobs: Blobs,
@@ -23,6 +25,10 @@ pub struct Writer {
     // TODO: is this faster than jsut using a single HashMap with a (String,String) key?
     pub type_refs: HashMap<String, HashMap<String, u32>>,
     pub type_specs: HashMap<Type, u32>,
+    pub constructors: HashMap<(u32, u32), u32>,
+    pub module_refs: HashMap<String, u32>,
+    /// The assemblies defining types written to sibling files, by namespace and name, when the output is split.
+    pub assemblies: HashMap<String, HashMap<String, String>>,
 }
 
 impl Writer {
@@ -34,6 +40,9 @@ impl Writer {
             scopes: Default::default(),
             type_refs: Default::default(),
             type_specs: Default::default(),
+            constructors: Default::default(),
+            module_refs: Default::default(),
+            assemblies: Default::default(),
         };
 
         writer.tables.TypeDef.push(TypeDef { TypeName: writer.strings.insert("<Module>"), ..Default::default() });
@@ -42,7 +51,7 @@ impl Writer {
 
         writer.tables.Module.push(Module { Name: writer.strings.insert(name), Mvid: 1, ..Default::default() });
 
-        let name = name.rsplit_once('.').map_or(name, |(_, name)| name);
+        let name = name.rsplit_once('.').map_or(name, |(name, _)| name);
 
         writer.tables.Assembly.push(Assembly {
             Name: writer.strings.insert(name),
@@ -88,6 +97,146 @@ impl Writer {
         self.blobs.insert(&blob)
     }
 
+    pub fn insert_property_sig(&mut self, ty: &Type) -> u32 {
+        let mut blob = vec![0x28, 0]; // PROPERTY | HASTHIS with no parameters
+        self.type_blob(ty, &mut blob);
+
+        self.blobs.insert(&blob)
+    }
+
+    /// Adds a custom attribute to `parent`. Fixed arguments are passed to the attribute's constructor and must precede
+    /// the named arguments, which are encoded as fields or properties. See II.23.3 in ECMA-335.
+    pub fn insert_attribute(&mut self, parent: HasAttribute, namespace: &str, name: &str, args: &[(metadata::ArgKind, String, Value)]) {
+        let fixed_count = args.iter().take_while(|(kind, _, _)| *kind == metadata::ArgKind::Fixed).count();
+        let (fixed, named) = args.split_at(fixed_count);
+
+        let param_types: Vec<Type> = fixed.iter().map(|(_, _, value)| value.ty()).collect();
+        let signature = self.insert_method_sig(metadata::MethodCallAttributes::HASTHIS, &Type::Void, &param_types);
+
+        // `insert_type_ref` returns a `TypeDefOrRef` code so the row has to be recovered from it.
+        let class = MemberRefParent::TypeRef((self.insert_type_ref(namespace, name) >> 2) - 1).encode();
+
+        let constructor = if let Some(constructor) = self.constructors.get(&(class, signature)) {
+            *constructor
+        } else {
+            let constructor = self.tables.MemberRef.push2(MemberRef { Class: class, Name: self.strings.insert(".ctor"), Signature: signature });
+            self.constructors.insert((class, signature), constructor);
+            constructor
+        };
+
+        let mut blob = vec![1, 0]; // Prolog
+
+        for (_, _, value) in fixed {
+            self.value_blob(value, &mut blob);
+        }
+
+        blob.extend_from_slice(&(named.len() as u16).to_le_bytes());
+
+        for (kind, name, value) in named {
+            blob.push(if *kind == metadata::ArgKind::Field { metadata::SERIALIZATION_TYPE_FIELD } else { metadata::SERIALIZATION_TYPE_PROPERTY });
+            self.field_or_prop_type_blob(&value.ty(), &mut blob);
+            str_blob(name, &mut blob);
+            self.value_blob(value, &mut blob);
+        }
+
+        let value = self.blobs.insert(&blob);
+        self.tables.CustomAttribute.push(CustomAttribute { Parent: parent.encode(), Type: AttributeType::MemberRef(constructor).encode(), Value: value });
+    }
+
+    pub fn insert_constant(&mut self, parent: HasConstant, value: &Value) {
+        let mut ty = vec![];
+        self.type_blob(&value.ty(), &mut ty);
+
+        let mut blob = vec![];
+
+        if let Value::String(value) = value {
+            // Unlike attribute arguments, constant strings are UTF-16 and the blob's length doubles as the string's length.
+            value.encode_utf16().for_each(|value| blob.extend_from_slice(&value.to_le_bytes()));
+        } else {
+            self.value_blob(value, &mut blob);
+        }
+
+        let value = self.blobs.insert(&blob);
+        self.tables.Constant.push(Constant { Type: ty[0] as u16, Parent: parent.encode(), Value: value });
+    }
+
+    /// Adds the `ImplMap` row that imports `method` from the DLL named `module`.
+    pub fn insert_impl_map(&mut self, method: u32, flags: u16, module: &str, import_name: &str) {
+        let scope = if let Some(scope) = self.module_refs.get(module) {
+            *scope
+        } else {
+            let scope = self.tables.ModuleRef.push2(ModuleRef { Name: self.strings.insert(module) });
+            self.module_refs.insert(module.to_string(), scope);
+            scope
+        };
+
+        self.tables.ImplMap.push(ImplMap {
+            MappingFlags: flags,
+            MemberForwarded: MemberForwarded::MethodDef(method).encode(),
+            ImportName: self.strings.insert(import_name),
+            ImportScope: scope,
+        });
+    }
+
+    fn value_blob(&mut self, value: &Value, blob: &mut Vec<u8>) {
+        match value {
+            Value::Bool(value) => blob.push(*value as u8),
+            Value::Char(value) => blob.extend_from_slice(&value.to_le_bytes()),
+            Value::I8(value) => blob.extend_from_slice(&value.to_le_bytes()),
+            Value::U8(value) => blob.push(*value),
+            Value::I16(value) => blob.extend_from_slice(&value.to_le_bytes()),
+            Value::U16(value) => blob.extend_from_slice(&value.to_le_bytes()),
+            Value::I32(value) => blob.extend_from_slice(&value.to_le_bytes()),
+            Value::U32(value) => blob.extend_from_slice(&value.to_le_bytes()),
+            Value::I64(value) => blob.extend_from_slice(&value.to_le_bytes()),
+            Value::U64(value) => blob.extend_from_slice(&value.to_le_bytes()),
+            Value::F32(value) => blob.extend_from_slice(&value.to_le_bytes()),
+            Value::F64(value) => blob.extend_from_slice(&value.to_le_bytes()),
+            Value::String(value) => str_blob(value, blob),
+            Value::TypeName(type_name) => str_blob(&serialized_type_name(type_name), blob),
+            Value::Enum(_, value) => self.value_blob(value, blob),
+            Value::Array(_, values) => {
+                blob.extend_from_slice(&(values.len() as u32).to_le_bytes());
+
+                for value in values {
+                    self.value_blob(value, blob);
+                }
+            }
+            Value::Object(value) => {
+                self.field_or_prop_type_blob(&value.ty(), blob);
+                self.value_blob(value, blob);
+            }
+            Value::Null(Type::WinrtArray(_)) => blob.extend_from_slice(&u32::MAX.to_le_bytes()),
+            Value::Null(_) => blob.push(0xFF),
+        }
+    }
+
+    fn field_or_prop_type_blob(&mut self, ty: &Type, blob: &mut Vec<u8>) {
+        match ty {
+            Type::Type => blob.push(metadata::SERIALIZATION_TYPE_TYPE),
+            Type::IInspectable => blob.push(metadata::SERIALIZATION_TYPE_TAGGED_OBJECT),
+            Type::TypeRef(type_name) => {
+                blob.push(metadata::SERIALIZATION_TYPE_ENUM);
+                str_blob(&serialized_type_name(type_name), blob);
+            }
+            Type::WinrtArray(ty) => {
+                blob.push(metadata::ELEMENT_TYPE_SZARRAY);
+                self.field_or_prop_type_blob(ty, blob);
+            }
+            _ => self.type_blob(ty, blob),
+        }
+    }
+
+    pub fn insert_type_def_or_ref(&mut self, ty: Type) -> u32 {
+        match &ty {
+            Type::TypeRef(type_name) if type_name.generics.is_empty() => self.insert_type_ref(&type_name.namespace, &type_name.name),
+            Type::TypeRef(_) => self.insert_type_spec(ty),
+            Type::IUnknown => self.insert_type_ref("Windows.Win32.System.Com", "IUnknown"),
+            Type::IInspectable => self.insert_type_ref("Windows.Win32.System.WinRT", "IInspectable"),
+            rest => unimplemented!("{rest:?}"),
+        }
+    }
+
     fn insert_scope(&mut self, namespace: &str) -> u32 {
         if let Some(scope) = self.scopes.get(namespace) {
             *scope
@@ -125,7 +274,9 @@ impl Writer {
             }
         }
 
-        let scope = self.insert_scope(namespace);
+        // Types defined by a sibling file resolve to that file's assembly rather than the namespace.
+        let assembly = self.assemblies.get(namespace).and_then(|names| names.get(name)).cloned();
+        let scope = self.insert_scope(assembly.as_deref().unwrap_or(namespace));
 
         let reference = TypeDefOrRef::TypeRef(self.tables.TypeRef.push2(TypeRef { TypeName: self.strings.insert(name), TypeNamespace: self.strings.insert(namespace), ResolutionScope: scope })).encode();
         self.type_refs.entry(namespace.to_string()).or_default().insert(name.to_string(), reference);
@@ -198,7 +349,7 @@ impl Writer {
                 usize_blob(code as usize, blob);
             }
             Type::IUnknown => {
-                let code = self.insert_type_ref("Windows.Win32.Foundation", "IUnknown");
+                let code = self.insert_type_ref("Windows.Win32.System.Com", "IUnknown");
                 blob.push(metadata::ELEMENT_TYPE_VALUETYPE);
                 usize_blob(code as usize, blob);
             }
@@ -253,6 +404,19 @@ impl Writer {
     }
 }
 
+fn str_blob(value: &str, blob: &mut Vec<u8>) {
+    usize_blob(value.len(), blob);
+    blob.extend_from_slice(value.as_bytes());
+}
+
+fn serialized_type_name(type_name: &TypeName) -> String {
+    if type_name.namespace.is_empty() {
+        type_name.name.clone()
+    } else {
+        format!("{}.{}", type_name.namespace, type_name.name)
+    }
+}
+
 fn round(size: usize, round: usize) -> usize {
     let round = round - 1;
     (size + round) & !round
@@ -11,17 +11,21 @@ pub struct Tables {
     pub ClassLayout: Vec<ClassLayout>,
     pub Constant: Vec<Constant>,
     pub CustomAttribute: Vec<CustomAttribute>,
+    pub Event: Vec<Event>,
+    pub EventMap: Vec<EventMap>,
     pub Field: Vec<Field>,
     pub GenericParam: Vec<GenericParam>,
     pub ImplMap: Vec<ImplMap>,
     pub InterfaceImpl: Vec<InterfaceImpl>,
     pub MemberRef: Vec<MemberRef>,
     pub MethodDef: Vec<MethodDef>,
+    pub MethodSemantics: Vec<MethodSemantics>,
     pub Module: Vec<Module>,
     pub ModuleRef: Vec<ModuleRef>,
     pub NestedClass: Vec<NestedClass>,
     pub Param: Vec<Param>,
     pub Property: Vec<Property>,
+    pub PropertyMap: Vec<PropertyMap>,
     pub TypeDef: Vec<TypeDef>,
     pub TypeRef: Vec<TypeRef>,
     pub TypeSpec: Vec<TypeSpec>,
@@ -74,6 +78,19 @@ pub struct CustomAttribute {
     pub Value: u32,
 }
 
+#[derive(Default)]
+pub struct Event {
+    pub EventFlags: u16,
+    pub Name: u32,
+    pub EventType: u32,
+}
+
+#[derive(Default)]
+pub struct EventMap {
+    pub Parent: u32,
+    pub EventList: u32,
+}
+
 #[derive(Default)]
 pub struct Field {
     pub Flags: u16,
@@ -120,6 +137,13 @@ pub struct MethodDef {
     pub ParamList: u32,
 }
 
+#[derive(Default)]
+pub struct MethodSemantics {
+    pub Semantics: u16,
+    pub Method: u32,
+    pub Association: u32,
+}
+
 #[derive(Default)]
 pub struct Module {
     pub Generation: u16,
@@ -154,6 +178,12 @@ pub struct Property {
     pub Type: u32,
 }
 
+#[derive(Default)]
+pub struct PropertyMap {
+    pub Parent: u32,
+    pub PropertyList: u32,
+}
+
 #[derive(Default)]
 pub struct TypeDef {
     pub Flags: u32,
@@ -177,8 +207,34 @@ pub struct TypeSpec {
 }
 
 impl Tables {
-    pub fn into_stream(self) -> Vec<u8> {
-        if [self.AssemblyRef.len(), self.ClassLayout.len(), self.Constant.len(), self.CustomAttribute.len(), self.Field.len(), self.GenericParam.len(), self.ImplMap.len(), self.InterfaceImpl.len(), self.MemberRef.len(), self.MethodDef.len(), self.Module.len(), self.ModuleRef.len(), self.NestedClass.len(), self.Param.len(), self.Property.len(), self.TypeDef.len(), self.TypeRef.len(), self.TypeSpec.len()].iter().any(|len| *len > u32::MAX as usize) {
+    pub fn into_stream(mut self) -> Vec<u8> {
+        if [
+            self.AssemblyRef.len(),
+            self.ClassLayout.len(),
+            self.Constant.len(),
+            self.CustomAttribute.len(),
+            self.Event.len(),
+            self.EventMap.len(),
+            self.Field.len(),
+            self.GenericParam.len(),
+            self.ImplMap.len(),
+            self.InterfaceImpl.len(),
+            self.MemberRef.len(),
+            self.MethodDef.len(),
+            self.MethodSemantics.len(),
+            self.Module.len(),
+            self.ModuleRef.len(),
+            self.NestedClass.len(),
+            self.Param.len(),
+            self.Property.len(),
+            self.PropertyMap.len(),
+            self.TypeDef.len(),
+            self.TypeRef.len(),
+            self.TypeSpec.len(),
+        ]
+        .iter()
+        .any(|len| *len > u32::MAX as usize)
+        {
             panic!("metadata table too large");
         }
 
@@ -190,6 +246,28 @@ impl Tables {
 
         let type_or_method_def = metadata::coded_index_size(&[self.TypeDef.len(), self.MethodDef.len()]);
 
+        let has_semantics = metadata::coded_index_size(&[self.Event.len(), self.Property.len()]);
+
+        let member_ref_parent = metadata::coded_index_size(&[self.TypeDef.len(), self.TypeRef.len(), self.ModuleRef.len(), self.MethodDef.len(), self.TypeSpec.len()]);
+
+        let member_forwarded = metadata::coded_index_size(&[self.Field.len(), self.MethodDef.len()]);
+
+        let custom_attribute_type = metadata::coded_index_size(&[self.MethodDef.len(), self.MemberRef.len(), 0, 0, 0]);
+
+        // The tables that the writer doesn't support are empty but still count towards the size of the coded index.
+        let has_custom_attribute = metadata::coded_index_size(&[self.MethodDef.len(), self.Field.len(), self.TypeRef.len(), self.TypeDef.len(), self.Param.len(), self.InterfaceImpl.len(), self.MemberRef.len(), self.Module.len(), self.Property.len(), self.Event.len(), 0, self.ModuleRef.len(), self.TypeSpec.len(), self.Assembly.len(), self.AssemblyRef.len(), 0, 0, 0, self.GenericParam.len(), 0, 0]);
+
+        // The `MethodSemantics` table is sorted by its `Association` column. A stable sort keeps the accessors of
+        // each property and event in the order they were added.
+        self.MethodSemantics.sort_by_key(|x| x.Association);
+
+        // Likewise, the `CustomAttribute` table is sorted by its `Parent` column while keeping each parent's attributes
+        // in the order they were added.
+        self.CustomAttribute.sort_by_key(|x| x.Parent);
+
+        self.Constant.sort_by_key(|x| x.Parent);
+        self.ImplMap.sort_by_key(|x| x.MemberForwarded);
+
         let valid_tables: u64 = 1 << 0 | // Module 
         1 << 0x01 | // TypeRef
         1 << 0x02 | // TypeDef
@@ -201,7 +279,11 @@ impl Tables {
         1 << 0x0B | // Constant
         1 << 0x0C | // CustomAttribute
         1 << 0x0F | // ClassLayout
+        1 << 0x12 | // EventMap
+        1 << 0x14 | // Event
+        1 << 0x15 | // PropertyMap
         1 << 0x17 | // Property
+        1 << 0x18 | // MethodSemantics
         1 << 0x1A | // ModuleRef
         1 << 0x1B | // TypeSpec
         1 << 0x1C | // ImplMap
@@ -234,7 +316,11 @@ impl Tables {
         buffer.write_u32(self.Constant.len() as u32);
         buffer.write_u32(self.CustomAttribute.len() as u32);
         buffer.write_u32(self.ClassLayout.len() as u32);
+        buffer.write_u32(self.EventMap.len() as u32);
+        buffer.write_u32(self.Event.len() as u32);
+        buffer.write_u32(self.PropertyMap.len() as u32);
         buffer.write_u32(self.Property.len() as u32);
+        buffer.write_u32(self.MethodSemantics.len() as u32);
         buffer.write_u32(self.ModuleRef.len() as u32);
         buffer.write_u32(self.TypeSpec.len() as u32);
         buffer.write_u32(self.ImplMap.len() as u32);
@@ -274,7 +360,7 @@ impl Tables {
             buffer.write_u32(x.Signature);
         }
 
-        for x in self.MethodDef {
+        for x in &self.MethodDef {
             buffer.write_u32(x.RVA);
             buffer.write_u16(x.ImplFlags);
             buffer.write_u16(x.Flags);
@@ -294,16 +380,70 @@ impl Tables {
             buffer.write_code(x.Interface, type_def_or_ref);
         }
 
+        for x in self.MemberRef {
+            buffer.write_code(x.Class, member_ref_parent);
+            buffer.write_u32(x.Name);
+            buffer.write_u32(x.Signature);
+        }
+
         for x in self.Constant {
             buffer.write_u16(x.Type);
             buffer.write_code(x.Parent, has_constant);
             buffer.write_u32(x.Value);
         }
 
+        for x in self.CustomAttribute {
+            buffer.write_code(x.Parent, has_custom_attribute);
+            buffer.write_code(x.Type, custom_attribute_type);
+            buffer.write_u32(x.Value);
+        }
+
+        for x in self.EventMap {
+            buffer.write_index(x.Parent, self.TypeDef.len());
+            buffer.write_index(x.EventList, self.Event.len());
+        }
+
+        for x in &self.Event {
+            buffer.write_u16(x.EventFlags);
+            buffer.write_u32(x.Name);
+            buffer.write_code(x.EventType, type_def_or_ref);
+        }
+
+        for x in self.PropertyMap {
+            buffer.write_index(x.Parent, self.TypeDef.len());
+            buffer.write_index(x.PropertyList, self.Property.len());
+        }
+
+        for x in &self.Property {
+            buffer.write_u16(x.Flags);
+            buffer.write_u32(x.Name);
+            buffer.write_u32(x.Type);
+        }
+
+        for x in self.MethodSemantics {
+            buffer.write_u16(x.Semantics);
+            buffer.write_index(x.Method, self.MethodDef.len());
+            buffer.write_code(x.Association, has_semantics);
+        }
+
+        // The `ImplMap` table refers to the `ModuleRef` table, which is written first.
+        let module_refs = self.ModuleRef.len();
+
+        for x in self.ModuleRef {
+            buffer.write_u32(x.Name);
+        }
+
         for x in self.TypeSpec {
             buffer.write_u32(x.Signature);
         }
 
+        for x in self.ImplMap {
+            buffer.write_u16(x.MappingFlags);
+            buffer.write_code(x.MemberForwarded, member_forwarded);
+            buffer.write_u32(x.ImportName);
+            buffer.write_index(x.ImportScope, module_refs);
+        }
+
         for x in self.Assembly {
             buffer.write_u32(x.HashAlgId);
             buffer.write_u16(x.MajorVersion);
@@ -0,0 +1,51 @@
+use super::*;
+
+/// A custom attribute argument, mirroring `metadata::Value`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Bool(bool),
+    Char(u16),
+    I8(i8),
+    U8(u8),
+    I16(i16),
+    U16(u16),
+    I32(i32),
+    U32(u32),
+    I64(i64),
+    U64(u64),
+    F32(f32),
+    F64(f64),
+    String(String),
+    TypeName(TypeName),
+    Enum(TypeName, Box<Self>),
+    Array(Type, Vec<Self>),
+    Object(Box<Self>),
+    /// A null string, `System.Type` or array along with its type.
+    Null(Type),
+}
+
+impl Value {
+    /// The type of the constructor parameter or named argument that holds the value.
+    pub fn ty(&self) -> Type {
+        match self {
+            Self::Bool(_) => Type::Bool,
+            Self::Char(_) => Type::Char,
+            Self::I8(_) => Type::I8,
+            Self::U8(_) => Type::U8,
+            Self::I16(_) => Type::I16,
+            Self::U16(_) => Type::U16,
+            Self::I32(_) => Type::I32,
+            Self::U32(_) => Type::U32,
+            Self::I64(_) => Type::I64,
+            Self::U64(_) => Type::U64,
+            Self::F32(_) => Type::F32,
+            Self::F64(_) => Type::F64,
+            Self::String(_) => Type::String,
+            Self::TypeName(_) => Type::Type,
+            Self::Enum(type_name, _) => Type::TypeRef(type_name.clone()),
+            Self::Array(ty, _) => Type::WinrtArray(Box::new(ty.clone())),
+            Self::Object(_) => Type::IInspectable,
+            Self::Null(ty) => ty.clone(),
+        }
+    }
+}
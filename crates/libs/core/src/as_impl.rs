@@ -1,6 +1,7 @@
 /// A trait for retrieving the implementation behind a COM or WinRT interface.
 ///
-/// This trait is automatically implemented when using the `implement` macro.
+/// This trait is automatically implemented when using the `implement` macro. Use [`ComObject::downcast`](crate::ComObject::downcast) when the
+/// interface may not be implemented by `T`.
 pub trait AsImpl<T> {
     /// # Safety
     ///
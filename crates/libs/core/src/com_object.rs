@@ -0,0 +1,93 @@
+use super::*;
+
+/// Identifies the type generated by the `implement` macro to hold an implemented object.
+#[doc(hidden)]
+pub trait ComObjectInner: Sized {
+    type Outer: IUnknownImpl<Impl = Self>;
+}
+
+/// Functions for working with objects implemented with the `implement` macro.
+pub enum ComObject {}
+
+impl ComObject {
+    /// Gets a reference to the implementation of the object, if it was implemented with the `implement` macro as a `T`.
+    ///
+    /// Unlike [`AsImpl::as_impl`], this is safe to call with any interface, including one handed back by foreign
+    /// code, since the object is asked for its implementation and the implementation's type is checked. The object
+    /// must have been implemented by the same build of this crate, as the answer is specific to it. Objects whose
+    /// implementation is generic can't be downcast, since their type may not be `'static`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// #[implement(IStringable)]
+    /// struct Object;
+    ///
+    /// let unknown: IUnknown = Object.into();
+    /// assert!(ComObject::downcast::<Object>(&unknown).is_some());
+    /// ```
+    pub fn downcast<T: std::any::Any>(unknown: &IUnknown) -> Option<&T> {
+        let mut query = imp::ComObjectQuery { object: std::ptr::null(), type_id: std::any::TypeId::of::<T>() };
+
+        unsafe {
+            // No reference is added, so the implementation lives as long as `unknown`.
+            if unknown.query(&imp::IID_COM_OBJECT, &mut query as *mut _ as _).is_ok() && !query.object.is_null() {
+                Some(&*(query.object as *const T))
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// An implemented object stored in a `static` rather than allocated on the heap.
+///
+/// The object is never freed and its reference count is never updated, so its interfaces may be handed out without
+/// any allocation or lifetime bookkeeping. This suits singletons such as class factories and stateless helpers.
+///
+/// # Example
+/// ```rust,ignore
+/// #[implement(IStringable)]
+/// struct Object;
+///
+/// static OBJECT: StaticComObject<Object> = Object.into_static();
+///
+/// let stringable: IStringable = OBJECT.cast()?;
+/// ```
+pub struct StaticComObject<T: ComObjectInner> {
+    outer: T::Outer,
+}
+
+// The object is shared by every thread that refers to the static, and its interfaces may be called from any of them.
+unsafe impl<T: ComObjectInner + Sync> Sync for StaticComObject<T> {}
+unsafe impl<T: ComObjectInner + Send> Send for StaticComObject<T> {}
+
+impl<T: ComObjectInner> StaticComObject<T> {
+    /// Wraps an object created with static vtables, whose reference counting does nothing.
+    ///
+    /// # Safety
+    ///
+    /// This is called by the `into_static` method generated by the `implement` macro and should not be called directly.
+    #[doc(hidden)]
+    pub const unsafe fn from_outer(outer: T::Outer) -> Self {
+        Self { outer }
+    }
+
+    /// Gets a reference to the implementation.
+    pub fn get(&self) -> &T {
+        self.outer.get_impl()
+    }
+
+    /// Queries the object for the provided interface.
+    pub fn cast<I: Interface>(&'static self) -> Result<I> {
+        let mut result = None;
+        unsafe { self.outer.QueryInterface(&I::IID, &mut result as *mut _ as _).and_some(result) }
+    }
+}
+
+impl<T: ComObjectInner> std::ops::Deref for StaticComObject<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.get()
+    }
+}
@@ -0,0 +1,56 @@
+use super::*;
+
+/// A class that may be created by the class factories of a component.
+///
+/// This trait is implemented by the `implement` macro's `factory` option for types that implement [`Default`].
+pub trait Activatable {
+    /// Creates a new instance of the class.
+    fn activate() -> Result<IInspectable>;
+}
+
+/// Exports the entry points of an in-process COM server or WinRT component, along with the class factories for
+/// its classes.
+///
+/// Each class is identified either by its runtime class name, in which case it is returned by
+/// `DllGetActivationFactory`, or by a CLSID, in which case it is returned by `DllGetClassObject`. Each class must
+/// implement [`Activatable`], which the `implement` macro's `factory` option provides.
+///
+/// `DllCanUnloadNow` reports that the module is in use while any class factory, any object of a `factory` class or
+/// any `IClassFactory::LockServer` lock is outstanding.
+///
+/// # Example
+/// ```rust,ignore
+/// #[implement(IStringable, factory)]
+/// #[derive(Default)]
+/// struct Class;
+///
+/// #[implement(IPersist, factory)]
+/// #[derive(Default)]
+/// struct Object;
+///
+/// const CLSID_OBJECT: GUID = GUID::from_u128(0x1b4d7d14_b3c4_4c8b_9e6b_3d4f2a7c8e91);
+///
+/// windows::core::component! {
+///     "Sample.Class" => Class,
+///     CLSID_OBJECT => Object,
+/// }
+/// ```
+#[macro_export]
+macro_rules! component {
+    ($($key:expr => $class:ty),* $(,)?) => {
+        #[no_mangle]
+        unsafe extern "system" fn DllGetClassObject(clsid: *const $crate::GUID, iid: *const $crate::GUID, result: *mut *mut ::std::ffi::c_void) -> $crate::HRESULT {
+            $crate::imp::get_class_object(&[$(($crate::imp::ClassKey::from($key), <$class as $crate::Activatable>::activate),)*], clsid, iid, result)
+        }
+
+        #[no_mangle]
+        unsafe extern "system" fn DllGetActivationFactory(name: ::std::mem::ManuallyDrop<$crate::HSTRING>, result: *mut *mut ::std::ffi::c_void) -> $crate::HRESULT {
+            $crate::imp::get_activation_factory(&[$(($crate::imp::ClassKey::from($key), <$class as $crate::Activatable>::activate),)*], &name, result)
+        }
+
+        #[no_mangle]
+        unsafe extern "system" fn DllCanUnloadNow() -> $crate::HRESULT {
+            $crate::imp::can_unload_now()
+        }
+    };
+}
@@ -3,10 +3,37 @@
 use super::*;
 
 /// An error object consists of both an error code as well as detailed error information for debugging.
-#[derive(Clone, PartialEq, Eq)]
+///
+/// With the `backtrace` feature enabled, the error also records the location where it was created
+/// and, if enabled by the `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment variables, a backtrace.
+#[derive(Clone)]
 pub struct Error {
     pub(crate) code: HRESULT,
     pub(crate) info: Option<crate::imp::IErrorInfo>,
+    #[cfg(feature = "backtrace")]
+    origin: Option<Origin>,
+}
+
+// `std::backtrace` requires Rust 1.65 so it is only used when the `backtrace` feature is enabled.
+#[cfg(feature = "backtrace")]
+#[allow(clippy::incompatible_msrv)]
+#[derive(Clone)]
+struct Origin {
+    location: &'static std::panic::Location<'static>,
+    backtrace: std::sync::Arc<std::backtrace::Backtrace>,
+}
+
+#[cfg(feature = "backtrace")]
+#[allow(clippy::incompatible_msrv)]
+impl Origin {
+    #[track_caller]
+    fn capture() -> Self {
+        Self { location: std::panic::Location::caller(), backtrace: std::sync::Arc::new(std::backtrace::Backtrace::capture()) }
+    }
+
+    fn backtrace(&self) -> Option<&std::backtrace::Backtrace> {
+        Some(&*self.backtrace).filter(|backtrace| backtrace.status() == std::backtrace::BacktraceStatus::Captured)
+    }
 }
 
 unsafe impl Send for Error {}
@@ -14,20 +41,43 @@ unsafe impl Sync for Error {}
 
 impl Error {
     /// An error object without any failure information.
-    pub const OK: Self = Self { code: HRESULT(0), info: None };
+    pub const OK: Self = Self {
+        code: HRESULT(0),
+        info: None,
+        #[cfg(feature = "backtrace")]
+        origin: None,
+    };
 
     /// This creates a new error object, capturing the stack and other information about the
     /// point of failure.
+    #[track_caller]
     pub fn new(code: HRESULT, message: HSTRING) -> Self {
         unsafe {
             crate::imp::RoOriginateError(code.0, std::mem::transmute_copy(&message));
-            Self { code, info: GetErrorInfo() }
+            Self::from_parts(code, GetErrorInfo())
         }
     }
 
     /// Creates a new `Error` from the Win32 error code returned by `GetLastError()`.
+    #[track_caller]
     pub fn from_win32() -> Self {
-        unsafe { Self { code: HRESULT::from_win32(crate::imp::GetLastError()), info: None } }
+        unsafe { Self::from_code(HRESULT::from_win32(crate::imp::GetLastError())) }
+    }
+
+    /// Creates a new `Error` from the error code without retrieving any error information.
+    #[track_caller]
+    pub(crate) fn from_code(code: HRESULT) -> Self {
+        Self::from_parts(code, None)
+    }
+
+    #[track_caller]
+    fn from_parts(code: HRESULT, info: Option<crate::imp::IErrorInfo>) -> Self {
+        Self {
+            code,
+            info,
+            #[cfg(feature = "backtrace")]
+            origin: Some(Origin::capture()),
+        }
     }
 
     /// The error code describing the error.
@@ -74,8 +124,29 @@ impl Error {
         // Otherwise fallback to a generic error code description.
         self.code.message()
     }
+
+    /// The source location where the error was created.
+    #[cfg(feature = "backtrace")]
+    pub fn location(&self) -> Option<&'static std::panic::Location<'static>> {
+        self.origin.as_ref().map(|origin| origin.location)
+    }
+
+    /// The backtrace captured when the error was created, if backtraces are enabled.
+    #[cfg(feature = "backtrace")]
+    #[allow(clippy::incompatible_msrv)]
+    pub fn backtrace(&self) -> Option<&std::backtrace::Backtrace> {
+        self.origin.as_ref().and_then(Origin::backtrace)
+    }
 }
 
+impl PartialEq for Error {
+    fn eq(&self, other: &Self) -> bool {
+        self.code == other.code && self.info == other.info
+    }
+}
+
+impl Eq for Error {}
+
 impl From<Error> for HRESULT {
     fn from(error: Error) -> Self {
         if error.info.is_some() {
@@ -89,12 +160,13 @@ impl From<Error> for HRESULT {
 }
 
 impl From<HRESULT> for Error {
+    #[track_caller]
     fn from(code: HRESULT) -> Self {
         let info = GetErrorInfo();
 
         // Call CapturePropagationContext here if a use case presents itself. Otherwise, we can avoid the overhead for error propagation.
 
-        Self { code, info }
+        Self::from_parts(code, info)
     }
 }
 
@@ -105,20 +177,23 @@ impl From<Error> for std::io::Error {
 }
 
 impl From<std::string::FromUtf16Error> for Error {
+    #[track_caller]
     fn from(_: std::string::FromUtf16Error) -> Self {
-        Self { code: HRESULT::from_win32(crate::imp::ERROR_NO_UNICODE_TRANSLATION), info: None }
+        Self::from_code(HRESULT::from_win32(crate::imp::ERROR_NO_UNICODE_TRANSLATION))
     }
 }
 
 impl From<std::string::FromUtf8Error> for Error {
+    #[track_caller]
     fn from(_: std::string::FromUtf8Error) -> Self {
-        Self { code: HRESULT::from_win32(crate::imp::ERROR_NO_UNICODE_TRANSLATION), info: None }
+        Self::from_code(HRESULT::from_win32(crate::imp::ERROR_NO_UNICODE_TRANSLATION))
     }
 }
 
 impl From<std::num::TryFromIntError> for Error {
+    #[track_caller]
     fn from(_: std::num::TryFromIntError) -> Self {
-        Self { code: crate::imp::E_INVALIDARG, info: None }
+        Self::from_code(crate::imp::E_INVALIDARG)
     }
 }
 
@@ -135,7 +210,20 @@ impl From<std::convert::Infallible> for Error {
 impl std::fmt::Debug for Error {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut debug = fmt.debug_struct("Error");
-        debug.field("code", &self.code).field("message", &self.message()).finish()
+        debug.field("code", &self.code).field("message", &self.message());
+
+        #[cfg(feature = "backtrace")]
+        {
+            if let Some(location) = self.location() {
+                debug.field("location", &format_args!("{location}"));
+            }
+
+            if let Some(backtrace) = self.backtrace() {
+                debug.field("backtrace", backtrace);
+            }
+        }
+
+        debug.finish()
     }
 }
 
@@ -197,8 +197,10 @@ impl<T: Interface> Drop for Array<T> {
 }
 
 /// A reference-counted buffer.
+///
+/// The zero-length array pads the header so that the delegates that follow it are suitably aligned.
 #[repr(C)]
-struct Buffer<T>(crate::imp::RefCount, std::marker::PhantomData<T>);
+struct Buffer<T>(crate::imp::RefCount, std::marker::PhantomData<T>, [Delegate<T>; 0]);
 
 impl<T: Interface> Buffer<T> {
     /// Creates a new `Buffer` with the specified size in bytes.
@@ -209,7 +211,7 @@ impl<T: Interface> Buffer<T> {
             let alloc_size = std::mem::size_of::<Self>() + len * std::mem::size_of::<Delegate<T>>();
             let header = crate::imp::heap_alloc(alloc_size)? as *mut Self;
             unsafe {
-                header.write(Self(crate::imp::RefCount::new(1), std::marker::PhantomData));
+                header.write(Self(crate::imp::RefCount::new(1), std::marker::PhantomData, []));
             }
             Ok(header)
         }
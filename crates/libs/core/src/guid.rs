@@ -62,6 +62,33 @@ impl GUID {
 
         Self::from_values(first, second, third, [fourth, bytes[9], bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15]])
     }
+
+    fn parse(value: &str) -> Option<Self> {
+        if value.len() != 36 {
+            return None;
+        }
+
+        let mut bytes = value.bytes();
+
+        let a = ((bytes.next_u32()? * 16 + bytes.next_u32()?) << 24) + ((bytes.next_u32()? * 16 + bytes.next_u32()?) << 16) + ((bytes.next_u32()? * 16 + bytes.next_u32()?) << 8) + bytes.next_u32()? * 16 + bytes.next_u32()?;
+        bytes.next_dash()?;
+        let b = ((bytes.next_u16()? * 16 + (bytes.next_u16()?)) << 8) + bytes.next_u16()? * 16 + bytes.next_u16()?;
+        bytes.next_dash()?;
+        let c = ((bytes.next_u16()? * 16 + bytes.next_u16()?) << 8) + bytes.next_u16()? * 16 + bytes.next_u16()?;
+        bytes.next_dash()?;
+        let d = bytes.next_u8()? * 16 + bytes.next_u8()?;
+        let e = bytes.next_u8()? * 16 + bytes.next_u8()?;
+        bytes.next_dash()?;
+
+        let f = bytes.next_u8()? * 16 + bytes.next_u8()?;
+        let g = bytes.next_u8()? * 16 + bytes.next_u8()?;
+        let h = bytes.next_u8()? * 16 + bytes.next_u8()?;
+        let i = bytes.next_u8()? * 16 + bytes.next_u8()?;
+        let j = bytes.next_u8()? * 16 + bytes.next_u8()?;
+        let k = bytes.next_u8()? * 16 + bytes.next_u8()?;
+
+        Some(Self::from_values(a, b, c, [d, e, f, g, h, i, j, k]))
+    }
 }
 
 impl RuntimeType for GUID {
@@ -80,27 +107,15 @@ impl std::fmt::Debug for GUID {
 
 impl From<&str> for GUID {
     fn from(value: &str) -> Self {
-        assert!(value.len() == 36, "Invalid GUID string");
-        let mut bytes = value.bytes();
+        Self::parse(value).expect("Invalid GUID string")
+    }
+}
 
-        let a = ((bytes.next_u32() * 16 + bytes.next_u32()) << 24) + ((bytes.next_u32() * 16 + bytes.next_u32()) << 16) + ((bytes.next_u32() * 16 + bytes.next_u32()) << 8) + bytes.next_u32() * 16 + bytes.next_u32();
-        assert!(bytes.next().unwrap() == b'-', "Invalid GUID string");
-        let b = ((bytes.next_u16() * 16 + (bytes.next_u16())) << 8) + bytes.next_u16() * 16 + bytes.next_u16();
-        assert!(bytes.next().unwrap() == b'-', "Invalid GUID string");
-        let c = ((bytes.next_u16() * 16 + bytes.next_u16()) << 8) + bytes.next_u16() * 16 + bytes.next_u16();
-        assert!(bytes.next().unwrap() == b'-', "Invalid GUID string");
-        let d = bytes.next_u8() * 16 + bytes.next_u8();
-        let e = bytes.next_u8() * 16 + bytes.next_u8();
-        assert!(bytes.next().unwrap() == b'-', "Invalid GUID string");
-
-        let f = bytes.next_u8() * 16 + bytes.next_u8();
-        let g = bytes.next_u8() * 16 + bytes.next_u8();
-        let h = bytes.next_u8() * 16 + bytes.next_u8();
-        let i = bytes.next_u8() * 16 + bytes.next_u8();
-        let j = bytes.next_u8() * 16 + bytes.next_u8();
-        let k = bytes.next_u8() * 16 + bytes.next_u8();
-
-        Self::from_values(a, b, c, [d, e, f, g, h, i, j, k])
+impl std::str::FromStr for GUID {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        Self::parse(value).ok_or_else(|| Error::from_code(imp::E_INVALIDARG))
     }
 }
 
@@ -117,27 +132,60 @@ impl From<GUID> for u128 {
 }
 
 trait HexReader {
-    fn next_u8(&mut self) -> u8;
-    fn next_u16(&mut self) -> u16;
-    fn next_u32(&mut self) -> u32;
+    fn next_u8(&mut self) -> Option<u8>;
+    fn next_u16(&mut self) -> Option<u16>;
+    fn next_u32(&mut self) -> Option<u32>;
+    fn next_dash(&mut self) -> Option<()>;
 }
 
 impl HexReader for std::str::Bytes<'_> {
-    fn next_u8(&mut self) -> u8 {
-        let value = self.next().unwrap();
+    fn next_u8(&mut self) -> Option<u8> {
+        let value = self.next()?;
         match value {
-            b'0'..=b'9' => value - b'0',
-            b'A'..=b'F' => 10 + value - b'A',
-            b'a'..=b'f' => 10 + value - b'a',
-            _ => panic!(),
+            b'0'..=b'9' => Some(value - b'0'),
+            b'A'..=b'F' => Some(10 + value - b'A'),
+            b'a'..=b'f' => Some(10 + value - b'a'),
+            _ => None,
         }
     }
 
-    fn next_u16(&mut self) -> u16 {
-        self.next_u8().into()
+    fn next_u16(&mut self) -> Option<u16> {
+        self.next_u8().map(Into::into)
+    }
+
+    fn next_u32(&mut self) -> Option<u32> {
+        self.next_u8().map(Into::into)
     }
 
-    fn next_u32(&mut self) -> u32 {
-        self.next_u8().into()
+    fn next_dash(&mut self) -> Option<()> {
+        (self.next()? == b'-').then_some(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for GUID {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{self:?}"))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for GUID {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = GUID;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a GUID string such as \"00000000-0000-0000-C000-000000000046\"")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> std::result::Result<GUID, E> {
+                GUID::parse(value).ok_or_else(|| E::invalid_value(serde::de::Unexpected::Str(value), &self))
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
     }
 }
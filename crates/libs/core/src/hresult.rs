@@ -32,6 +32,7 @@ impl HRESULT {
 
     /// Converts the [`HRESULT`] to [`Result<()>`][Result<_>].
     #[inline]
+    #[track_caller]
     pub fn ok(self) -> Result<()> {
         if self.is_ok() {
             Ok(())
@@ -42,6 +43,7 @@ impl HRESULT {
 
     /// Returns the [`Option`] as a [`Result`] if the option is a [`Some`] value, returning
     /// a suitable error if not.
+    #[track_caller]
     pub fn and_some<T: Interface>(self, some: Option<T>) -> Result<T> {
         if self.is_ok() {
             if let Some(result) = some {
@@ -57,6 +59,7 @@ impl HRESULT {
     /// Calls `op` if `self` is a success code, otherwise returns [`HRESULT`]
     /// converted to [`Result<T>`].
     #[inline]
+    #[track_caller]
     pub fn and_then<F, T>(self, op: F) -> Result<T>
     where
         F: FnOnce() -> T,
@@ -72,6 +75,7 @@ impl HRESULT {
     /// Safe to call if
     /// * `abi` is initialized if `self` is `Ok`
     /// * `abi` can be safely transmuted to `T`
+    #[track_caller]
     pub unsafe fn from_abi<T: Type<T>>(self, abi: T::Abi) -> Result<T> {
         if self.is_ok() {
             T::from_abi(abi)
@@ -87,6 +91,10 @@ impl HRESULT {
         unsafe {
             let size = crate::imp::FormatMessageW(crate::imp::FORMAT_MESSAGE_ALLOCATE_BUFFER | crate::imp::FORMAT_MESSAGE_FROM_SYSTEM | crate::imp::FORMAT_MESSAGE_IGNORE_INSERTS, std::ptr::null(), self.0 as u32, 0, &mut message.0 as *mut _ as *mut _, 0, std::ptr::null());
 
+            if message.0.is_null() {
+                return HSTRING::new();
+            }
+
             HSTRING::from_wide(crate::imp::wide_trim_end(std::slice::from_raw_parts(message.0 as *const u16, size as usize))).unwrap_or_default()
         }
     }
@@ -127,6 +135,20 @@ impl std::fmt::Debug for HRESULT {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for HRESULT {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_i32(self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for HRESULT {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        i32::deserialize(deserializer).map(Self)
+    }
+}
+
 struct HeapString(*mut u16);
 
 impl Drop for HeapString {
@@ -16,18 +16,26 @@
 ::windows_targets::link!("kernel32.dll" "system" fn LoadLibraryExA(lplibfilename : PCSTR, hfile : HANDLE, dwflags : LOAD_LIBRARY_FLAGS) -> HMODULE);
 ::windows_targets::link!("kernel32.dll" "system" fn SetEvent(hevent : HANDLE) -> BOOL);
 ::windows_targets::link!("kernel32.dll" "system" fn WaitForSingleObject(hhandle : HANDLE, dwmilliseconds : u32) -> WAIT_EVENT);
+::windows_targets::link!("ole32.dll" "system" fn CoCreateFreeThreadedMarshaler(punkouter : * mut::core::ffi::c_void, ppunkmarshal : *mut * mut::core::ffi::c_void) -> HRESULT);
 ::windows_targets::link!("ole32.dll" "system" fn CoIncrementMTAUsage(pcookie : *mut CO_MTA_USAGE_COOKIE) -> HRESULT);
 ::windows_targets::link!("ole32.dll" "system" fn CoTaskMemAlloc(cb : usize) -> *mut ::core::ffi::c_void);
 ::windows_targets::link!("ole32.dll" "system" fn CoTaskMemFree(pv : *const ::core::ffi::c_void));
 ::windows_targets::link!("ole32.dll" "system" fn PropVariantClear(pvar : *mut PROPVARIANT) -> HRESULT);
 ::windows_targets::link!("ole32.dll" "system" fn PropVariantCopy(pvardest : *mut PROPVARIANT, pvarsrc : *const PROPVARIANT) -> HRESULT);
 ::windows_targets::link!("oleaut32.dll" "system" fn GetErrorInfo(dwreserved : u32, pperrinfo : *mut * mut::core::ffi::c_void) -> HRESULT);
+::windows_targets::link!("oleaut32.dll" "system" fn SafeArrayAccessData(psa : *const SAFEARRAY, ppvdata : *mut *mut ::core::ffi::c_void) -> HRESULT);
+::windows_targets::link!("oleaut32.dll" "system" fn SafeArrayCreateVector(vt : VARENUM, llbound : i32, celements : u32) -> *mut SAFEARRAY);
+::windows_targets::link!("oleaut32.dll" "system" fn SafeArrayGetDim(psa : *const SAFEARRAY) -> u32);
+::windows_targets::link!("oleaut32.dll" "system" fn SafeArrayGetLBound(psa : *const SAFEARRAY, ndim : u32, pllbound : *mut i32) -> HRESULT);
+::windows_targets::link!("oleaut32.dll" "system" fn SafeArrayGetUBound(psa : *const SAFEARRAY, ndim : u32, plubound : *mut i32) -> HRESULT);
+::windows_targets::link!("oleaut32.dll" "system" fn SafeArrayUnaccessData(psa : *const SAFEARRAY) -> HRESULT);
 ::windows_targets::link!("oleaut32.dll" "system" fn SetErrorInfo(dwreserved : u32, perrinfo : * mut::core::ffi::c_void) -> HRESULT);
 ::windows_targets::link!("oleaut32.dll" "system" fn SysAllocStringLen(strin : PCWSTR, ui : u32) -> BSTR);
 ::windows_targets::link!("oleaut32.dll" "system" fn SysFreeString(bstrstring : BSTR));
 ::windows_targets::link!("oleaut32.dll" "system" fn SysStringLen(pbstr : BSTR) -> u32);
 ::windows_targets::link!("oleaut32.dll" "system" fn VariantClear(pvarg : *mut VARIANT) -> HRESULT);
 ::windows_targets::link!("oleaut32.dll" "system" fn VariantCopy(pvargdest : *mut VARIANT, pvargsrc : *const VARIANT) -> HRESULT);
+::windows_targets::link!("oleaut32.dll" "system" fn VariantCopyInd(pvardest : *mut VARIANT, pvargsrc : *const VARIANT) -> HRESULT);
 ::windows_targets::link!("propsys.dll" "system" fn PropVariantCompareEx(propvar1 : *const PROPVARIANT, propvar2 : *const PROPVARIANT, unit : PROPVAR_COMPARE_UNIT, flags : PROPVAR_COMPARE_FLAGS) -> i32);
 ::windows_targets::link!("propsys.dll" "system" fn PropVariantToBSTR(propvar : *const PROPVARIANT, pbstrout : *mut BSTR) -> HRESULT);
 ::windows_targets::link!("propsys.dll" "system" fn PropVariantToBoolean(propvarin : *const PROPVARIANT, pfret : *mut BOOL) -> HRESULT);
@@ -983,19 +991,32 @@ impl ::core::clone::Clone for VERSIONEDSTREAM {
         *self
     }
 }
+pub const VT_ARRAY: VARENUM = 8192u16;
 pub const VT_BOOL: VARENUM = 11u16;
 pub const VT_BSTR: VARENUM = 8u16;
+pub const VT_BYREF: VARENUM = 16384u16;
+pub const VT_CY: VARENUM = 6u16;
+pub const VT_DATE: VARENUM = 7u16;
+pub const VT_DECIMAL: VARENUM = 14u16;
+pub const VT_DISPATCH: VARENUM = 9u16;
 pub const VT_EMPTY: VARENUM = 0u16;
+pub const VT_ERROR: VARENUM = 10u16;
 pub const VT_I1: VARENUM = 16u16;
 pub const VT_I2: VARENUM = 2u16;
 pub const VT_I4: VARENUM = 3u16;
 pub const VT_I8: VARENUM = 20u16;
+pub const VT_INT: VARENUM = 22u16;
+pub const VT_NULL: VARENUM = 1u16;
 pub const VT_R4: VARENUM = 4u16;
 pub const VT_R8: VARENUM = 5u16;
+pub const VT_TYPEMASK: VARENUM = 4095u16;
 pub const VT_UI1: VARENUM = 17u16;
 pub const VT_UI2: VARENUM = 18u16;
 pub const VT_UI4: VARENUM = 19u16;
 pub const VT_UI8: VARENUM = 21u16;
+pub const VT_UINT: VARENUM = 23u16;
 pub const VT_UNKNOWN: VARENUM = 13u16;
+pub const VT_VARIANT: VARENUM = 12u16;
+pub const VT_VECTOR: VARENUM = 4096u16;
 pub type WAIT_EVENT = u32;
 pub type WIN32_ERROR = u32;
@@ -0,0 +1,246 @@
+use super::*;
+use crate::Interface;
+use std::ffi::c_void;
+
+// The class factories returned by the entry points generated by the `component` macro. A single object implements
+// `IClassFactory` for COM classes and `IActivationFactory` for WinRT classes, depending on the vtable it is created with.
+
+/// Identifies a class registered with the `component` macro, either by CLSID or by runtime class name.
+pub enum ClassKey {
+    Clsid(crate::GUID),
+    Name(&'static str),
+}
+
+impl From<crate::GUID> for ClassKey {
+    fn from(value: crate::GUID) -> Self {
+        Self::Clsid(value)
+    }
+}
+
+impl From<&'static str> for ClassKey {
+    fn from(value: &'static str) -> Self {
+        Self::Name(value)
+    }
+}
+
+/// A function that creates a new instance of a class.
+pub type Activate = fn() -> crate::Result<crate::IInspectable>;
+
+static MODULE_LOCKS: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+// The locks taken by `IClassFactory::LockServer`, which are also counted by `MODULE_LOCKS`, so that unbalanced calls to
+// unlock the server can be told apart from the locks held by factories and objects.
+static SERVER_LOCKS: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+/// Keeps the module that exports the `component` macro's entry points loaded for as long as it lives.
+///
+/// Class factories and the objects of `factory` classes hold a lock, as does each call to `IClassFactory::LockServer`.
+pub struct ModuleLock(());
+
+impl ModuleLock {
+    pub fn new() -> Self {
+        MODULE_LOCKS.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        Self(())
+    }
+}
+
+impl Default for ModuleLock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for ModuleLock {
+    fn drop(&mut self) {
+        MODULE_LOCKS.fetch_sub(1, std::sync::atomic::Ordering::Release);
+    }
+}
+
+/// Returns `S_OK` if the module holds no locks and may be unloaded, as `DllCanUnloadNow` does, or `S_FALSE` otherwise.
+pub fn can_unload_now() -> crate::HRESULT {
+    if MODULE_LOCKS.load(std::sync::atomic::Ordering::Acquire) == 0 {
+        crate::HRESULT(0)
+    } else {
+        S_FALSE
+    }
+}
+
+pub unsafe fn get_class_object(classes: &[(ClassKey, Activate)], clsid: *const crate::GUID, iid: *const crate::GUID, result: *mut *mut c_void) -> crate::HRESULT {
+    if clsid.is_null() || iid.is_null() || result.is_null() {
+        return E_POINTER;
+    }
+
+    *result = std::ptr::null_mut();
+
+    match classes.iter().find(|(key, _)| matches!(key, ClassKey::Clsid(key) if *key == *clsid)) {
+        Some((_, activate)) => Factory::create(&CLASS_FACTORY_VTABLE, &CLASS_FACTORY_IIDS, *activate).query(iid, result),
+        None => CLASS_E_CLASSNOTAVAILABLE,
+    }
+}
+
+pub unsafe fn get_activation_factory(classes: &[(ClassKey, Activate)], name: &crate::HSTRING, result: *mut *mut c_void) -> crate::HRESULT {
+    if result.is_null() {
+        return E_POINTER;
+    }
+
+    *result = std::ptr::null_mut();
+
+    match classes.iter().find(|(key, _)| matches!(key, ClassKey::Name(key) if *name == **key)) {
+        Some((_, activate)) => {
+            *result = Factory::create(&ACTIVATION_FACTORY_VTABLE, &ACTIVATION_FACTORY_IIDS, *activate).into_raw();
+            crate::HRESULT(0)
+        }
+        None => CLASS_E_CLASSNOTAVAILABLE,
+    }
+}
+
+const IID_ICLASS_FACTORY: crate::GUID = crate::GUID::from_u128(0x00000001_0000_0000_c000_000000000046);
+const IID_IACTIVATION_FACTORY: crate::GUID = crate::GUID::from_u128(0x00000035_0000_0000_c000_000000000046);
+const S_FALSE: crate::HRESULT = crate::HRESULT(1);
+const E_POINTER: crate::HRESULT = crate::HRESULT(-2147467261);
+const E_UNEXPECTED: crate::HRESULT = crate::HRESULT(-2147418113);
+const CLASS_E_NOAGGREGATION: crate::HRESULT = crate::HRESULT(-2147221232);
+
+static CLASS_FACTORY_IIDS: [crate::GUID; 1] = [IID_ICLASS_FACTORY];
+static ACTIVATION_FACTORY_IIDS: [crate::GUID; 2] = [<crate::IInspectable as Interface>::IID, IID_IACTIVATION_FACTORY];
+
+#[repr(C)]
+struct IClassFactory_Vtbl {
+    base__: crate::IUnknown_Vtbl,
+    CreateInstance: unsafe extern "system" fn(*mut c_void, *mut c_void, *const crate::GUID, *mut *mut c_void) -> crate::HRESULT,
+    LockServer: unsafe extern "system" fn(*mut c_void, i32) -> crate::HRESULT,
+}
+
+#[repr(C)]
+struct IActivationFactory_Vtbl {
+    base__: crate::IInspectable_Vtbl,
+    ActivateInstance: unsafe extern "system" fn(*mut c_void, *mut *mut c_void) -> crate::HRESULT,
+}
+
+static CLASS_FACTORY_VTABLE: IClassFactory_Vtbl = IClassFactory_Vtbl { base__: Factory::UNKNOWN, CreateInstance: Factory::CreateInstance, LockServer: Factory::LockServer };
+
+static ACTIVATION_FACTORY_VTABLE: IActivationFactory_Vtbl = IActivationFactory_Vtbl {
+    base__: crate::IInspectable_Vtbl { base: Factory::UNKNOWN, GetIids: Factory::GetIids, GetRuntimeClassName: Factory::GetRuntimeClassName, GetTrustLevel: Factory::GetTrustLevel },
+    ActivateInstance: Factory::ActivateInstance,
+};
+
+#[repr(C)]
+struct Factory {
+    vtable: *const c_void,
+    count: RefCount,
+    interfaces: &'static [crate::GUID],
+    activate: Activate,
+    _lock: ModuleLock,
+}
+
+impl Factory {
+    const UNKNOWN: crate::IUnknown_Vtbl = crate::IUnknown_Vtbl { QueryInterface: Self::QueryInterface, AddRef: Self::AddRef, Release: Self::Release };
+
+    fn create<V>(vtable: &'static V, interfaces: &'static [crate::GUID], activate: Activate) -> crate::IUnknown {
+        let object = Box::new(Self { vtable: vtable as *const V as _, count: RefCount::new(1), interfaces, activate, _lock: ModuleLock::new() });
+        unsafe { crate::IUnknown::from_raw(Box::into_raw(object) as _) }
+    }
+
+    unsafe fn from_this<'a>(this: *mut c_void) -> &'a Self {
+        &*(this as *const Self)
+    }
+
+    unsafe extern "system" fn QueryInterface(this: *mut c_void, iid: *const crate::GUID, interface: *mut *mut c_void) -> crate::HRESULT {
+        if iid.is_null() || interface.is_null() {
+            return E_POINTER;
+        }
+
+        // Factories hold no state, so they are agile.
+        if *iid == <crate::IUnknown as Interface>::IID || *iid == <IAgileObject as Interface>::IID || Self::from_this(this).interfaces.contains(&*iid) {
+            Self::AddRef(this);
+            *interface = this;
+            crate::HRESULT(0)
+        } else {
+            *interface = std::ptr::null_mut();
+            E_NOINTERFACE
+        }
+    }
+
+    unsafe extern "system" fn AddRef(this: *mut c_void) -> u32 {
+        Self::from_this(this).count.add_ref()
+    }
+
+    unsafe extern "system" fn Release(this: *mut c_void) -> u32 {
+        let remaining = Self::from_this(this).count.release();
+
+        if remaining == 0 {
+            drop(Box::from_raw(this as *mut Self));
+        }
+
+        remaining
+    }
+
+    unsafe extern "system" fn CreateInstance(this: *mut c_void, outer: *mut c_void, iid: *const crate::GUID, object: *mut *mut c_void) -> crate::HRESULT {
+        if iid.is_null() || object.is_null() {
+            return E_POINTER;
+        }
+
+        *object = std::ptr::null_mut();
+
+        if !outer.is_null() {
+            return CLASS_E_NOAGGREGATION;
+        }
+
+        match (Self::from_this(this).activate)() {
+            Ok(instance) => instance.query(iid, object),
+            Err(error) => error.into(),
+        }
+    }
+
+    unsafe extern "system" fn LockServer(_this: *mut c_void, lock: i32) -> crate::HRESULT {
+        if lock != 0 {
+            SERVER_LOCKS.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            std::mem::forget(ModuleLock::new());
+        } else if SERVER_LOCKS.fetch_update(std::sync::atomic::Ordering::Relaxed, std::sync::atomic::Ordering::Relaxed, |locks| locks.checked_sub(1)).is_ok() {
+            drop(ModuleLock(()));
+        } else {
+            // Unbalanced calls to unlock the server are ignored rather than releasing the locks held by objects.
+            return E_UNEXPECTED;
+        }
+
+        crate::HRESULT(0)
+    }
+
+    unsafe extern "system" fn GetIids(_this: *mut c_void, count: *mut u32, values: *mut *mut crate::GUID) -> crate::HRESULT {
+        if count.is_null() || values.is_null() {
+            return E_POINTER;
+        }
+
+        let (data, len) = crate::Array::<crate::GUID>::from_slice(&[IID_IACTIVATION_FACTORY]).into_abi();
+        *values = data;
+        *count = len;
+        crate::HRESULT(0)
+    }
+
+    unsafe extern "system" fn GetRuntimeClassName(_this: *mut c_void, value: *mut *mut c_void) -> crate::HRESULT {
+        *value = std::ptr::null_mut();
+        crate::HRESULT(0)
+    }
+
+    unsafe extern "system" fn GetTrustLevel(_this: *mut c_void, value: *mut i32) -> crate::HRESULT {
+        *value = 0;
+        crate::HRESULT(0)
+    }
+
+    unsafe extern "system" fn ActivateInstance(this: *mut c_void, instance: *mut *mut c_void) -> crate::HRESULT {
+        if instance.is_null() {
+            return E_POINTER;
+        }
+
+        match (Self::from_this(this).activate)() {
+            Ok(value) => {
+                *instance = value.into_raw();
+                crate::HRESULT(0)
+            }
+            Err(error) => {
+                *instance = std::ptr::null_mut();
+                error.into()
+            }
+        }
+    }
+}
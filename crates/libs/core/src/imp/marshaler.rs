@@ -0,0 +1,56 @@
+use super::*;
+use crate::Interface;
+use std::ffi::c_void;
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+/// The IID of the `IMarshal` interface.
+pub const IID_IMARSHAL: crate::GUID = crate::GUID::from_u128(0x00000003_0000_0000_c000_000000000046);
+
+/// The free-threaded marshaler aggregated by an object implemented with `#[implement(..., Agile = FreeThreaded)]`.
+///
+/// The marshaler is only created the first time it is queried for, since most objects are never marshaled.
+#[derive(Default)]
+pub struct FreeThreadedMarshaler(AtomicPtr<c_void>);
+
+impl FreeThreadedMarshaler {
+    pub const fn new() -> Self {
+        Self(AtomicPtr::new(std::ptr::null_mut()))
+    }
+
+    /// Queries the marshaler, aggregated by `outer`, for the requested interface.
+    ///
+    /// # Safety
+    ///
+    /// `outer` must be the controlling `IUnknown` of the object that owns the marshaler.
+    pub unsafe fn query(&self, outer: *mut c_void, iid: *const crate::GUID, interface: *mut *mut c_void) -> crate::HRESULT {
+        let mut marshaler = self.0.load(Ordering::Acquire);
+
+        if marshaler.is_null() {
+            let hr = CoCreateFreeThreadedMarshaler(outer, &mut marshaler);
+
+            if hr < 0 {
+                *interface = std::ptr::null_mut();
+                return crate::HRESULT(hr);
+            }
+
+            // Another thread may have created the marshaler in the meantime, in which case this one is discarded.
+            if let Err(existing) = self.0.compare_exchange(std::ptr::null_mut(), marshaler, Ordering::AcqRel, Ordering::Acquire) {
+                drop(crate::IUnknown::from_raw(marshaler));
+                marshaler = existing;
+            }
+        }
+
+        // This is the marshaler's non-delegating `IUnknown`, so its interfaces are reference counted by `outer`.
+        crate::IUnknown::from_raw_borrowed(&marshaler).unwrap().query(iid, interface)
+    }
+}
+
+impl Drop for FreeThreadedMarshaler {
+    fn drop(&mut self) {
+        let marshaler = *self.0.get_mut();
+
+        if !marshaler.is_null() {
+            unsafe { drop(crate::IUnknown::from_raw(marshaler)) };
+        }
+    }
+}
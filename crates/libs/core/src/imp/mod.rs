@@ -1,25 +1,51 @@
 mod bindings;
+mod class_factory;
 mod com_bindings;
 mod delay_load;
 mod factory_cache;
 mod generic_factory;
 mod heap;
+mod marshaler;
+#[cfg(not(windows))]
+mod portable;
 mod ref_count;
 mod sha1;
+mod static_identity;
+mod tear_off;
 mod waiter;
 mod weak_ref_count;
 
 pub use bindings::*;
+pub use class_factory::*;
 pub use com_bindings::*;
 pub use delay_load::*;
 pub use factory_cache::*;
 pub use generic_factory::*;
 pub use heap::*;
+pub use marshaler::*;
+#[cfg(not(windows))]
+pub use portable::{CoCreateFreeThreadedMarshaler, CoIncrementMTAUsage, CoTaskMemAlloc, CoTaskMemFree, EncodePointer, FormatMessageW, FreeLibrary, GetErrorInfo, GetLastError, GetProcAddress, GetProcessHeap, HeapAlloc, HeapFree, LoadLibraryExA, RoGetActivationFactory, RoGetAgileReference, RoOriginateError, SetErrorInfo, SysAllocStringLen, SysFreeString, SysStringLen};
 pub use ref_count::*;
 pub use sha1::*;
+pub use static_identity::*;
+pub use tear_off::*;
 pub use waiter::*;
 pub use weak_ref_count::*;
 
+/// A private IID answered by objects implemented with the `implement` macro, on behalf of `ComObject::downcast`. The
+/// interface pointer passed along with it points to a `ComObjectQuery` rather than a single interface pointer.
+pub const IID_COM_OBJECT: crate::GUID = crate::GUID::from_u128(0xe3dbd14e_5170_4ce1_8abe_53803399ad43);
+
+/// The result of querying for `IID_COM_OBJECT`.
+///
+/// The object is only set if the implementation has the type identified by `type_id`. It comes first so that objects
+/// that don't know the IID and clear the interface pointer, as `QueryInterface` requires, only write to it.
+#[repr(C)]
+pub struct ComObjectQuery {
+    pub object: *const std::ffi::c_void,
+    pub type_id: std::any::TypeId,
+}
+
 pub fn wide_trim_end(mut wide: &[u16]) -> &[u16] {
     while let Some(last) = wide.last() {
         match last {
@@ -0,0 +1,275 @@
+use super::*;
+use crate::Interface;
+use std::ffi::c_void;
+
+// In-process stand-ins for the Windows APIs that `windows-core` depends on, used on targets other than Windows so that
+// COM objects, strings, arrays, and error information work without the operating system. Each function has the same
+// name and signature as the import it replaces so that callers need not distinguish between the two.
+
+// Matches `MEMORY_ALLOCATION_ALIGNMENT` on 64-bit Windows. The size of each allocation is stored in this prefix.
+const ALIGNMENT: usize = 16;
+
+fn layout(bytes: usize) -> Option<std::alloc::Layout> {
+    std::alloc::Layout::from_size_align(bytes.checked_add(ALIGNMENT)?, ALIGNMENT).ok()
+}
+
+/// There is only a single heap, so the handle is ignored by `HeapAlloc` and `HeapFree`.
+pub unsafe fn GetProcessHeap() -> HANDLE {
+    0
+}
+
+pub unsafe fn HeapAlloc(_hheap: HANDLE, _dwflags: HEAP_FLAGS, dwbytes: usize) -> *mut c_void {
+    match layout(dwbytes) {
+        Some(layout) => {
+            let ptr = std::alloc::alloc(layout);
+
+            if ptr.is_null() {
+                return std::ptr::null_mut();
+            }
+
+            (ptr as *mut usize).write(dwbytes);
+            ptr.add(ALIGNMENT) as _
+        }
+        None => std::ptr::null_mut(),
+    }
+}
+
+pub unsafe fn HeapFree(_hheap: HANDLE, _dwflags: HEAP_FLAGS, lpmem: *const c_void) -> BOOL {
+    if !lpmem.is_null() {
+        let ptr = (lpmem as *mut u8).sub(ALIGNMENT);
+        std::alloc::dealloc(ptr, layout((ptr as *const usize).read()).unwrap());
+    }
+
+    1
+}
+
+pub unsafe fn CoTaskMemAlloc(cb: usize) -> *mut c_void {
+    HeapAlloc(0, 0, cb)
+}
+
+pub unsafe fn CoTaskMemFree(pv: *const c_void) {
+    HeapFree(0, 0, pv);
+}
+
+/// Allocates a `BSTR`, which is prefixed by its length in bytes and followed by a null terminator.
+pub unsafe fn SysAllocStringLen(strin: PCWSTR, ui: u32) -> BSTR {
+    if ui > u32::MAX / 2 - 2 {
+        return std::ptr::null();
+    }
+
+    let header = HeapAlloc(0, 0, 4 + (ui as usize + 1) * 2) as *mut u32;
+
+    if header.is_null() {
+        return std::ptr::null();
+    }
+
+    header.write(ui * 2);
+    let data = header.add(1) as *mut u16;
+
+    if !strin.is_null() {
+        std::ptr::copy_nonoverlapping(strin, data, ui as usize);
+    }
+
+    data.add(ui as usize).write(0);
+    data
+}
+
+pub unsafe fn SysFreeString(bstrstring: BSTR) {
+    if !bstrstring.is_null() {
+        HeapFree(0, 0, (bstrstring as *const u32).sub(1) as _);
+    }
+}
+
+pub unsafe fn SysStringLen(pbstr: BSTR) -> u32 {
+    if pbstr.is_null() {
+        0
+    } else {
+        (pbstr as *const u32).sub(1).read() / 2
+    }
+}
+
+/// Returns the last OS error (`errno`) of the calling thread.
+pub unsafe fn GetLastError() -> WIN32_ERROR {
+    std::io::Error::last_os_error().raw_os_error().unwrap_or(0) as _
+}
+
+/// There is no system message table, so no message is ever found.
+pub unsafe fn FormatMessageW(_dwflags: FORMAT_MESSAGE_OPTIONS, _lpsource: *const c_void, _dwmessageid: u32, _dwlanguageid: u32, _lpbuffer: PWSTR, _nsize: u32, _arguments: *const *const i8) -> u32 {
+    0
+}
+
+/// Libraries cannot be loaded, so activation factories are only ever found in the calling module.
+pub unsafe fn LoadLibraryExA(_lplibfilename: PCSTR, _hfile: HANDLE, _dwflags: LOAD_LIBRARY_FLAGS) -> HMODULE {
+    0
+}
+
+pub unsafe fn GetProcAddress(_hmodule: HMODULE, _lpprocname: PCSTR) -> FARPROC {
+    None
+}
+
+pub unsafe fn FreeLibrary(_hlibmodule: HMODULE) -> BOOL {
+    1
+}
+
+/// There are no apartments to marshal between, so the free-threaded marshaler is not available.
+pub unsafe fn CoCreateFreeThreadedMarshaler(_punkouter: *mut c_void, ppunkmarshal: *mut *mut c_void) -> HRESULT {
+    *ppunkmarshal = std::ptr::null_mut();
+    -2147467263 // E_NOTIMPL
+}
+
+pub unsafe fn CoIncrementMTAUsage(pcookie: *mut CO_MTA_USAGE_COOKIE) -> HRESULT {
+    *pcookie = 0;
+    0
+}
+
+/// There is no registry of activatable classes, so activation always fails with `REGDB_E_CLASSNOTREG`.
+pub unsafe fn RoGetActivationFactory(_activatableclassid: HSTRING, _iid: *const GUID, factory: *mut *mut c_void) -> HRESULT {
+    *factory = std::ptr::null_mut();
+    -2147221164
+}
+
+/// Pointers are only encoded to harden them against tampering, which is of no use within a single process.
+pub unsafe fn EncodePointer(ptr: *const c_void) -> *mut c_void {
+    ptr as _
+}
+
+thread_local! {
+    static ERROR_INFO: std::cell::RefCell<Option<crate::IUnknown>> = const { std::cell::RefCell::new(None) };
+}
+
+pub unsafe fn SetErrorInfo(_dwreserved: u32, perrinfo: *mut c_void) -> HRESULT {
+    let info = crate::IUnknown::from_raw_borrowed(&perrinfo).cloned();
+    // The previous error object is released outside of the borrow in case its destructor reports an error.
+    drop(ERROR_INFO.with(|cell| cell.replace(info)));
+    0
+}
+
+pub unsafe fn GetErrorInfo(_dwreserved: u32, pperrinfo: *mut *mut c_void) -> HRESULT {
+    match ERROR_INFO.with(|cell| cell.take()) {
+        Some(info) => {
+            *pperrinfo = info.into_raw();
+            0
+        }
+        None => {
+            *pperrinfo = std::ptr::null_mut();
+            1
+        }
+    }
+}
+
+/// Records the message with the thread's error information so that it may be retrieved by `GetErrorInfo`.
+pub unsafe fn RoOriginateError(error: HRESULT, message: HSTRING) -> BOOL {
+    if error >= 0 {
+        return 0;
+    }
+
+    let message: &crate::HSTRING = std::mem::transmute(&message);
+    let info: IErrorInfo = Object::create(&ERROR_INFO_VTABLE, IErrorInfo::IID, crate::BSTR::from_wide(message.as_wide()).unwrap_or_default());
+    SetErrorInfo(0, info.as_raw());
+    1
+}
+
+/// Every object is agile within a single process, so the agile reference simply holds on to the object.
+pub unsafe fn RoGetAgileReference<P0>(_options: AgileReferenceOptions, riid: *const crate::GUID, punk: P0) -> crate::Result<IAgileReference>
+where
+    P0: crate::IntoParam<crate::IUnknown>,
+{
+    let punk = punk.into_param();
+    let mut object = std::ptr::null_mut();
+    crate::IUnknown::from_raw_borrowed(&punk.abi()).ok_or_else(|| crate::Error::from_code(E_INVALIDARG))?.query(riid, &mut object).ok()?;
+    Ok(Object::create(&AGILE_REFERENCE_VTABLE, IAgileReference::IID, crate::IUnknown::from_raw(object)))
+}
+
+// A minimal COM object implementing `IUnknown` and a single interface described by `vtable`.
+#[repr(C)]
+struct Object<V: 'static, T> {
+    vtable: &'static V,
+    count: RefCount,
+    interface: crate::GUID,
+    value: T,
+}
+
+impl<V, T> Object<V, T> {
+    const UNKNOWN: crate::IUnknown_Vtbl = crate::IUnknown_Vtbl { QueryInterface: Self::QueryInterface, AddRef: Self::AddRef, Release: Self::Release };
+
+    fn create<I: Interface>(vtable: &'static V, interface: crate::GUID, value: T) -> I {
+        let object = Box::new(Self { vtable, count: RefCount::new(1), interface, value });
+        unsafe { I::from_raw(Box::into_raw(object) as _) }
+    }
+
+    unsafe fn from_this<'a>(this: *mut c_void) -> &'a Self {
+        &*(this as *const Self)
+    }
+
+    unsafe extern "system" fn QueryInterface(this: *mut c_void, iid: *const crate::GUID, interface: *mut *mut c_void) -> crate::HRESULT {
+        if iid.is_null() || interface.is_null() {
+            return E_INVALIDARG;
+        }
+
+        if *iid == <crate::IUnknown as Interface>::IID || *iid == Self::from_this(this).interface {
+            Self::AddRef(this);
+            *interface = this;
+            crate::HRESULT(0)
+        } else {
+            *interface = std::ptr::null_mut();
+            E_NOINTERFACE
+        }
+    }
+
+    unsafe extern "system" fn AddRef(this: *mut c_void) -> u32 {
+        Self::from_this(this).count.add_ref()
+    }
+
+    unsafe extern "system" fn Release(this: *mut c_void) -> u32 {
+        let remaining = Self::from_this(this).count.release();
+
+        if remaining == 0 {
+            drop(Box::from_raw(this as *mut Self));
+        }
+
+        remaining
+    }
+}
+
+type ErrorInfo = Object<IErrorInfo_Vtbl, crate::BSTR>;
+
+static ERROR_INFO_VTABLE: IErrorInfo_Vtbl = IErrorInfo_Vtbl {
+    base__: ErrorInfo::UNKNOWN,
+    GetGUID: ErrorInfo::GetGUID,
+    GetSource: ErrorInfo::GetEmptyString,
+    GetDescription: ErrorInfo::GetDescription,
+    GetHelpFile: ErrorInfo::GetEmptyString,
+    GetHelpContext: ErrorInfo::GetHelpContext,
+};
+
+impl ErrorInfo {
+    unsafe extern "system" fn GetGUID(_this: *mut c_void, guid: *mut crate::GUID) -> crate::HRESULT {
+        *guid = crate::GUID::zeroed();
+        crate::HRESULT(0)
+    }
+
+    unsafe extern "system" fn GetEmptyString(_this: *mut c_void, value: *mut std::mem::MaybeUninit<crate::BSTR>) -> crate::HRESULT {
+        (*value).write(crate::BSTR::new());
+        crate::HRESULT(0)
+    }
+
+    unsafe extern "system" fn GetDescription(this: *mut c_void, value: *mut std::mem::MaybeUninit<crate::BSTR>) -> crate::HRESULT {
+        (*value).write(Self::from_this(this).value.clone());
+        crate::HRESULT(0)
+    }
+
+    unsafe extern "system" fn GetHelpContext(_this: *mut c_void, value: *mut u32) -> crate::HRESULT {
+        *value = 0;
+        crate::HRESULT(0)
+    }
+}
+
+type AgileReference = Object<IAgileReference_Vtbl, crate::IUnknown>;
+
+static AGILE_REFERENCE_VTABLE: IAgileReference_Vtbl = IAgileReference_Vtbl { base__: AgileReference::UNKNOWN, Resolve: AgileReference::Resolve };
+
+impl AgileReference {
+    unsafe extern "system" fn Resolve(this: *mut c_void, riid: *const crate::GUID, ppvobjectreference: *mut *mut c_void) -> crate::HRESULT {
+        Self::from_this(this).value.query(riid, ppvobjectreference)
+    }
+}
@@ -0,0 +1,47 @@
+use super::*;
+use crate::Interface;
+use std::ffi::c_void;
+
+/// The identity used by the vtables of an object stored in a `StaticComObject`.
+///
+/// Static objects are never freed, so reference counting does nothing. They don't support weak references, since
+/// they can't go away.
+#[repr(transparent)]
+pub struct StaticIdentity<T: crate::IUnknownImpl>(T);
+
+impl<T: crate::IUnknownImpl> crate::IUnknownImpl for StaticIdentity<T> {
+    type Impl = T::Impl;
+
+    fn get_impl(&self) -> &Self::Impl {
+        self.0.get_impl()
+    }
+
+    unsafe fn QueryInterface(&self, iid: *const crate::GUID, interface: *mut *mut c_void) -> crate::HRESULT {
+        if !iid.is_null() && !interface.is_null() && *iid == IWeakReferenceSource::IID {
+            *interface = std::ptr::null_mut();
+            return E_NOINTERFACE;
+        }
+
+        self.0.QueryInterface(iid, interface)
+    }
+
+    fn AddRef(&self) -> u32 {
+        2
+    }
+
+    unsafe fn Release(&self) -> u32 {
+        1
+    }
+
+    unsafe fn GetTrustLevel(&self, value: *mut i32) -> crate::HRESULT {
+        self.0.GetTrustLevel(value)
+    }
+
+    unsafe fn GetIids(&self, count: *mut u32, values: *mut *mut crate::GUID) -> crate::HRESULT {
+        self.0.GetIids(count, values)
+    }
+
+    fn outer(&self) -> Option<&crate::IUnknown> {
+        self.0.outer()
+    }
+}
@@ -0,0 +1,80 @@
+use super::*;
+use crate::Interface;
+use std::ffi::c_void;
+
+/// A tear-off interface of an object implemented with `#[implement(..., TearOff(...))]`.
+///
+/// Rather than reserving a vtable pointer in every instance of the object, a tear-off is allocated each time the
+/// object is queried for its interface. It has its own reference count and holds a reference to the object, to
+/// which it forwards queries for any other interface so that the object's identity is preserved.
+#[repr(C)]
+pub struct TearOff<T: crate::IUnknownImpl> {
+    vtable: *const c_void,
+    count: RefCount,
+    owner: *const T,
+    // The object's identity, or its controlling `IUnknown` if it is the inner object of an aggregate.
+    unknown: crate::IUnknown,
+    matches: fn(&crate::GUID) -> bool,
+}
+
+impl<T: crate::IUnknownImpl> TearOff<T> {
+    /// Creates a tear-off with the given vtable for the object whose identity is `identity`, returning the tear-off
+    /// interface pointer.
+    ///
+    /// # Safety
+    ///
+    /// `vtable` must be created for `TearOff<T>` at offset zero and `identity` must point to `owner`'s identity.
+    pub unsafe fn create<V>(vtable: &'static V, matches: fn(&crate::GUID) -> bool, owner: &T, identity: *mut c_void) -> *mut c_void {
+        let unknown = match owner.outer() {
+            Some(outer) => outer.clone(),
+            None => crate::IUnknown::from_raw_borrowed(&identity).unwrap().clone(),
+        };
+
+        let tear_off = Box::new(Self { vtable: vtable as *const V as _, count: RefCount::new(1), owner, unknown, matches });
+        Box::into_raw(tear_off) as _
+    }
+}
+
+impl<T: crate::IUnknownImpl> crate::IUnknownImpl for TearOff<T> {
+    type Impl = T::Impl;
+
+    fn get_impl(&self) -> &Self::Impl {
+        unsafe { (*self.owner).get_impl() }
+    }
+
+    unsafe fn QueryInterface(&self, iid: *const crate::GUID, interface: *mut *mut c_void) -> crate::HRESULT {
+        if iid.is_null() || interface.is_null() {
+            return crate::HRESULT(-2147467261); // E_POINTER
+        }
+
+        if (self.matches)(&*iid) {
+            self.count.add_ref();
+            *interface = self as *const Self as *mut _;
+            crate::HRESULT(0)
+        } else {
+            self.unknown.query(iid, interface)
+        }
+    }
+
+    fn AddRef(&self) -> u32 {
+        self.count.add_ref()
+    }
+
+    unsafe fn Release(&self) -> u32 {
+        let remaining = self.count.release();
+
+        if remaining == 0 {
+            drop(Box::from_raw(self as *const Self as *mut Self));
+        }
+
+        remaining
+    }
+
+    unsafe fn GetTrustLevel(&self, value: *mut i32) -> crate::HRESULT {
+        (*self.owner).GetTrustLevel(value)
+    }
+
+    unsafe fn GetIids(&self, count: *mut u32, values: *mut *mut crate::GUID) -> crate::HRESULT {
+        (*self.owner).GetIids(count, values)
+    }
+}
@@ -8,7 +8,7 @@ use std::sync::atomic::{AtomicIsize, Ordering};
 pub struct WeakRefCount(AtomicIsize);
 
 impl WeakRefCount {
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self(AtomicIsize::new(1))
     }
 
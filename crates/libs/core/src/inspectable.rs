@@ -28,6 +28,15 @@ impl IInspectable {
             Ok(value)
         }
     }
+
+    /// Gets the interfaces that are implemented by the current object, excluding `IUnknown` and `IInspectable`.
+    pub fn GetIids(&self) -> Result<Array<GUID>> {
+        unsafe {
+            let mut values = Array::new();
+            (self.vtable().GetIids)(std::mem::transmute_copy(self), values.set_abi_len(), &mut values as *mut _ as _).ok()?;
+            Ok(values)
+        }
+    }
 }
 
 #[doc(hidden)]
@@ -53,13 +62,9 @@ impl RuntimeName for IInspectable {}
 #[cfg(feature = "implement")]
 impl IInspectable_Vtbl {
     pub const fn new<Identity: IUnknownImpl, Name: RuntimeName, const OFFSET: isize>() -> Self {
-        unsafe extern "system" fn GetIids(_: *mut std::ffi::c_void, count: *mut u32, values: *mut *mut GUID) -> HRESULT {
-            // Note: even if we end up implementing this in future, it still doesn't need a this pointer
-            // since the data to be returned is type- not instance-specific so can be shared for all
-            // interfaces.
-            *count = 0;
-            *values = std::ptr::null_mut();
-            HRESULT(0)
+        unsafe extern "system" fn GetIids<T: IUnknownImpl, const OFFSET: isize>(this: *mut std::ffi::c_void, count: *mut u32, values: *mut *mut GUID) -> HRESULT {
+            let this = (this as *mut *mut std::ffi::c_void).offset(OFFSET) as *mut T;
+            (*this).GetIids(count, values)
         }
         unsafe extern "system" fn GetRuntimeClassName<T: RuntimeName>(_: *mut std::ffi::c_void, value: *mut *mut std::ffi::c_void) -> HRESULT {
             let h: HSTRING = T::NAME.into(); // TODO: should be try_into
@@ -72,7 +77,7 @@ impl IInspectable_Vtbl {
         }
         Self {
             base: IUnknown_Vtbl::new::<Identity, OFFSET>(),
-            GetIids,
+            GetIids: GetIids::<Identity, OFFSET>,
             GetRuntimeClassName: GetRuntimeClassName::<Name>,
             GetTrustLevel: GetTrustLevel::<Identity, OFFSET>,
         }
@@ -14,6 +14,8 @@ pub mod imp;
 mod agile_reference;
 mod array;
 mod as_impl;
+mod com_object;
+mod component;
 mod error;
 mod event;
 mod guid;
@@ -33,6 +35,8 @@ mod weak;
 pub use agile_reference::*;
 pub use array::*;
 pub use as_impl::*;
+pub use com_object::*;
+pub use component::*;
 pub use error::*;
 pub use event::*;
 pub use guid::*;
@@ -143,6 +143,12 @@ impl PartialEq for BSTR {
 
 impl Eq for BSTR {}
 
+impl std::hash::Hash for BSTR {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_wide().hash(state)
+    }
+}
+
 impl PartialEq<BSTR> for &str {
     fn eq(&self, other: &BSTR) -> bool {
         other == self
@@ -172,3 +178,31 @@ impl Drop for BSTR {
 impl TypeKind for BSTR {
     type TypeKind = ValueType;
 }
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for BSTR {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&String::from_utf16(self.as_wide()).map_err(serde::ser::Error::custom)?)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BSTR {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = BSTR;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> std::result::Result<BSTR, E> {
+                Ok(value.into())
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
@@ -82,6 +82,23 @@ impl HSTRING {
         Ok(Self(std::ptr::NonNull::new(ptr)))
     }
 
+    /// Creates a new `HSTRING` by concatenating the given strings with a single allocation.
+    pub fn concat(strings: &[&HSTRING]) -> Result<Self> {
+        let mut builder = HStringBuilder::with_capacity(strings.iter().map(|string| string.len()).sum())?;
+
+        for string in strings {
+            builder.push_wide(string.as_wide())?;
+        }
+
+        Ok(builder.into())
+    }
+
+    /// # Safety
+    /// `header` must be null or a valid pointer to a header that is not reference-counted by anyone else.
+    pub(super) unsafe fn from_header(header: *mut Header) -> Self {
+        Self(std::ptr::NonNull::new(header))
+    }
+
     fn get_header(&self) -> Option<&Header> {
         self.0.map(|header| unsafe { header.as_ref() })
     }
@@ -191,8 +208,61 @@ impl From<&std::ffi::OsString> for HSTRING {
     }
 }
 
+impl FromIterator<u16> for HSTRING {
+    fn from_iter<I: IntoIterator<Item = u16>>(iter: I) -> Self {
+        HStringBuilder::from_iter(iter).into()
+    }
+}
+
+impl FromIterator<char> for HSTRING {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        HStringBuilder::from_iter(iter).into()
+    }
+}
+
+impl std::ops::Add<&HSTRING> for &HSTRING {
+    type Output = HSTRING;
+
+    fn add(self, other: &HSTRING) -> HSTRING {
+        HSTRING::concat(&[self, other]).unwrap()
+    }
+}
+
+impl std::ops::Add<&HSTRING> for HSTRING {
+    type Output = HSTRING;
+
+    fn add(self, other: &HSTRING) -> HSTRING {
+        &self + other
+    }
+}
+
+impl std::ops::Add<&str> for &HSTRING {
+    type Output = HSTRING;
+
+    fn add(self, other: &str) -> HSTRING {
+        let mut builder = HStringBuilder::with_capacity(self.len() + other.len()).unwrap();
+        builder.push_wide(self.as_wide()).unwrap();
+        builder.push_str(other).unwrap();
+        builder.into()
+    }
+}
+
+impl std::ops::Add<&str> for HSTRING {
+    type Output = HSTRING;
+
+    fn add(self, other: &str) -> HSTRING {
+        &self + other
+    }
+}
+
 impl Eq for HSTRING {}
 
+impl std::hash::Hash for HSTRING {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_wide().hash(state)
+    }
+}
+
 impl Ord for HSTRING {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         self.as_wide().cmp(other.as_wide())
@@ -403,21 +473,49 @@ impl IntoParam<PCWSTR> for &HSTRING {
     }
 }
 
-const REFERENCE_FLAG: u32 = 1;
+#[cfg(feature = "serde")]
+impl serde::Serialize for HSTRING {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&String::from_utf16(self.as_wide()).map_err(serde::ser::Error::custom)?)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for HSTRING {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = HSTRING;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("a string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> std::result::Result<HSTRING, E> {
+                Ok(value.into())
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+pub(super) const REFERENCE_FLAG: u32 = 1;
 
 #[repr(C)]
-struct Header {
+pub(super) struct Header {
     flags: u32,
-    len: u32,
+    pub(super) len: u32,
     _0: u32,
     _1: u32,
-    data: *mut u16,
+    pub(super) data: *mut u16,
     count: crate::imp::RefCount,
     buffer_start: u16,
 }
 
 impl Header {
-    fn alloc(len: u32) -> Result<*mut Header> {
+    pub(super) fn alloc(len: u32) -> Result<*mut Header> {
         debug_assert!(len != 0);
         // Allocate enough space for header and two bytes per character.
         // The space for the terminating null character is already accounted for inside of `Header`.
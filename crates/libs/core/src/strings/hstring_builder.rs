@@ -0,0 +1,232 @@
+use super::*;
+
+/// A preallocated [`HSTRING`] buffer that may be written to before being converted into an immutable `HSTRING`.
+///
+/// This is the equivalent of [WindowsPreallocateStringBuffer](https://learn.microsoft.com/en-us/windows/win32/api/winstring/nf-winstring-windowspreallocatestringbuffer):
+/// characters are written directly into the buffer backing the resulting `HSTRING` so that the final conversion
+/// does not need to copy the string.
+pub struct HStringBuilder {
+    header: *mut Header,
+    capacity: usize,
+}
+
+impl HStringBuilder {
+    /// Create an empty `HStringBuilder`.
+    ///
+    /// This function does not allocate memory.
+    pub const fn new() -> Self {
+        Self { header: std::ptr::null_mut(), capacity: 0 }
+    }
+
+    /// Create an empty `HStringBuilder` with room for at least `capacity` UTF-16 code units.
+    pub fn with_capacity(capacity: usize) -> Result<Self> {
+        let mut builder = Self::new();
+        builder.reserve(capacity)?;
+        Ok(builder)
+    }
+
+    /// Returns the length of the string. The length is measured in `u16`s (UTF-16 code units).
+    pub fn len(&self) -> usize {
+        if self.header.is_null() {
+            0
+        } else {
+            unsafe { (*self.header).len as usize }
+        }
+    }
+
+    /// Returns `true` if the string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of UTF-16 code units the builder can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Get the string as 16-bit wide characters (wchars).
+    pub fn as_wide(&self) -> &[u16] {
+        if self.header.is_null() {
+            &[]
+        } else {
+            unsafe { std::slice::from_raw_parts((*self.header).data, self.len()) }
+        }
+    }
+
+    /// Get the string as mutable 16-bit wide characters (wchars).
+    pub fn as_wide_mut(&mut self) -> &mut [u16] {
+        if self.header.is_null() {
+            &mut []
+        } else {
+            unsafe { std::slice::from_raw_parts_mut((*self.header).data, self.len()) }
+        }
+    }
+
+    /// Reserves capacity for at least `additional` more UTF-16 code units.
+    pub fn reserve(&mut self, additional: usize) -> Result<()> {
+        let required = self.len().checked_add(additional).ok_or_else(|| Error::from(crate::imp::E_OUTOFMEMORY))?;
+
+        if required <= self.capacity {
+            return Ok(());
+        }
+
+        let capacity = required.max(self.capacity * 2).max(8);
+        let header = Header::alloc(capacity.try_into().map_err(|_| Error::from(crate::imp::E_OUTOFMEMORY))?)?;
+
+        unsafe {
+            (*header).len = 0;
+
+            if !self.header.is_null() {
+                std::ptr::copy_nonoverlapping((*self.header).data, (*header).data, self.len());
+                (*header).len = (*self.header).len;
+                imp::heap_free(self.header as _);
+            }
+        }
+
+        self.header = header;
+        self.capacity = capacity;
+        Ok(())
+    }
+
+    /// Appends a single UTF-16 code unit.
+    pub fn push_u16(&mut self, wide: u16) -> Result<()> {
+        self.push_wide(&[wide])
+    }
+
+    /// Appends a slice of 16 bit characters (wchars).
+    pub fn push_wide(&mut self, wide: &[u16]) -> Result<()> {
+        if wide.is_empty() {
+            return Ok(());
+        }
+
+        self.reserve(wide.len())?;
+
+        unsafe {
+            let len = self.len();
+            std::ptr::copy_nonoverlapping(wide.as_ptr(), (*self.header).data.add(len), wide.len());
+            (*self.header).len = (len + wide.len()) as u32;
+        }
+
+        Ok(())
+    }
+
+    /// Appends a character, encoded as UTF-16.
+    pub fn push(&mut self, c: char) -> Result<()> {
+        self.push_wide(c.encode_utf16(&mut [0; 2]))
+    }
+
+    /// Appends a UTF-8 string slice, encoded as UTF-16.
+    pub fn push_str(&mut self, value: &str) -> Result<()> {
+        // The UTF-8 length is an upper bound on the UTF-16 length.
+        self.reserve(value.len())?;
+        value.encode_utf16().try_for_each(|wide| self.push_u16(wide))
+    }
+
+    /// Truncates the string, keeping the allocated capacity.
+    pub fn clear(&mut self) {
+        if !self.header.is_null() {
+            unsafe { (*self.header).len = 0 };
+        }
+    }
+}
+
+impl Default for HStringBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for HStringBuilder {
+    fn drop(&mut self) {
+        if !self.header.is_null() {
+            unsafe { imp::heap_free(self.header as _) };
+        }
+    }
+}
+
+unsafe impl Send for HStringBuilder {}
+unsafe impl Sync for HStringBuilder {}
+
+impl From<HStringBuilder> for HSTRING {
+    fn from(value: HStringBuilder) -> Self {
+        if value.is_empty() {
+            return Self::new();
+        }
+
+        let value = std::mem::ManuallyDrop::new(value);
+
+        // The buffer is handed over to the `HSTRING` as is, only the terminating null character needs to be written.
+        unsafe {
+            std::ptr::write((*value.header).data.add(value.len()), 0);
+            Self::from_header(value.header)
+        }
+    }
+}
+
+impl std::fmt::Write for HStringBuilder {
+    fn write_str(&mut self, value: &str) -> std::fmt::Result {
+        self.push_str(value).map_err(|_| std::fmt::Error)
+    }
+
+    fn write_char(&mut self, c: char) -> std::fmt::Result {
+        self.push(c).map_err(|_| std::fmt::Error)
+    }
+}
+
+impl std::fmt::Display for HStringBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Decode(|| std::char::decode_utf16(self.as_wide().iter().cloned())))
+    }
+}
+
+impl std::fmt::Debug for HStringBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "\"{}\"", self)
+    }
+}
+
+// `Extend` can't report errors so, like `From<&str>` for `HSTRING`, these panic if the buffer can't be allocated.
+
+impl Extend<u16> for HStringBuilder {
+    fn extend<I: IntoIterator<Item = u16>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0).unwrap();
+        iter.for_each(|wide| self.push_u16(wide).unwrap());
+    }
+}
+
+impl Extend<char> for HStringBuilder {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0).unwrap();
+        iter.for_each(|c| self.push(c).unwrap());
+    }
+}
+
+impl<'a> Extend<&'a str> for HStringBuilder {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| self.push_str(value).unwrap());
+    }
+}
+
+impl<'a> Extend<&'a HSTRING> for HStringBuilder {
+    fn extend<I: IntoIterator<Item = &'a HSTRING>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| self.push_wide(value.as_wide()).unwrap());
+    }
+}
+
+impl FromIterator<u16> for HStringBuilder {
+    fn from_iter<I: IntoIterator<Item = u16>>(iter: I) -> Self {
+        let mut builder = Self::new();
+        builder.extend(iter);
+        builder
+    }
+}
+
+impl FromIterator<char> for HStringBuilder {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut builder = Self::new();
+        builder.extend(iter);
+        builder
+    }
+}
@@ -0,0 +1,82 @@
+use super::*;
+
+/// A borrowed WinRT string, also known as a "fast pass" string, that wraps existing null-terminated
+/// UTF-16 data without allocating or copying it.
+///
+/// An `HStringRef` dereferences to an [`HSTRING`] and may be passed anywhere an `&HSTRING` or an
+/// `HSTRING` parameter is expected. The callee must copy the string if it needs to hold on to it,
+/// which [`HSTRING::clone`] does automatically.
+pub struct HStringRef<'a> {
+    header: HSTRING_HEADER,
+    // Holds the address of `header` once borrowed, since `self` cannot move while it is borrowed.
+    hstring: std::cell::Cell<*const HSTRING_HEADER>,
+    _marker: std::marker::PhantomData<&'a [u16]>,
+}
+
+impl<'a> HStringRef<'a> {
+    /// Create an `HStringRef` from a slice of 16 bit characters (wchars) that must end with a null terminator.
+    ///
+    /// The terminating null character is not considered part of the string.
+    pub fn from_wide(value: &'a [u16]) -> Result<Self> {
+        match value.split_last() {
+            Some((0, value)) => Ok(Self {
+                header: HSTRING_HEADER { flags: REFERENCE_FLAG, len: value.len().try_into()?, padding1: 0, padding2: 0, ptr: value.as_ptr() },
+                hstring: std::cell::Cell::new(std::ptr::null()),
+                _marker: std::marker::PhantomData,
+            }),
+            _ => Err(Error::from_code(imp::E_INVALIDARG)),
+        }
+    }
+
+    fn as_hstring(&self) -> &HSTRING {
+        // An empty `HSTRING` is represented by a null pointer.
+        if self.header.len != 0 {
+            self.hstring.set(&self.header);
+        }
+
+        // SAFETY: an `HSTRING` is exactly equivalent to a pointer to an `HSTRING_HEADER`
+        unsafe { std::mem::transmute(&self.hstring) }
+    }
+}
+
+impl std::ops::Deref for HStringRef<'_> {
+    type Target = HSTRING;
+
+    fn deref(&self) -> &HSTRING {
+        self.as_hstring()
+    }
+}
+
+impl AsRef<HSTRING> for HStringRef<'_> {
+    fn as_ref(&self) -> &HSTRING {
+        self.as_hstring()
+    }
+}
+
+unsafe impl Send for HStringRef<'_> {}
+
+impl std::fmt::Display for HStringRef<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.as_hstring(), f)
+    }
+}
+
+impl std::fmt::Debug for HStringRef<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self.as_hstring(), f)
+    }
+}
+
+impl<'a> TryFrom<&'a [u16]> for HStringRef<'a> {
+    type Error = Error;
+
+    fn try_from(value: &'a [u16]) -> Result<Self> {
+        Self::from_wide(value)
+    }
+}
+
+impl IntoParam<HSTRING> for &HStringRef<'_> {
+    unsafe fn into_param(self) -> Param<HSTRING> {
+        Param::Borrowed(std::mem::transmute_copy(self.as_hstring()))
+    }
+}
@@ -1,5 +1,7 @@
 mod bstr;
 mod hstring;
+mod hstring_builder;
+mod hstring_ref;
 mod literals;
 mod pcstr;
 mod pcwstr;
@@ -8,6 +10,8 @@ mod pwstr;
 
 pub use bstr::*;
 pub use hstring::*;
+pub use hstring_builder::*;
+pub use hstring_ref::*;
 #[doc(hidden)]
 pub use literals::*;
 pub use pcstr::*;
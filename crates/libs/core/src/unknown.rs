@@ -83,22 +83,46 @@ pub trait IUnknownImpl {
 
     /// Gets the trust level of the current object.
     unsafe fn GetTrustLevel(&self, value: *mut i32) -> HRESULT;
+
+    /// Gets the interfaces implemented by the current object, excluding `IUnknown` and `IInspectable`.
+    ///
+    /// # Safety
+    ///
+    /// The returned array is allocated with `CoTaskMemAlloc` and must be freed by the caller.
+    unsafe fn GetIids(&self, count: *mut u32, values: *mut *mut GUID) -> HRESULT;
+
+    /// Gets the controlling `IUnknown` if the object is the inner object of an aggregate.
+    ///
+    /// The `IUnknown` methods of every interface other than the object's identity are delegated to the controlling `IUnknown`.
+    fn outer(&self) -> Option<&IUnknown> {
+        None
+    }
 }
 
 #[cfg(feature = "implement")]
 impl IUnknown_Vtbl {
     pub const fn new<T: IUnknownImpl, const OFFSET: isize>() -> Self {
+        // The identity is found at offset zero and is the non-delegating `IUnknown` of an aggregated object.
         unsafe extern "system" fn QueryInterface<T: IUnknownImpl, const OFFSET: isize>(this: *mut std::ffi::c_void, iid: *const GUID, interface: *mut *mut std::ffi::c_void) -> HRESULT {
             let this = (this as *mut *mut std::ffi::c_void).offset(OFFSET) as *mut T;
-            (*this).QueryInterface(iid, interface)
+            match (*this).outer() {
+                Some(outer) if OFFSET != 0 => outer.query(iid, interface),
+                _ => (*this).QueryInterface(iid, interface),
+            }
         }
         unsafe extern "system" fn AddRef<T: IUnknownImpl, const OFFSET: isize>(this: *mut std::ffi::c_void) -> u32 {
             let this = (this as *mut *mut std::ffi::c_void).offset(OFFSET) as *mut T;
-            (*this).AddRef()
+            match (*this).outer() {
+                Some(outer) if OFFSET != 0 => (outer.vtable().AddRef)(outer.as_raw()),
+                _ => (*this).AddRef(),
+            }
         }
         unsafe extern "system" fn Release<T: IUnknownImpl, const OFFSET: isize>(this: *mut std::ffi::c_void) -> u32 {
             let this = (this as *mut *mut std::ffi::c_void).offset(OFFSET) as *mut T;
-            (*this).Release()
+            match (*this).outer() {
+                Some(outer) if OFFSET != 0 => (outer.vtable().Release)(outer.as_raw()),
+                _ => (*this).Release(),
+            }
         }
         Self { QueryInterface: QueryInterface::<T, OFFSET>, AddRef: AddRef::<T, OFFSET>, Release: Release::<T, OFFSET> }
     }
@@ -209,7 +209,7 @@ impl TryFrom<&VARIANT> for IUnknown {
                 let unknown: &IUnknown = std::mem::transmute(&from.0.Anonymous.Anonymous.Anonymous.punkVal);
                 Ok(unknown.clone())
             } else {
-                Err(Error { code: imp::TYPE_E_TYPEMISMATCH, info: None })
+                Err(Error::from_code(imp::TYPE_E_TYPEMISMATCH))
             }
         }
     }
@@ -223,7 +223,7 @@ impl TryFrom<&PROPVARIANT> for IUnknown {
                 let unknown: &IUnknown = std::mem::transmute(&from.0.Anonymous.Anonymous.Anonymous.punkVal);
                 Ok(unknown.clone())
             } else {
-                Err(Error { code: imp::TYPE_E_TYPEMISMATCH, info: None })
+                Err(Error::from_code(imp::TYPE_E_TYPEMISMATCH))
             }
         }
     }
@@ -628,3 +628,767 @@ impl TryFrom<&PROPVARIANT> for f64 {
         HRESULT(unsafe { imp::PropVariantToDouble(&from.0, &mut value) }).and_then(|| value)
     }
 }
+
+// VT_CY
+
+/// A currency value ([CY](https://learn.microsoft.com/en-us/windows/win32/api/wtypes/ns-wtypes-cy-r1)) stored as a
+/// 64-bit integer scaled by 10,000 to give a fixed-point number with four digits after the decimal point.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Currency(pub i64);
+
+impl From<Currency> for f64 {
+    fn from(value: Currency) -> Self {
+        value.0 as f64 / 10_000.0
+    }
+}
+
+impl From<Currency> for VARIANT {
+    fn from(value: Currency) -> Self {
+        VARIANT::from_parts(imp::VT_CY, imp::VARIANT_0_0_0 { cyVal: imp::CY { int64: value.0 } })
+    }
+}
+
+impl From<Currency> for PROPVARIANT {
+    fn from(value: Currency) -> Self {
+        PROPVARIANT::from_parts(imp::VT_CY, imp::PROPVARIANT_0_0_0 { cyVal: imp::CY { int64: value.0 } })
+    }
+}
+
+impl TryFrom<&VARIANT> for Currency {
+    type Error = Error;
+    fn try_from(from: &VARIANT) -> Result<Self> {
+        match from.to_value()? {
+            VariantValue::Currency(value) => Ok(value),
+            _ => Err(Error::from_code(imp::TYPE_E_TYPEMISMATCH)),
+        }
+    }
+}
+
+impl TryFrom<&PROPVARIANT> for Currency {
+    type Error = Error;
+    fn try_from(from: &PROPVARIANT) -> Result<Self> {
+        match from.to_value()? {
+            VariantValue::Currency(value) => Ok(value),
+            _ => Err(Error::from_code(imp::TYPE_E_TYPEMISMATCH)),
+        }
+    }
+}
+
+// VT_DATE
+
+/// An OLE Automation date ([DATE](https://learn.microsoft.com/en-us/cpp/atl-mfc-shared/date-type)) stored as the
+/// number of days since midnight, 30 December 1899, with the fractional part representing the time of day.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Date(pub f64);
+
+impl Date {
+    // The `Date` for midnight, 1 January 1970.
+    const UNIX_EPOCH: f64 = 25569.0;
+    const SECONDS_PER_DAY: f64 = 86400.0;
+
+    // Dates before 30 December 1899 store a negative number of days but a positive time of day, so the
+    // fraction must be flipped to get a value that may be used for arithmetic.
+    fn to_linear(self) -> f64 {
+        if self.0 < 0.0 {
+            self.0.trunc() - self.0.fract()
+        } else {
+            self.0
+        }
+    }
+
+    fn from_linear(value: f64) -> Self {
+        if value < 0.0 {
+            let days = value.floor();
+            Self(days - (value - days))
+        } else {
+            Self(value)
+        }
+    }
+}
+
+impl From<std::time::SystemTime> for Date {
+    fn from(value: std::time::SystemTime) -> Self {
+        let seconds = match value.duration_since(std::time::UNIX_EPOCH) {
+            Ok(duration) => duration.as_secs_f64(),
+            Err(error) => -error.duration().as_secs_f64(),
+        };
+
+        Self::from_linear(Self::UNIX_EPOCH + seconds / Self::SECONDS_PER_DAY)
+    }
+}
+
+impl TryFrom<Date> for std::time::SystemTime {
+    type Error = Error;
+    fn try_from(value: Date) -> Result<Self> {
+        // A `Date` is only accurate to around a millisecond so the result is rounded to avoid returning noise.
+        let seconds = ((value.to_linear() - Date::UNIX_EPOCH) * Date::SECONDS_PER_DAY * 1000.0).round() / 1000.0;
+
+        if !seconds.is_finite() || seconds.abs() >= u64::MAX as f64 {
+            return Err(Error::from_code(imp::E_INVALIDARG));
+        }
+
+        let duration = std::time::Duration::from_secs_f64(seconds.abs());
+
+        if seconds < 0.0 { std::time::UNIX_EPOCH.checked_sub(duration) } else { std::time::UNIX_EPOCH.checked_add(duration) }.ok_or_else(|| Error::from_code(imp::E_INVALIDARG))
+    }
+}
+
+impl From<Date> for VARIANT {
+    fn from(value: Date) -> Self {
+        VARIANT::from_parts(imp::VT_DATE, imp::VARIANT_0_0_0 { date: value.0 })
+    }
+}
+
+impl From<Date> for PROPVARIANT {
+    fn from(value: Date) -> Self {
+        PROPVARIANT::from_parts(imp::VT_DATE, imp::PROPVARIANT_0_0_0 { date: value.0 })
+    }
+}
+
+impl TryFrom<&VARIANT> for Date {
+    type Error = Error;
+    fn try_from(from: &VARIANT) -> Result<Self> {
+        match from.to_value()? {
+            VariantValue::Date(value) => Ok(value),
+            _ => Err(Error::from_code(imp::TYPE_E_TYPEMISMATCH)),
+        }
+    }
+}
+
+impl TryFrom<&PROPVARIANT> for Date {
+    type Error = Error;
+    fn try_from(from: &PROPVARIANT) -> Result<Self> {
+        match from.to_value()? {
+            VariantValue::Date(value) => Ok(value),
+            _ => Err(Error::from_code(imp::TYPE_E_TYPEMISMATCH)),
+        }
+    }
+}
+
+// VT_DECIMAL
+
+/// A decimal value ([DECIMAL](https://learn.microsoft.com/en-us/windows/win32/api/wtypes/ns-wtypes-decimal-r1))
+/// made up of a signed 96-bit integer mantissa divided by a power of ten between 0 and 28.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Decimal {
+    mantissa: i128,
+    scale: u8,
+}
+
+impl Decimal {
+    const MAX_MANTISSA: i128 = (1 << 96) - 1;
+    const MAX_SCALE: u8 = 28;
+    const NEGATIVE: u8 = 0x80;
+
+    /// Creates a `Decimal` with the value `mantissa / 10^scale`.
+    ///
+    /// Returns an error if the mantissa does not fit in 96 bits or the scale is greater than 28.
+    pub fn new(mantissa: i128, scale: u8) -> Result<Self> {
+        if mantissa.abs() > Self::MAX_MANTISSA || scale > Self::MAX_SCALE {
+            return Err(Error::from_code(imp::E_INVALIDARG));
+        }
+
+        Ok(Self { mantissa, scale })
+    }
+
+    /// Returns the signed mantissa of the value.
+    pub fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    /// Returns the power of ten that the mantissa is divided by.
+    pub fn scale(&self) -> u8 {
+        self.scale
+    }
+
+    fn from_abi(value: &imp::DECIMAL) -> Self {
+        unsafe {
+            let mantissa = ((value.Hi32 as i128) << 64) | value.Anonymous2.Lo64 as i128;
+            let mantissa = if value.Anonymous1.Anonymous.sign & Self::NEGATIVE == 0 { mantissa } else { -mantissa };
+            Self { mantissa, scale: value.Anonymous1.Anonymous.scale }
+        }
+    }
+
+    fn to_abi(self) -> imp::DECIMAL {
+        let magnitude = self.mantissa.unsigned_abs();
+        let sign = if self.mantissa < 0 { Self::NEGATIVE } else { 0 };
+
+        imp::DECIMAL {
+            wReserved: imp::VT_DECIMAL,
+            Anonymous1: imp::DECIMAL_0 { Anonymous: imp::DECIMAL_0_0 { scale: self.scale, sign } },
+            Hi32: (magnitude >> 64) as u32,
+            Anonymous2: imp::DECIMAL_1 { Lo64: magnitude as u64 },
+        }
+    }
+}
+
+impl From<Decimal> for f64 {
+    fn from(value: Decimal) -> Self {
+        value.mantissa as f64 / 10f64.powi(value.scale as i32)
+    }
+}
+
+impl std::fmt::Display for Decimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let digits = self.mantissa.unsigned_abs().to_string();
+        let scale = self.scale as usize;
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+
+        if scale == 0 {
+            write!(f, "{sign}{digits}")
+        } else if digits.len() > scale {
+            let (whole, fraction) = digits.split_at(digits.len() - scale);
+            write!(f, "{sign}{whole}.{fraction}")
+        } else {
+            write!(f, "{sign}0.{digits:0>scale$}")
+        }
+    }
+}
+
+impl From<Decimal> for VARIANT {
+    fn from(value: Decimal) -> Self {
+        Self(imp::VARIANT { Anonymous: imp::VARIANT_0 { decVal: value.to_abi() } })
+    }
+}
+
+impl From<Decimal> for PROPVARIANT {
+    fn from(value: Decimal) -> Self {
+        Self(imp::PROPVARIANT { Anonymous: imp::PROPVARIANT_0 { decVal: value.to_abi() } })
+    }
+}
+
+impl TryFrom<&VARIANT> for Decimal {
+    type Error = Error;
+    fn try_from(from: &VARIANT) -> Result<Self> {
+        match from.to_value()? {
+            VariantValue::Decimal(value) => Ok(value),
+            _ => Err(Error::from_code(imp::TYPE_E_TYPEMISMATCH)),
+        }
+    }
+}
+
+impl TryFrom<&PROPVARIANT> for Decimal {
+    type Error = Error;
+    fn try_from(from: &PROPVARIANT) -> Result<Self> {
+        match from.to_value()? {
+            VariantValue::Decimal(value) => Ok(value),
+            _ => Err(Error::from_code(imp::TYPE_E_TYPEMISMATCH)),
+        }
+    }
+}
+
+// VT_ERROR
+
+impl From<HRESULT> for VARIANT {
+    fn from(value: HRESULT) -> Self {
+        VARIANT::from_parts(imp::VT_ERROR, imp::VARIANT_0_0_0 { scode: value.0 })
+    }
+}
+
+impl From<HRESULT> for PROPVARIANT {
+    fn from(value: HRESULT) -> Self {
+        PROPVARIANT::from_parts(imp::VT_ERROR, imp::PROPVARIANT_0_0_0 { scode: value.0 })
+    }
+}
+
+impl TryFrom<&VARIANT> for HRESULT {
+    type Error = Error;
+    fn try_from(from: &VARIANT) -> Result<Self> {
+        match from.to_value()? {
+            VariantValue::Error(value) => Ok(value),
+            _ => Err(Error::from_code(imp::TYPE_E_TYPEMISMATCH)),
+        }
+    }
+}
+
+impl TryFrom<&PROPVARIANT> for HRESULT {
+    type Error = Error;
+    fn try_from(from: &PROPVARIANT) -> Result<Self> {
+        match from.to_value()? {
+            VariantValue::Error(value) => Ok(value),
+            _ => Err(Error::from_code(imp::TYPE_E_TYPEMISMATCH)),
+        }
+    }
+}
+
+// VT_ARRAY and VT_VECTOR
+
+#[doc(hidden)]
+pub trait VariantElement: Sized {
+    const VT: u16;
+    type Abi: Copy;
+    fn into_abi(self) -> Self::Abi;
+    /// # Safety
+    /// The `abi` value must be a valid instance of the element type. Any resources it owns are copied rather than taken.
+    unsafe fn from_abi(abi: &Self::Abi) -> Self;
+}
+
+/// A type that may be stored in a `SAFEARRAY` inside a [`VARIANT`].
+#[doc(hidden)]
+pub trait SafeArrayElement: VariantElement {}
+
+/// A type that may be stored in a counted `VT_VECTOR` array inside a [`PROPVARIANT`].
+#[doc(hidden)]
+pub trait VectorElement: VariantElement {}
+
+macro_rules! variant_element {
+    ($($ty:ty => $vt:ident,)*) => {
+        $(
+            impl VariantElement for $ty {
+                const VT: u16 = imp::$vt;
+                type Abi = Self;
+                fn into_abi(self) -> Self {
+                    self
+                }
+                unsafe fn from_abi(abi: &Self) -> Self {
+                    *abi
+                }
+            }
+            impl SafeArrayElement for $ty {}
+            impl VectorElement for $ty {}
+        )*
+    };
+}
+
+variant_element! {
+    i8 => VT_I1,
+    u8 => VT_UI1,
+    i16 => VT_I2,
+    u16 => VT_UI2,
+    i32 => VT_I4,
+    u32 => VT_UI4,
+    i64 => VT_I8,
+    u64 => VT_UI8,
+    f32 => VT_R4,
+    f64 => VT_R8,
+    Currency => VT_CY,
+    Date => VT_DATE,
+    HRESULT => VT_ERROR,
+}
+
+impl VariantElement for bool {
+    const VT: u16 = imp::VT_BOOL;
+    type Abi = imp::VARIANT_BOOL;
+    fn into_abi(self) -> Self::Abi {
+        if self {
+            -1
+        } else {
+            0
+        }
+    }
+    unsafe fn from_abi(abi: &Self::Abi) -> Self {
+        *abi != 0
+    }
+}
+
+impl SafeArrayElement for bool {}
+impl VectorElement for bool {}
+
+impl VariantElement for BSTR {
+    const VT: u16 = imp::VT_BSTR;
+    type Abi = imp::BSTR;
+    fn into_abi(self) -> Self::Abi {
+        self.into_raw()
+    }
+    unsafe fn from_abi(abi: &Self::Abi) -> Self {
+        std::mem::transmute::<&Self::Abi, &Self>(abi).clone()
+    }
+}
+
+impl SafeArrayElement for BSTR {}
+impl VectorElement for BSTR {}
+
+impl VariantElement for Decimal {
+    const VT: u16 = imp::VT_DECIMAL;
+    type Abi = imp::DECIMAL;
+    fn into_abi(self) -> Self::Abi {
+        self.to_abi()
+    }
+    unsafe fn from_abi(abi: &Self::Abi) -> Self {
+        Decimal::from_abi(abi)
+    }
+}
+
+impl SafeArrayElement for Decimal {}
+
+impl VariantElement for VARIANT {
+    const VT: u16 = imp::VT_VARIANT;
+    type Abi = imp::VARIANT;
+    fn into_abi(self) -> Self::Abi {
+        std::mem::ManuallyDrop::new(self).0
+    }
+    unsafe fn from_abi(abi: &Self::Abi) -> Self {
+        std::mem::transmute::<&Self::Abi, &Self>(abi).clone()
+    }
+}
+
+impl SafeArrayElement for VARIANT {}
+
+impl VariantElement for PROPVARIANT {
+    const VT: u16 = imp::VT_VARIANT;
+    type Abi = imp::PROPVARIANT;
+    fn into_abi(self) -> Self::Abi {
+        std::mem::ManuallyDrop::new(self).0
+    }
+    unsafe fn from_abi(abi: &Self::Abi) -> Self {
+        std::mem::transmute::<&Self::Abi, &Self>(abi).clone()
+    }
+}
+
+impl VectorElement for PROPVARIANT {}
+
+impl<T: SafeArrayElement> From<Vec<T>> for VARIANT {
+    fn from(value: Vec<T>) -> Self {
+        unsafe {
+            let array = imp::SafeArrayCreateVector(T::VT, 0, value.len().try_into().unwrap());
+            assert!(!array.is_null(), "could not allocate SAFEARRAY");
+
+            let mut data = std::ptr::null_mut();
+            HRESULT(imp::SafeArrayAccessData(array, &mut data)).unwrap();
+            let data = data as *mut T::Abi;
+
+            for (index, element) in value.into_iter().enumerate() {
+                data.add(index).write(element.into_abi());
+            }
+
+            imp::SafeArrayUnaccessData(array);
+            VARIANT::from_parts(imp::VT_ARRAY | T::VT, imp::VARIANT_0_0_0 { parray: array })
+        }
+    }
+}
+
+impl<T: SafeArrayElement + Clone> From<&[T]> for VARIANT {
+    fn from(value: &[T]) -> Self {
+        value.to_vec().into()
+    }
+}
+
+impl<T: SafeArrayElement> TryFrom<&VARIANT> for Vec<T> {
+    type Error = Error;
+    fn try_from(from: &VARIANT) -> Result<Self> {
+        unsafe {
+            let vt = from.vt();
+            let value = &from.0.Anonymous.Anonymous.Anonymous;
+
+            if vt == imp::VT_ARRAY | T::VT {
+                read_safe_array(value.parray)
+            } else if vt == imp::VT_BYREF | imp::VT_ARRAY | T::VT && !value.pparray.is_null() {
+                read_safe_array(*value.pparray)
+            } else {
+                Err(Error::from_code(imp::TYPE_E_TYPEMISMATCH))
+            }
+        }
+    }
+}
+
+unsafe fn read_safe_array<T: SafeArrayElement>(array: *const imp::SAFEARRAY) -> Result<Vec<T>> {
+    if array.is_null() {
+        return Ok(Vec::new());
+    }
+
+    if imp::SafeArrayGetDim(array) != 1 {
+        return Err(Error::from_code(imp::TYPE_E_TYPEMISMATCH));
+    }
+
+    let mut lower = 0;
+    let mut upper = 0;
+    HRESULT(imp::SafeArrayGetLBound(array, 1, &mut lower)).ok()?;
+    HRESULT(imp::SafeArrayGetUBound(array, 1, &mut upper)).ok()?;
+    let len: usize = (upper as i64 - lower as i64 + 1).try_into()?;
+
+    let mut data = std::ptr::null_mut();
+    HRESULT(imp::SafeArrayAccessData(array, &mut data)).ok()?;
+    let data = data as *const T::Abi;
+    let result = (0..len).map(|index| T::from_abi(&*data.add(index))).collect();
+    imp::SafeArrayUnaccessData(array);
+    Ok(result)
+}
+
+impl<T: VectorElement> From<Vec<T>> for PROPVARIANT {
+    fn from(value: Vec<T>) -> Self {
+        unsafe {
+            let len = value.len();
+
+            // A `PROPVARIANT` vector must be allocated with `CoTaskMemAlloc` so that `PropVariantClear` can free it.
+            let data = if len == 0 {
+                std::ptr::null_mut()
+            } else {
+                let data = imp::CoTaskMemAlloc(len.checked_mul(std::mem::size_of::<T::Abi>()).unwrap()) as *mut T::Abi;
+                assert!(!data.is_null(), "could not allocate memory");
+                data
+            };
+
+            for (index, element) in value.into_iter().enumerate() {
+                data.add(index).write(element.into_abi());
+            }
+
+            // Every counted array has the same layout so `caub` stands in for whichever one matches `T`.
+            PROPVARIANT::from_parts(imp::VT_VECTOR | T::VT, imp::PROPVARIANT_0_0_0 { caub: imp::CAUB { cElems: len.try_into().unwrap(), pElems: data as *mut u8 } })
+        }
+    }
+}
+
+impl<T: VectorElement + Clone> From<&[T]> for PROPVARIANT {
+    fn from(value: &[T]) -> Self {
+        value.to_vec().into()
+    }
+}
+
+impl<T: VectorElement> TryFrom<&PROPVARIANT> for Vec<T> {
+    type Error = Error;
+    fn try_from(from: &PROPVARIANT) -> Result<Self> {
+        unsafe {
+            if from.vt() != imp::VT_VECTOR | T::VT {
+                return Err(Error::from_code(imp::TYPE_E_TYPEMISMATCH));
+            }
+
+            let vector = from.0.Anonymous.Anonymous.Anonymous.caub;
+            let data = vector.pElems as *const T::Abi;
+            Ok((0..vector.cElems as usize).map(|index| T::from_abi(&*data.add(index))).collect())
+        }
+    }
+}
+
+// VariantValue
+
+/// The contents of a [`VARIANT`] or [`PROPVARIANT`], suitable for pattern matching.
+///
+/// Signed and unsigned machine integers (`VT_INT` and `VT_UINT`) are reported as `I32` and `U32`, by-reference
+/// values are dereferenced, and a null `VT_UNKNOWN` or `VT_DISPATCH` pointer is reported as `Null`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum VariantValue {
+    /// No value (`VT_EMPTY`).
+    Empty,
+    /// A SQL-style null value (`VT_NULL`).
+    Null,
+    /// A boolean value (`VT_BOOL`).
+    Bool(bool),
+    /// A signed 8-bit integer (`VT_I1`).
+    I8(i8),
+    /// An unsigned 8-bit integer (`VT_UI1`).
+    U8(u8),
+    /// A signed 16-bit integer (`VT_I2`).
+    I16(i16),
+    /// An unsigned 16-bit integer (`VT_UI2`).
+    U16(u16),
+    /// A signed 32-bit integer (`VT_I4` or `VT_INT`).
+    I32(i32),
+    /// An unsigned 32-bit integer (`VT_UI4` or `VT_UINT`).
+    U32(u32),
+    /// A signed 64-bit integer (`VT_I8`).
+    I64(i64),
+    /// An unsigned 64-bit integer (`VT_UI8`).
+    U64(u64),
+    /// A 32-bit floating point number (`VT_R4`).
+    F32(f32),
+    /// A 64-bit floating point number (`VT_R8`).
+    F64(f64),
+    /// A currency value (`VT_CY`).
+    Currency(Currency),
+    /// A date and time (`VT_DATE`).
+    Date(Date),
+    /// A decimal value (`VT_DECIMAL`).
+    Decimal(Decimal),
+    /// An error code (`VT_ERROR`).
+    Error(HRESULT),
+    /// A string (`VT_BSTR`).
+    String(BSTR),
+    /// A COM object (`VT_UNKNOWN`).
+    Unknown(IUnknown),
+    /// A COM object implementing `IDispatch` (`VT_DISPATCH`).
+    Dispatch(IUnknown),
+    /// A one-dimensional `SAFEARRAY` (`VT_ARRAY`) or counted vector (`VT_VECTOR`).
+    Array(Vec<VariantValue>),
+}
+
+impl VARIANT {
+    fn from_parts(vt: u16, value: imp::VARIANT_0_0_0) -> Self {
+        Self(imp::VARIANT { Anonymous: imp::VARIANT_0 { Anonymous: imp::VARIANT_0_0 { vt, wReserved1: 0, wReserved2: 0, wReserved3: 0, Anonymous: value } } })
+    }
+
+    fn vt(&self) -> u16 {
+        unsafe { self.0.Anonymous.Anonymous.vt }
+    }
+
+    /// Returns the contents of the `VARIANT` as a [`VariantValue`].
+    ///
+    /// Returns an error if the `VARIANT` holds a type that `VariantValue` cannot represent, such as a multidimensional array.
+    pub fn to_value(&self) -> Result<VariantValue> {
+        let vt = self.vt();
+
+        if vt & imp::VT_BYREF != 0 {
+            let mut value = Self::new();
+            HRESULT(unsafe { imp::VariantCopyInd(&mut value.0, &self.0) }).ok()?;
+            return value.to_value();
+        }
+
+        if vt & imp::VT_ARRAY != 0 {
+            return self.to_array_value(vt & imp::VT_TYPEMASK);
+        }
+
+        unsafe {
+            let value = &self.0.Anonymous.Anonymous.Anonymous;
+
+            Ok(match vt {
+                imp::VT_EMPTY => VariantValue::Empty,
+                imp::VT_NULL => VariantValue::Null,
+                imp::VT_BOOL => VariantValue::Bool(value.boolVal != 0),
+                imp::VT_I1 => VariantValue::I8(value.cVal),
+                imp::VT_UI1 => VariantValue::U8(value.bVal),
+                imp::VT_I2 => VariantValue::I16(value.iVal),
+                imp::VT_UI2 => VariantValue::U16(value.uiVal),
+                imp::VT_I4 | imp::VT_INT => VariantValue::I32(value.lVal),
+                imp::VT_UI4 | imp::VT_UINT => VariantValue::U32(value.ulVal),
+                imp::VT_I8 => VariantValue::I64(value.llVal),
+                imp::VT_UI8 => VariantValue::U64(value.ullVal),
+                imp::VT_R4 => VariantValue::F32(value.fltVal),
+                imp::VT_R8 => VariantValue::F64(value.dblVal),
+                imp::VT_CY => VariantValue::Currency(Currency(value.cyVal.int64)),
+                imp::VT_DATE => VariantValue::Date(Date(value.date)),
+                imp::VT_DECIMAL => VariantValue::Decimal(Decimal::from_abi(&self.0.Anonymous.decVal)),
+                imp::VT_ERROR => VariantValue::Error(HRESULT(value.scode)),
+                imp::VT_BSTR => VariantValue::String(<BSTR as VariantElement>::from_abi(&value.bstrVal)),
+                imp::VT_UNKNOWN => unknown_value(&value.punkVal, VariantValue::Unknown),
+                imp::VT_DISPATCH => unknown_value(&value.pdispVal, VariantValue::Dispatch),
+                _ => return Err(Error::from_code(imp::TYPE_E_TYPEMISMATCH)),
+            })
+        }
+    }
+
+    fn to_array_value(&self, vt: u16) -> Result<VariantValue> {
+        fn array<T: SafeArrayElement>(from: &VARIANT, value: fn(T) -> VariantValue) -> Result<VariantValue> {
+            Ok(VariantValue::Array(Vec::<T>::try_from(from)?.into_iter().map(value).collect()))
+        }
+
+        match vt {
+            imp::VT_BOOL => array(self, VariantValue::Bool),
+            imp::VT_I1 => array(self, VariantValue::I8),
+            imp::VT_UI1 => array(self, VariantValue::U8),
+            imp::VT_I2 => array(self, VariantValue::I16),
+            imp::VT_UI2 => array(self, VariantValue::U16),
+            imp::VT_I4 => array(self, VariantValue::I32),
+            imp::VT_UI4 => array(self, VariantValue::U32),
+            imp::VT_I8 => array(self, VariantValue::I64),
+            imp::VT_UI8 => array(self, VariantValue::U64),
+            imp::VT_R4 => array(self, VariantValue::F32),
+            imp::VT_R8 => array(self, VariantValue::F64),
+            imp::VT_CY => array(self, VariantValue::Currency),
+            imp::VT_DATE => array(self, VariantValue::Date),
+            imp::VT_DECIMAL => array(self, VariantValue::Decimal),
+            imp::VT_ERROR => array(self, VariantValue::Error),
+            imp::VT_BSTR => array(self, VariantValue::String),
+            imp::VT_VARIANT => Ok(VariantValue::Array(Vec::<VARIANT>::try_from(self)?.iter().map(VARIANT::to_value).collect::<Result<_>>()?)),
+            _ => Err(Error::from_code(imp::TYPE_E_TYPEMISMATCH)),
+        }
+    }
+}
+
+impl PROPVARIANT {
+    fn from_parts(vt: u16, value: imp::PROPVARIANT_0_0_0) -> Self {
+        Self(imp::PROPVARIANT { Anonymous: imp::PROPVARIANT_0 { Anonymous: imp::PROPVARIANT_0_0 { vt, wReserved1: 0, wReserved2: 0, wReserved3: 0, Anonymous: value } } })
+    }
+
+    fn vt(&self) -> u16 {
+        unsafe { self.0.Anonymous.Anonymous.vt }
+    }
+
+    /// Returns the contents of the `PROPVARIANT` as a [`VariantValue`].
+    ///
+    /// Returns an error if the `PROPVARIANT` holds a type that `VariantValue` cannot represent, such as a `FILETIME`.
+    pub fn to_value(&self) -> Result<VariantValue> {
+        fn vector<T: VectorElement>(from: &PROPVARIANT, value: fn(T) -> VariantValue) -> Result<VariantValue> {
+            Ok(VariantValue::Array(Vec::<T>::try_from(from)?.into_iter().map(value).collect()))
+        }
+
+        let vt = self.vt();
+
+        if vt & imp::VT_VECTOR == 0 {
+            return VARIANT::try_from(self)?.to_value();
+        }
+
+        match vt & imp::VT_TYPEMASK {
+            imp::VT_BOOL => vector(self, VariantValue::Bool),
+            imp::VT_I1 => vector(self, VariantValue::I8),
+            imp::VT_UI1 => vector(self, VariantValue::U8),
+            imp::VT_I2 => vector(self, VariantValue::I16),
+            imp::VT_UI2 => vector(self, VariantValue::U16),
+            imp::VT_I4 => vector(self, VariantValue::I32),
+            imp::VT_UI4 => vector(self, VariantValue::U32),
+            imp::VT_I8 => vector(self, VariantValue::I64),
+            imp::VT_UI8 => vector(self, VariantValue::U64),
+            imp::VT_R4 => vector(self, VariantValue::F32),
+            imp::VT_R8 => vector(self, VariantValue::F64),
+            imp::VT_CY => vector(self, VariantValue::Currency),
+            imp::VT_DATE => vector(self, VariantValue::Date),
+            imp::VT_ERROR => vector(self, VariantValue::Error),
+            imp::VT_BSTR => vector(self, VariantValue::String),
+            imp::VT_VARIANT => Ok(VariantValue::Array(Vec::<PROPVARIANT>::try_from(self)?.iter().map(PROPVARIANT::to_value).collect::<Result<_>>()?)),
+            _ => Err(Error::from_code(imp::TYPE_E_TYPEMISMATCH)),
+        }
+    }
+}
+
+unsafe fn unknown_value(value: &*mut std::ffi::c_void, kind: fn(IUnknown) -> VariantValue) -> VariantValue {
+    match IUnknown::from_raw_borrowed(value) {
+        Some(unknown) => kind(unknown.clone()),
+        None => VariantValue::Null,
+    }
+}
+
+impl From<VariantValue> for VARIANT {
+    fn from(value: VariantValue) -> Self {
+        match value {
+            VariantValue::Empty => Self::new(),
+            VariantValue::Null => Self::from_parts(imp::VT_NULL, imp::VARIANT_0_0_0 { llVal: 0 }),
+            VariantValue::Bool(value) => value.into(),
+            VariantValue::I8(value) => value.into(),
+            VariantValue::U8(value) => value.into(),
+            VariantValue::I16(value) => value.into(),
+            VariantValue::U16(value) => value.into(),
+            VariantValue::I32(value) => value.into(),
+            VariantValue::U32(value) => value.into(),
+            VariantValue::I64(value) => value.into(),
+            VariantValue::U64(value) => value.into(),
+            VariantValue::F32(value) => value.into(),
+            VariantValue::F64(value) => value.into(),
+            VariantValue::Currency(value) => value.into(),
+            VariantValue::Date(value) => value.into(),
+            VariantValue::Decimal(value) => value.into(),
+            VariantValue::Error(value) => value.into(),
+            VariantValue::String(value) => value.into(),
+            VariantValue::Unknown(value) => value.into(),
+            VariantValue::Dispatch(value) => Self::from_parts(imp::VT_DISPATCH, imp::VARIANT_0_0_0 { pdispVal: value.into_raw() }),
+            VariantValue::Array(value) => value.into_iter().map(Self::from).collect::<Vec<_>>().into(),
+        }
+    }
+}
+
+impl From<VariantValue> for PROPVARIANT {
+    fn from(value: VariantValue) -> Self {
+        match value {
+            VariantValue::Empty => Self::new(),
+            VariantValue::Null => Self::from_parts(imp::VT_NULL, imp::PROPVARIANT_0_0_0 { hVal: 0 }),
+            VariantValue::Bool(value) => value.into(),
+            VariantValue::I8(value) => value.into(),
+            VariantValue::U8(value) => value.into(),
+            VariantValue::I16(value) => value.into(),
+            VariantValue::U16(value) => value.into(),
+            VariantValue::I32(value) => value.into(),
+            VariantValue::U32(value) => value.into(),
+            VariantValue::I64(value) => value.into(),
+            VariantValue::U64(value) => value.into(),
+            VariantValue::F32(value) => value.into(),
+            VariantValue::F64(value) => value.into(),
+            VariantValue::Currency(value) => value.into(),
+            VariantValue::Date(value) => value.into(),
+            VariantValue::Decimal(value) => value.into(),
+            VariantValue::Error(value) => value.into(),
+            VariantValue::String(value) => value.into(),
+            VariantValue::Unknown(value) => value.into(),
+            VariantValue::Dispatch(value) => Self::from_parts(imp::VT_DISPATCH, imp::PROPVARIANT_0_0_0 { pdispVal: value.into_raw() }),
+            VariantValue::Array(value) => value.into_iter().map(Self::from).collect::<Vec<_>>().into(),
+        }
+    }
+}
@@ -28,20 +28,66 @@ use quote::{quote, ToTokens};
 ///     // Call interface methods...
 /// }
 /// ```
+///
+/// # Aggregation
+///
+/// The `outer` keyword, as in `#[implement(IValue, outer)]`, allows the object to be created as the inner object
+/// of an aggregate with the generated `aggregate` method. The `composable` keyword allows the object to be created
+/// as the outer object of an aggregate, extending a base object, with the generated `compose` method. Interfaces
+/// not implemented by a composable object are provided by its base object, which is available through the
+/// generated `base` method.
+///
+/// # Agility
+///
+/// Objects are agile by default, answering `IAgileObject` so that they may be used from any apartment. An
+/// apartment-bound object may opt out with `Agile = false`, while `Agile = FreeThreaded` additionally aggregates
+/// the free-threaded marshaler so that the object is passed directly, rather than through a proxy, when it is
+/// marshaled to another apartment.
+///
+/// # Crate path
+///
+/// The generated code refers to the `windows` crate's `core` module, or to the `windows-core` crate for crates that
+/// depend on it alone, under whatever name the calling crate uses for them. Use `crate = path` to provide the path
+/// to the `windows-core` API explicitly, for example `#[implement(IValue, crate = ::my_crate::windows_core)]` when
+/// it is re-exported by another crate.
+///
+/// # Tear-offs
+///
+/// Every interface listed reserves a vtable pointer in each instance of the object. Interfaces that are rarely used
+/// may instead be listed with `TearOff`, as in `#[implement(IValue, TearOff(IPersist, IPersistStream))]`, so that
+/// they are allocated, with their own reference count, each time the object is queried for them. A tear-off holds a
+/// reference to the object, which provides its other interfaces, including its identity, but the object cannot be
+/// converted to a tear-off interface directly with `From`.
+///
+/// # Static objects
+///
+/// The generated `into_static` method creates the object in a `StaticComObject`, which may be stored in a `static`
+/// so that its interfaces are handed out without allocation. A static object is never freed and its reference count
+/// is never updated, so it doesn't support weak references.
+///
+/// # Factories
+///
+/// The `factory` keyword implements `Activatable` for a type that implements `Default`, so that it may be
+/// registered with the `component` macro, which exports the class factories of an in-process COM server or
+/// WinRT component.
 #[proc_macro_attribute]
 pub fn implement(attributes: proc_macro::TokenStream, original_type: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let attributes = syn::parse_macro_input!(attributes as ImplementAttributes);
-    let interfaces_len = proc_macro2::Literal::usize_unsuffixed(attributes.implement.len());
+    let krate = crate_path(attributes.krate.as_ref());
+    // The number of vtable pointers between the identity and the implementation, including the delegating identity of
+    // an `outer` object.
+    let vtables_len = proc_macro2::Literal::usize_unsuffixed(attributes.implement.len() + attributes.outer as usize);
 
     let identity_type = if let Some(first) = attributes.implement.first() {
         first.to_ident()
     } else {
-        quote! { ::windows::core::IInspectable }
+        quote! { #krate::IInspectable }
     };
 
     let original_type2 = original_type.clone();
     let original_type2 = syn::parse_macro_input!(original_type2 as syn::ItemStruct);
     let original_ident = original_type2.ident;
+    let original_vis = original_type2.vis;
     let mut constraints = quote! {};
 
     if let Some(where_clause) = original_type2.generics.where_clause {
@@ -56,20 +102,48 @@ pub fn implement(attributes: proc_macro::TokenStream, original_type: proc_macro:
         quote! { <> }
     };
 
+    // `ComObject::downcast` identifies the implementation by its `TypeId`, which only `'static` types have. Objects that
+    // aren't generic are always `'static` while generic objects may borrow, so only the former may be downcast.
+    let com_object_query = if original_type2.generics.params.is_empty() {
+        quote! {
+            if iid == &#krate::imp::IID_COM_OBJECT {
+                let query = &mut *(interface as *mut #krate::imp::ComObjectQuery);
+                if query.type_id == ::core::any::TypeId::of::<#original_ident>() {
+                    query.object = &self.this as *const _ as *const ::core::ffi::c_void;
+                    return #krate::HRESULT(0);
+                }
+                *interface = ::core::ptr::null_mut();
+                return #krate::HRESULT(-2147467262); // E_NOINTERFACE
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     let impl_ident = quote::format_ident!("{}_Impl", original_ident);
-    let vtbl_idents = attributes.implement.iter().map(|implement| implement.to_vtbl_ident());
+    let vtbl_idents = attributes.implement.iter().map(|implement| implement.to_vtbl_ident(&krate));
     let vtbl_idents2 = vtbl_idents.clone();
 
     let vtable_news = attributes.implement.iter().enumerate().map(|(enumerate, implement)| {
-        let vtbl_ident = implement.to_vtbl_ident();
+        let vtbl_ident = implement.to_vtbl_ident(&krate);
         let offset = proc_macro2::Literal::isize_unsuffixed(-1 - enumerate as isize);
         quote! { #vtbl_ident::new::<Self, #original_ident::#generics, #offset>() }
     });
 
+    // Objects stored in a `StaticComObject` use vtables whose reference counting does nothing.
+    let vtbl_idents3 = vtbl_idents.clone();
+
+    let static_vtable_news = attributes.implement.iter().enumerate().map(|(enumerate, implement)| {
+        let vtbl_ident = implement.to_vtbl_ident(&krate);
+        let offset = proc_macro2::Literal::isize_unsuffixed(-1 - enumerate as isize);
+        quote! { #vtbl_ident::new::<#krate::imp::StaticIdentity<Self>, #original_ident::#generics, #offset>() }
+    });
+
     let offset = attributes.implement.iter().enumerate().map(|(offset, _)| proc_macro2::Literal::usize_unsuffixed(offset));
+    let offset2 = offset.clone();
 
     let queries = attributes.implement.iter().enumerate().map(|(count, implement)| {
-        let vtbl_ident = implement.to_vtbl_ident();
+        let vtbl_ident = implement.to_vtbl_ident(&krate);
         let offset = proc_macro2::Literal::usize_unsuffixed(count);
         quote! {
             else if #vtbl_ident::matches(iid) {
@@ -78,8 +152,174 @@ pub fn implement(attributes: proc_macro::TokenStream, original_type: proc_macro:
         }
     });
 
+    // Tear-off interfaces are allocated when queried for rather than reserving a vtable pointer in every object.
+    let tear_off_vtbl_idents = attributes.tear_offs.iter().map(|implement| implement.to_vtbl_ident(&krate));
+
+    let tear_off_vtable_news = attributes.tear_offs.iter().map(|implement| {
+        let vtbl_ident = implement.to_vtbl_ident(&krate);
+        quote! { #vtbl_ident::new::<#krate::imp::TearOff<Self>, #original_ident::#generics, 0>() }
+    });
+
+    let tear_off_queries = attributes.tear_offs.iter().enumerate().map(|(count, implement)| {
+        let vtbl_ident = implement.to_vtbl_ident(&krate);
+        let offset = proc_macro2::Literal::usize_unsuffixed(count);
+        quote! {
+            if #vtbl_ident::matches(iid) {
+                *interface = #krate::imp::TearOff::<Self>::create(&Self::TEAR_OFFS.#offset, #vtbl_ident::matches, self, &self.identity as *const _ as *mut _);
+                return #krate::HRESULT(0);
+            }
+        }
+    });
+
     let trust_level = proc_macro2::Literal::usize_unsuffixed(attributes.trust_level);
 
+    // An `outer` object may be created as the inner object of an aggregate whose `IUnknown` is held in `outer`.
+    let (outer_field, outer_new, outer_impl, outer_methods) = if attributes.outer {
+        (
+            quote! { outer: *mut ::core::ffi::c_void, },
+            quote! { outer: ::core::ptr::null_mut(), },
+            quote! {
+                fn outer(&self) -> ::core::option::Option<&#krate::IUnknown> {
+                    unsafe { <#krate::IUnknown as #krate::Interface>::from_raw_borrowed(&self.outer) }
+                }
+            },
+            quote! {
+                /// Creates the object as the inner object of an aggregate, returning its non-delegating `IInspectable`.
+                ///
+                /// The object's other interfaces delegate their `IUnknown` methods to `outer`, which must hold on to the
+                /// returned `IInspectable` for as long as it lives. No reference to `outer` is held, since it owns the object.
+                fn aggregate(self, outer: &#krate::IUnknown) -> #krate::IInspectable {
+                    let mut this = #impl_ident::#generics::new(self);
+                    this.outer = #krate::Interface::as_raw(outer);
+                    let boxed = ::core::mem::ManuallyDrop::new(::std::boxed::Box::new(this));
+                    unsafe {
+                        ::core::mem::transmute(&boxed.identity)
+                    }
+                }
+            },
+        )
+    } else {
+        (quote! {}, quote! {}, quote! {}, quote! {})
+    };
+
+    // The non-delegating identity of an `outer` object is only handed out as its `IUnknown`, so that every other
+    // interface of an aggregate, including `IInspectable`, is counted and queried by the outer object.
+    let (delegating_field, delegating_new, delegating_static, delegating_vtables, inspectable_query, weak_query) = if attributes.outer {
+        let offset = proc_macro2::Literal::isize_unsuffixed(-1 - attributes.implement.len() as isize);
+        (
+            quote! { delegating_identity: *const #krate::IInspectable_Vtbl, },
+            quote! { delegating_identity: &Self::DELEGATING_IDENTITY, },
+            quote! { delegating_identity: &Self::STATIC_DELEGATING_IDENTITY, },
+            quote! {
+                const DELEGATING_IDENTITY: #krate::IInspectable_Vtbl = #krate::IInspectable_Vtbl::new::<Self, #identity_type, #offset>();
+                const STATIC_DELEGATING_IDENTITY: #krate::IInspectable_Vtbl = #krate::IInspectable_Vtbl::new::<#krate::imp::StaticIdentity<Self>, #identity_type, #offset>();
+            },
+            quote! {
+                if self.outer.is_null() {
+                    &self.identity as *const _ as *mut _
+                } else {
+                    &self.delegating_identity as *const _ as *mut _
+                }
+            },
+            quote! {
+                // The weak references of an aggregate are provided by the outer object.
+                if self.outer.is_null() {
+                    *interface = self.count.query(iid, &self.identity as *const _ as *mut _);
+                }
+            },
+        )
+    } else {
+        (quote! {}, quote! {}, quote! {}, quote! {}, quote! { &self.identity as *const _ as *mut _ }, quote! { *interface = self.count.query(iid, &self.identity as *const _ as *mut _); })
+    };
+
+    // A `composable` object is the outer object of an aggregate, extending a base object held in `base`.
+    let (base_field, base_new, base_query, base_methods) = if attributes.composable {
+        (
+            quote! { base: ::core::option::Option<#krate::IInspectable>, },
+            quote! { base: ::core::option::Option::None, },
+            quote! {
+                if let ::core::option::Option::Some(base) = &self.base {
+                    return #krate::Interface::query(base, iid, interface);
+                }
+            },
+            quote! {
+                /// Creates the object as the outer object of an aggregate, extending the base object returned by `create`.
+                ///
+                /// The `create` function is given the object's identity and must return the non-delegating inner object
+                /// of the base, such as that returned by a composable WinRT class factory's `CreateInstance` method.
+                /// Interfaces that the object does not implement itself are then provided by the base object.
+                fn compose<F: ::core::ops::FnOnce(&#krate::IInspectable) -> #krate::Result<#krate::IInspectable>>(self, create: F) -> #krate::Result<#krate::IInspectable> {
+                    let this = ::std::boxed::Box::into_raw(::std::boxed::Box::new(#impl_ident::#generics::new(self)));
+                    unsafe {
+                        let identity: #krate::IInspectable = ::core::mem::transmute(&(*this).identity);
+                        (*this).base = ::core::option::Option::Some(create(&identity)?);
+                        Ok(identity)
+                    }
+                }
+                /// Try casting the base object, created by `compose`, as the provided interface so that its
+                /// methods may be called.
+                ///
+                /// # Safety
+                ///
+                /// This function can only be safely called if `self` has been heap allocated and pinned using
+                /// the mechanisms provided by `implement` macro.
+                unsafe fn base<I: #krate::Interface>(&self) -> #krate::Result<I> {
+                    let boxed = (self as *const _ as *const *mut ::core::ffi::c_void).sub(1 + #vtables_len) as *mut #impl_ident::#generics;
+                    match &(*boxed).base {
+                        ::core::option::Option::Some(base) => #krate::Interface::cast(base),
+                        ::core::option::Option::None => Err(#krate::HRESULT(-2147467262).into()), // E_NOINTERFACE
+                    }
+                }
+            },
+        )
+    } else {
+        (quote! {}, quote! {}, quote! {}, quote! {})
+    };
+
+    // Objects are agile unless they opt out, and may also aggregate the free-threaded marshaler so that they are not
+    // marshaled through proxies.
+    let agile_query = if attributes.agility == Agility::NotAgile {
+        quote! {}
+    } else {
+        quote! { || iid == &<#krate::imp::IAgileObject as #krate::Interface>::IID }
+    };
+
+    let (marshaler_field, marshaler_new, marshaler_query) = if attributes.agility == Agility::FreeThreaded {
+        (
+            quote! { marshaler: #krate::imp::FreeThreadedMarshaler, },
+            quote! { marshaler: #krate::imp::FreeThreadedMarshaler::new(), },
+            quote! {
+                if iid == &#krate::imp::IID_IMARSHAL {
+                    let outer = #krate::IUnknownImpl::outer(self).map_or(&self.identity as *const _ as *mut _, #krate::Interface::as_raw);
+                    return self.marshaler.query(outer, iid, interface);
+                }
+            },
+        )
+    } else {
+        (quote! {}, quote! {}, quote! {})
+    };
+
+    // A `factory` object may be activated by the class factories exported by the `component` macro, and keeps the
+    // module that exports them loaded while it lives. Static objects don't hold a lock as they're never released.
+    let (module_lock_field, module_lock_new, module_lock_static) = if attributes.factory { (quote! { _module_lock: ::core::option::Option<#krate::imp::ModuleLock>, }, quote! { _module_lock: ::core::option::Option::Some(#krate::imp::ModuleLock::new()), }, quote! { _module_lock: ::core::option::Option::None, }) } else { (quote! {}, quote! {}, quote! {}) };
+
+    let activatable = if attributes.factory {
+        quote! {
+            impl #generics #krate::Activatable for #original_ident::#generics where #constraints {
+                fn activate() -> #krate::Result<#krate::IInspectable> {
+                    ::core::result::Result::Ok(<Self as ::core::default::Default>::default().into())
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let iids = attributes.implement.iter().chain(&attributes.tear_offs).map(|implement| {
+        let interface_ident = implement.to_ident();
+        quote! { <#interface_ident as #krate::Interface>::IID }
+    });
+
     let conversions = attributes.implement.iter().enumerate().map(|(enumerate, implement)| {
         let interface_ident = implement.to_ident();
         let offset = proc_macro2::Literal::usize_unsuffixed(enumerate);
@@ -93,11 +333,11 @@ pub fn implement(attributes: proc_macro::TokenStream, original_type: proc_macro:
                     unsafe { ::core::mem::transmute(vtable_ptr) }
                 }
             }
-            impl #generics ::windows::core::AsImpl<#original_ident::#generics> for #interface_ident where #constraints {
+            impl #generics #krate::AsImpl<#original_ident::#generics> for #interface_ident where #constraints {
                 // SAFETY: the offset is guranteed to be in bounds, and the implementation struct
                 // is guaranteed to live at least as long as `self`.
                 unsafe fn as_impl(&self) -> &#original_ident::#generics {
-                    let this = ::windows::core::Interface::as_raw(self);
+                    let this = #krate::Interface::as_raw(self);
                     // Subtract away the vtable offset plus 1, for the `identity` field, to get
                     // to the impl struct which contains that original implementation type.
                     let this = (this as *mut *mut ::core::ffi::c_void).sub(1 + #offset) as *mut #impl_ident::#generics;
@@ -109,55 +349,93 @@ pub fn implement(attributes: proc_macro::TokenStream, original_type: proc_macro:
 
     let tokens = quote! {
         #[repr(C)]
-        struct #impl_ident #generics where #constraints {
-            identity: *const ::windows::core::IInspectable_Vtbl,
+        #[doc(hidden)]
+        #original_vis struct #impl_ident #generics where #constraints {
+            identity: *const #krate::IInspectable_Vtbl,
             vtables: (#(*const #vtbl_idents,)*),
+            #delegating_field
              this: #original_ident::#generics,
-            count: ::windows::core::imp::WeakRefCount,
+            count: #krate::imp::WeakRefCount,
+            #outer_field
+            #base_field
+            #marshaler_field
+            #module_lock_field
         }
         impl #generics #impl_ident::#generics where #constraints {
             const VTABLES: (#(#vtbl_idents2,)*) = (#(#vtable_news,)*);
-            const IDENTITY: ::windows::core::IInspectable_Vtbl = ::windows::core::IInspectable_Vtbl::new::<Self, #identity_type, 0>();
+            const TEAR_OFFS: (#(#tear_off_vtbl_idents,)*) = (#(#tear_off_vtable_news,)*);
+            const IDENTITY: #krate::IInspectable_Vtbl = #krate::IInspectable_Vtbl::new::<Self, #identity_type, 0>();
+            const STATIC_VTABLES: (#(#vtbl_idents3,)*) = (#(#static_vtable_news,)*);
+            const STATIC_IDENTITY: #krate::IInspectable_Vtbl = #krate::IInspectable_Vtbl::new::<#krate::imp::StaticIdentity<Self>, #identity_type, 0>();
+            #delegating_vtables
             fn new(this: #original_ident::#generics) -> Self {
                 Self {
                     identity: &Self::IDENTITY,
                     vtables:(#(&Self::VTABLES.#offset,)*),
+                    #delegating_new
+                    this,
+                    count: #krate::imp::WeakRefCount::new(),
+                    #outer_new
+                    #base_new
+                    #marshaler_new
+                    #module_lock_new
+                }
+            }
+            const fn new_static(this: #original_ident::#generics) -> Self {
+                Self {
+                    identity: &Self::STATIC_IDENTITY,
+                    vtables:(#(&Self::STATIC_VTABLES.#offset2,)*),
+                    #delegating_static
                     this,
-                    count: ::windows::core::imp::WeakRefCount::new(),
+                    count: #krate::imp::WeakRefCount::new(),
+                    #outer_new
+                    #base_new
+                    #marshaler_new
+                    #module_lock_static
                 }
             }
         }
-         impl #generics ::windows::core::IUnknownImpl for #impl_ident::#generics where #constraints {
+         impl #generics #krate::IUnknownImpl for #impl_ident::#generics where #constraints {
             type Impl = #original_ident::#generics;
             fn get_impl(&self) -> &Self::Impl {
                 &self.this
             }
-            unsafe fn QueryInterface(&self, iid: *const ::windows::core::GUID, interface: *mut *mut ::core::ffi::c_void) -> ::windows::core::HRESULT {
+            unsafe fn QueryInterface(&self, iid: *const #krate::GUID, interface: *mut *mut ::core::ffi::c_void) -> #krate::HRESULT {
                 if iid.is_null() || interface.is_null() {
-                    return ::windows::core::HRESULT(-2147467261); // E_POINTER
+                    return #krate::HRESULT(-2147467261); // E_POINTER
                 }
 
                 let iid = &*iid;
 
-                *interface = if iid == &<::windows::core::IUnknown as ::windows::core::Interface>::IID
-                    || iid == &<::windows::core::IInspectable as ::windows::core::Interface>::IID
-                    || iid == &<::windows::core::imp::IAgileObject as ::windows::core::Interface>::IID {
-                        &self.identity as *const _ as *mut _
+                // The implementation is provided, without adding a reference, to `ComObject::downcast` if it has the type asked for.
+                #com_object_query
+
+                *interface = if iid == &<#krate::IUnknown as #krate::Interface>::IID {
+                    &self.identity as *const _ as *mut _
+                } else if iid == &<#krate::IInspectable as #krate::Interface>::IID
+                    #agile_query {
+                        #inspectable_query
                 } #(#queries)* else {
                     ::core::ptr::null_mut()
                 };
 
                 if !(*interface).is_null() {
-                    self.count.add_ref();
-                    return ::windows::core::HRESULT(0);
+                    // The reference is added through the interface's own vtable, so that interfaces other than the
+                    // identity are counted by the outer object, if any, and those of static objects aren't counted.
+                    (<#krate::IUnknown as #krate::Interface>::vtable(&*(interface as *const #krate::IUnknown)).AddRef)(*interface);
+                    return #krate::HRESULT(0);
                 }
 
-                *interface = self.count.query(iid, &self.identity as *const _ as *mut _);
+                #(#tear_off_queries)*
+
+                #weak_query
 
                 if (*interface).is_null() {
-                    ::windows::core::HRESULT(-2147467262) // E_NOINTERFACE
+                    #marshaler_query
+                    #base_query
+                    #krate::HRESULT(-2147467262) // E_NOINTERFACE
                 } else {
-                    ::windows::core::HRESULT(0)
+                    #krate::HRESULT(0)
                 }
             }
             fn AddRef(&self) -> u32 {
@@ -170,12 +448,23 @@ pub fn implement(attributes: proc_macro::TokenStream, original_type: proc_macro:
                 }
                 remaining
             }
-            unsafe fn GetTrustLevel(&self, value: *mut i32) -> ::windows::core::HRESULT {
+            unsafe fn GetTrustLevel(&self, value: *mut i32) -> #krate::HRESULT {
                 if value.is_null() {
-                    return ::windows::core::HRESULT(-2147467261); // E_POINTER
+                    return #krate::HRESULT(-2147467261); // E_POINTER
                 }
                 *value = #trust_level;
-                ::windows::core::HRESULT(0)
+                #krate::HRESULT(0)
+            }
+            #outer_impl
+            unsafe fn GetIids(&self, count: *mut u32, values: *mut *mut #krate::GUID) -> #krate::HRESULT {
+                if count.is_null() || values.is_null() {
+                    return #krate::HRESULT(-2147467261); // E_POINTER
+                }
+                let iids: &[#krate::GUID] = &[#(#iids,)*];
+                let (data, len) = #krate::Array::<#krate::GUID>::from_slice(iids).into_abi();
+                *values = data;
+                *count = len;
+                #krate::HRESULT(0)
             }
          }
         impl #generics #original_ident::#generics where #constraints {
@@ -185,13 +474,22 @@ pub fn implement(attributes: proc_macro::TokenStream, original_type: proc_macro:
             ///
             /// This function can only be safely called if `self` has been heap allocated and pinned using
             /// the mechanisms provided by `implement` macro.
-            unsafe fn cast<I: ::windows::core::Interface>(&self) -> ::windows::core::Result<I> {
-                let boxed = (self as *const _ as *const *mut ::core::ffi::c_void).sub(1 + #interfaces_len) as *mut #impl_ident::#generics;
+            unsafe fn cast<I: #krate::Interface>(&self) -> #krate::Result<I> {
+                let boxed = (self as *const _ as *const *mut ::core::ffi::c_void).sub(1 + #vtables_len) as *mut #impl_ident::#generics;
                 let mut result = None;
-                <#impl_ident::#generics as ::windows::core::IUnknownImpl>::QueryInterface(&*boxed, &I::IID, &mut result as *mut _ as _).and_some(result)
+                <#impl_ident::#generics as #krate::IUnknownImpl>::QueryInterface(&*boxed, &I::IID, &mut result as *mut _ as _).and_some(result)
+            }
+            /// Creates the object for storage in a `static`, which is never freed and doesn't count references.
+            #original_vis const fn into_static(self) -> #krate::StaticComObject<Self> {
+                unsafe { #krate::StaticComObject::from_outer(#impl_ident::#generics::new_static(self)) }
             }
+            #outer_methods
+            #base_methods
+        }
+        impl #generics #krate::ComObjectInner for #original_ident::#generics where #constraints {
+            type Outer = #impl_ident::#generics;
         }
-        impl #generics ::core::convert::From<#original_ident::#generics> for ::windows::core::IUnknown where #constraints {
+        impl #generics ::core::convert::From<#original_ident::#generics> for #krate::IUnknown where #constraints {
             fn from(this: #original_ident::#generics) -> Self {
                 let this = #impl_ident::#generics::new(this);
                 let boxed = ::core::mem::ManuallyDrop::new(::std::boxed::Box::new(this));
@@ -200,7 +498,7 @@ pub fn implement(attributes: proc_macro::TokenStream, original_type: proc_macro:
                 }
             }
         }
-        impl #generics ::core::convert::From<#original_ident::#generics> for ::windows::core::IInspectable where #constraints {
+        impl #generics ::core::convert::From<#original_ident::#generics> for #krate::IInspectable where #constraints {
             fn from(this: #original_ident::#generics) -> Self {
                 let this = #impl_ident::#generics::new(this);
                 let boxed = ::core::mem::ManuallyDrop::new(::std::boxed::Box::new(this));
@@ -210,6 +508,7 @@ pub fn implement(attributes: proc_macro::TokenStream, original_type: proc_macro:
             }
         }
         #(#conversions)*
+        #activatable
     };
 
     let mut tokens: proc_macro::TokenStream = tokens.into();
@@ -217,6 +516,33 @@ pub fn implement(attributes: proc_macro::TokenStream, original_type: proc_macro:
     tokens
 }
 
+/// Returns the path to the `windows-core` API: either the `crate = path` argument, the `core` module of the `windows`
+/// crate, or the `windows-core` crate itself, under whatever name the calling crate depends on them.
+fn crate_path(path: Option<&proc_macro2::TokenStream>) -> proc_macro2::TokenStream {
+    use proc_macro_crate::{crate_name, FoundCrate};
+
+    if let Some(path) = path {
+        return path.clone();
+    }
+
+    match crate_name("windows") {
+        Ok(FoundCrate::Name(name)) => {
+            let name = quote::format_ident!("{}", name);
+            quote! { ::#name::core }
+        }
+        // Both crates declare `extern crate self` so that they may refer to themselves by name.
+        Ok(FoundCrate::Itself) => quote! { ::windows::core },
+        Err(_) => match crate_name("windows-core") {
+            Ok(FoundCrate::Name(name)) => {
+                let name = quote::format_ident!("{}", name);
+                quote! { ::#name }
+            }
+            Ok(FoundCrate::Itself) => quote! { ::windows_core },
+            Err(_) => quote! { ::windows::core },
+        },
+    }
+}
+
 #[derive(Default)]
 struct ImplementType {
     type_name: String,
@@ -229,10 +555,10 @@ impl ImplementType {
         let generics = self.generics.iter().map(|g| g.to_ident());
         quote! { #type_name<#(#generics,)*> }
     }
-    fn to_vtbl_ident(&self) -> proc_macro2::TokenStream {
+    fn to_vtbl_ident(&self, krate: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
         let ident = self.to_ident();
         quote! {
-            <#ident as ::windows::core::Interface>::Vtable
+            <#ident as #krate::Interface>::Vtable
         }
     }
 }
@@ -240,7 +566,21 @@ impl ImplementType {
 #[derive(Default)]
 struct ImplementAttributes {
     pub implement: Vec<ImplementType>,
+    pub tear_offs: Vec<ImplementType>,
     pub trust_level: usize,
+    pub outer: bool,
+    pub composable: bool,
+    pub factory: bool,
+    pub agility: Agility,
+    pub krate: Option<proc_macro2::TokenStream>,
+}
+
+#[derive(Clone, Copy, Default, PartialEq)]
+enum Agility {
+    #[default]
+    Agile,
+    NotAgile,
+    FreeThreaded,
 }
 
 impl syn::parse::Parse for ImplementAttributes {
@@ -277,6 +617,9 @@ impl ImplementAttributes {
                 namespace.push_str(&input.ident.to_string());
                 self.walk_implement(&input.tree, namespace)?;
             }
+            UseTree2::Name(input) if namespace.is_empty() && input.generics.is_empty() && input.ident == "outer" => self.outer = true,
+            UseTree2::Name(input) if namespace.is_empty() && input.generics.is_empty() && input.ident == "composable" => self.composable = true,
+            UseTree2::Name(input) if namespace.is_empty() && input.generics.is_empty() && input.ident == "factory" => self.factory = true,
             UseTree2::Name(_) => {
                 self.implement.push(tree.to_element_type(namespace)?);
             }
@@ -285,7 +628,20 @@ impl ImplementAttributes {
                     self.walk_implement(tree, namespace)?;
                 }
             }
-            UseTree2::TrustLevel(input) => self.trust_level = *input,
+            UseTree2::TrustLevel(_, input) => self.trust_level = *input,
+            UseTree2::Agility(_, input) => self.agility = *input,
+            UseTree2::Crate(_, input) => self.krate = Some(input.clone()),
+            UseTree2::TearOff(_, input) => {
+                for tree in input {
+                    if let UseTree2::Name(input) = tree {
+                        if input.generics.is_empty() && (input.ident == "outer" || input.ident == "composable" || input.ident == "factory") {
+                            return Err(syn::parse::Error::new_spanned(&input.ident, format!("`{}` can't be a tear-off", input.ident)));
+                        }
+                    }
+
+                    self.tear_offs.push(tree.to_element_type(&mut String::new())?);
+                }
+            }
         }
 
         Ok(())
@@ -296,7 +652,10 @@ enum UseTree2 {
     Path(UsePath2),
     Name(UseName2),
     Group(UseGroup2),
-    TrustLevel(usize),
+    TrustLevel(syn::Ident, usize),
+    Agility(syn::Ident, Agility),
+    Crate(syn::Token![crate], proc_macro2::TokenStream),
+    TearOff(syn::Ident, Vec<UseTree2>),
 }
 
 impl UseTree2 {
@@ -326,7 +685,8 @@ impl UseTree2 {
                 Ok(ImplementType { type_name, generics })
             }
             UseTree2::Group(input) => Err(syn::parse::Error::new(input.brace_token.span.join(), "Syntax not supported")),
-            _ => unimplemented!(),
+            UseTree2::TrustLevel(ident, _) | UseTree2::Agility(ident, _) | UseTree2::TearOff(ident, _) => Err(syn::parse::Error::new_spanned(ident, format!("`{ident}` is not a type"))),
+            UseTree2::Crate(token, _) => Err(syn::parse::Error::new_spanned(token, "`crate` is not a type")),
         }
     }
 }
@@ -348,24 +708,37 @@ struct UseGroup2 {
 
 impl syn::parse::Parse for UseTree2 {
     fn parse(input: syn::parse::ParseStream<'_>) -> syn::parse::Result<UseTree2> {
+        if input.peek(syn::Token![crate]) && input.peek2(syn::Token![=]) {
+            let token = input.parse::<syn::Token![crate]>()?;
+            input.parse::<syn::Token![=]>()?;
+            return Ok(UseTree2::Crate(token, input.parse::<syn::Path>()?.to_token_stream()));
+        }
+
         let lookahead = input.lookahead1();
         if lookahead.peek(syn::Ident) {
             use syn::ext::IdentExt;
             let ident = input.call(syn::Ident::parse_any)?;
-            if input.peek(syn::Token![::]) {
+            if ident == "TearOff" && input.peek(syn::token::Paren) {
+                let content;
+                syn::parenthesized!(content in input);
+                let items = content.parse_terminated(UseTree2::parse, syn::Token![,])?;
+                Ok(UseTree2::TearOff(ident, items.into_iter().collect()))
+            } else if input.peek(syn::Token![::]) {
                 input.parse::<syn::Token![::]>()?;
                 Ok(UseTree2::Path(UsePath2 { ident, tree: Box::new(input.parse()?) }))
             } else if input.peek(syn::Token![=]) {
-                if ident != "TrustLevel" {
-                    return Err(syn::parse::Error::new(ident.span(), "Unrecognized key-value pair"));
-                }
                 input.parse::<syn::Token![=]>()?;
                 let span = input.span();
                 let value = input.call(syn::Ident::parse_any)?;
-                match value.to_string().as_str() {
-                    "Partial" => Ok(UseTree2::TrustLevel(1)),
-                    "Full" => Ok(UseTree2::TrustLevel(2)),
-                    _ => Err(syn::parse::Error::new(span, "`TrustLevel` must be `Partial` or `Full`")),
+                match (ident.to_string().as_str(), value.to_string().as_str()) {
+                    ("TrustLevel", "Partial") => Ok(UseTree2::TrustLevel(ident, 1)),
+                    ("TrustLevel", "Full") => Ok(UseTree2::TrustLevel(ident, 2)),
+                    ("TrustLevel", _) => Err(syn::parse::Error::new(span, "`TrustLevel` must be `Partial` or `Full`")),
+                    ("Agile", "true") => Ok(UseTree2::Agility(ident, Agility::Agile)),
+                    ("Agile", "false") => Ok(UseTree2::Agility(ident, Agility::NotAgile)),
+                    ("Agile", "FreeThreaded") => Ok(UseTree2::Agility(ident, Agility::FreeThreaded)),
+                    ("Agile", _) => Err(syn::parse::Error::new(span, "`Agile` must be `true`, `false`, or `FreeThreaded`")),
+                    _ => Err(syn::parse::Error::new(ident.span(), "Unrecognized key-value pair")),
                 }
             } else {
                 let generics = if input.peek(syn::Token![<]) {
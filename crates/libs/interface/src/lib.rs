@@ -2,7 +2,7 @@
 Learn more about Rust for Windows here: <https://github.com/microsoft/windows-rs>
 */
 
-use quote::quote;
+use quote::{quote, ToTokens};
 use syn::spanned::Spanned;
 
 /// Defines a COM interface to call or implement.
@@ -29,11 +29,25 @@ use syn::spanned::Spanned;
 ///     // Call interface methods...
 /// }
 /// ```
+///
+/// An interface may derive from `IUnknown`, `IInspectable`, or another interface defined with this macro, including
+/// one in another module or crate. The methods of the parent interface may be called on the derived interface, which
+/// also converts to its parent with `From`, and implementations must implement the parent's `_Impl` trait as well.
+///
+/// The methods generated for callers accept anything that converts to the interface for parameters declared as
+/// `Option<&I>`, and return a `Result<T>` for methods that return `HRESULT` and whose last parameter is marked
+/// `#[retval]`, as in `fn GetValue(&self, #[retval] value: *mut i32) -> HRESULT`. The `_Impl` trait keeps the
+/// declared signature.
+///
+/// The generated code refers to the `windows` crate's `core` module, or to the `windows-core` crate for crates that
+/// depend on it alone. Use `crate = path`, as in `#[interface("094d70d6-5202-44b8-abb8-43860da5aca2", crate = ::windows_core)]`,
+/// to provide the path to the `windows-core` API explicitly.
 #[proc_macro_attribute]
 pub fn interface(attributes: proc_macro::TokenStream, original_type: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let guid = syn::parse_macro_input!(attributes as Guid);
+    let attributes = syn::parse_macro_input!(attributes as Attributes);
     let interface = syn::parse_macro_input!(original_type as Interface);
-    let tokens = match interface.gen_tokens(&guid) {
+    let krate = crate_path(attributes.krate.as_ref());
+    let tokens = match interface.gen_tokens(&attributes.guid, &krate) {
         Ok(t) => t,
         Err(e) => return e.to_compile_error().into(),
     };
@@ -81,28 +95,28 @@ struct Interface {
 
 impl Interface {
     /// Generates all the code needed for a COM interface
-    fn gen_tokens(&self, guid: &Guid) -> syn::Result<proc_macro2::TokenStream> {
+    fn gen_tokens(&self, guid: &Guid, krate: &proc_macro2::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
         let vis = &self.visibility;
         let name = &self.name;
         let docs = &self.docs;
         let parent = self.parent_type();
         let vtable_name = quote::format_ident!("{}_Vtbl", name);
-        let guid = guid.to_tokens()?;
-        let implementation = self.gen_implementation();
+        let guid = guid.to_tokens(krate)?;
+        let implementation = self.gen_implementation(krate);
         let com_trait = self.get_com_trait();
-        let vtable = self.gen_vtable(&vtable_name);
-        let conversions = self.gen_conversions();
+        let vtable = self.gen_vtable(&vtable_name, krate);
+        let conversions = self.gen_conversions(krate);
 
         Ok(quote! {
             #[repr(transparent)]
             #(#docs)*
             #vis struct #name(#parent);
             #implementation
-            unsafe impl ::windows::core::Interface for #name {
+            unsafe impl #krate::Interface for #name {
                 type Vtable = #vtable_name;
-                const IID: ::windows::core::GUID = #guid;
+                const IID: #krate::GUID = #guid;
             }
-            impl ::windows::core::RuntimeName for #name {}
+            impl #krate::RuntimeName for #name {}
 
             #com_trait
             #vtable
@@ -111,7 +125,10 @@ impl Interface {
     }
 
     /// Generates the methods users can call on the COM interface pointer
-    fn gen_implementation(&self) -> proc_macro2::TokenStream {
+    ///
+    /// Interface parameters, declared as `Option<&I>`, accept anything that converts to `I` and a `#[retval]` parameter,
+    /// declared as `*mut T`, is returned as a `Result<T>` instead.
+    fn gen_implementation(&self, krate: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
         let name = &self.name;
         let methods = self
             .methods
@@ -119,20 +136,47 @@ impl Interface {
             .map(|m| {
                 let vis = &m.visibility;
                 let name = &m.name;
+                let mut generics = Vec::new();
+                let mut args = Vec::new();
+                let mut params = Vec::new();
+                let mut ret = m.ret.to_token_stream();
+                let mut retval = None;
+
+                for arg in &m.args {
+                    let pat = &arg.pat;
+                    let ty = &arg.ty;
+
+                    if arg.retval {
+                        let ty = arg.retval_type().expect("checked when parsing");
+                        ret = quote! { -> #krate::Result<#ty> };
+                        params.push(quote! { result__.as_mut_ptr() });
+                        retval = Some(ty);
+                    } else if let Some(interface) = arg.interface_type() {
+                        let param = quote::format_ident!("P{}", generics.len());
+                        generics.push(quote! { #param: #krate::IntoParam<#interface> });
+                        args.push(quote! { #pat: #param });
+                        params.push(quote! { #krate::IntoParam::into_param(#pat).abi() });
+                    } else {
+                        args.push(quote! { #pat: #ty });
+                        params.push(quote! { #pat });
+                    }
+                }
+
+                let call = quote! { (#krate::Interface::vtable(self).#name)(#krate::Interface::as_raw(self), #(#params),*) };
+
+                // The value is only read if the method succeeds, since it may not have been written otherwise.
+                let body = if let Some(ty) = retval {
+                    quote! {
+                        let mut result__ = ::core::mem::MaybeUninit::<#ty>::uninit();
+                        #call.and_then(|| result__.assume_init())
+                    }
+                } else {
+                    call
+                };
 
-                let args = m.gen_args();
-                let params = &m
-                    .args
-                    .iter()
-                    .map(|a| {
-                        let pat = &a.pat;
-                        quote! { #pat }
-                    })
-                    .collect::<Vec<_>>();
-                let ret = &m.ret;
                 quote! {
-                    #vis unsafe fn #name(&self, #(#args),*) #ret {
-                        (::windows::core::Interface::vtable(self).#name)(::windows::core::Interface::as_raw(self), #(#params),*)
+                    #vis unsafe fn #name<#(#generics),*>(&self, #(#args),*) #ret {
+                        #body
                     }
                 }
             })
@@ -172,7 +216,7 @@ impl Interface {
     }
 
     /// Generates the vtable for a COM interface
-    fn gen_vtable(&self, vtable_name: &syn::Ident) -> proc_macro2::TokenStream {
+    fn gen_vtable(&self, vtable_name: &syn::Ident, krate: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
         let vis = &self.visibility;
         let name = &self.name;
         let trait_name = quote::format_ident!("{}_Impl", name);
@@ -184,34 +228,39 @@ impl Interface {
             .map(|m| {
                 let name = &m.name;
                 let ret = &m.ret;
-                let args = m.gen_args();
+                let args = m.gen_abi_args();
                 quote! {
                     pub #name: unsafe extern "system" fn(this: *mut ::core::ffi::c_void, #(#args),*) #ret,
                 }
             })
             .collect::<Vec<_>>();
 
-        let parent_vtable_generics = if self.parent_is_iunknown() { quote!(Identity, OFFSET) } else { quote!(Identity, Impl, OFFSET) };
         let parent_vtable = self.parent_vtable();
+        let parent_vtable_generics = if self.parent_is_iunknown() {
+            quote!(Identity, OFFSET)
+        } else if self.parent_is_iinspectable() {
+            quote!(Identity, #name, OFFSET)
+        } else {
+            quote!(Identity, Impl, OFFSET)
+        };
+
+        // An interface also matches its ancestors, other than `IUnknown` and `IInspectable` which every implementation matches.
+        let parent_matches = match &parent_vtable {
+            Some(parent_vtable) if !self.parent_is_iunknown() && !self.parent_is_iinspectable() => quote! { || #parent_vtable::matches(iid) },
+            _ => quote! {},
+        };
 
         let functions = self
             .methods
             .iter()
             .map(|m| {
                 let name = &m.name;
-                let args = m.gen_args();
-                let params = &m
-                    .args
-                    .iter()
-                    .map(|a| {
-                        let pat = &a.pat;
-                        quote! { #pat }
-                    })
-                    .collect::<Vec<_>>();
+                let args = m.gen_abi_args();
+                let params = m.gen_abi_params(krate);
                 let ret = &m.ret;
                 if parent_vtable.is_some() {
                     quote! {
-                        unsafe extern "system" fn #name<Identity: ::windows::core::IUnknownImpl<Impl = Impl>, Impl: #trait_name, const OFFSET: isize>(this: *mut ::core::ffi::c_void, #(#args),*) #ret {
+                        unsafe extern "system" fn #name<Identity: #krate::IUnknownImpl<Impl = Impl>, Impl: #trait_name, const OFFSET: isize>(this: *mut ::core::ffi::c_void, #(#args),*) #ret {
                             let this = (this as *const *const ()).offset(OFFSET) as *const Identity;
                             let this = (*this).get_impl();
                             this.#name(#(#params),*).into()
@@ -220,7 +269,7 @@ impl Interface {
                 } else {
                     quote! {
                         unsafe extern "system" fn #name<Impl: #trait_name>(this: *mut ::core::ffi::c_void, #(#args),*) #ret {
-                            let this = (this as *mut *mut ::core::ffi::c_void) as *const ::windows::core::ScopedHeap;
+                            let this = (this as *mut *mut ::core::ffi::c_void) as *const #krate::ScopedHeap;
                             let this = (*this).this as *const Impl;
                             (*this).#name(#(#params),*).into()
                         }
@@ -255,13 +304,13 @@ impl Interface {
                     #(#vtable_entries)*
                 }
                 impl #vtable_name {
-                    pub const fn new<Identity: ::windows::core::IUnknownImpl<Impl = Impl>, Impl: #trait_name, const OFFSET: isize>() -> Self {
+                    pub const fn new<Identity: #krate::IUnknownImpl<Impl = Impl>, Impl: #trait_name, const OFFSET: isize>() -> Self {
                         #(#functions)*
                         Self { base__: #parent_vtable::new::<#parent_vtable_generics>(), #(#entries),* }
                     }
 
-                    pub fn matches(iid: &windows::core::GUID) -> bool {
-                        iid == &<#name as ::windows::core::Interface>::IID
+                    pub fn matches(iid: &#krate::GUID) -> bool {
+                        iid == &<#name as #krate::Interface>::IID #parent_matches
                     }
                 }
             }
@@ -283,10 +332,10 @@ impl Interface {
                     const VTABLE: #vtable_name = #vtable_name::new::<T>();
                 }
                 impl #name {
-                    fn new<'a, T: #trait_name>(this: &'a T) -> ::windows::core::ScopedInterface<'a, #name> {
-                        let this = ::windows::core::ScopedHeap { vtable: &#implvtbl_name::<T>::VTABLE as *const _ as *const _, this: this as *const _ as *const _ };
+                    fn new<'a, T: #trait_name>(this: &'a T) -> #krate::ScopedInterface<'a, #name> {
+                        let this = #krate::ScopedHeap { vtable: &#implvtbl_name::<T>::VTABLE as *const _ as *const _, this: this as *const _ as *const _ };
                         let this = ::std::mem::ManuallyDrop::new(::std::boxed::Box::new(this));
-                        unsafe { ::windows::core::ScopedInterface::new(::std::mem::transmute(&this.vtable)) }
+                        unsafe { #krate::ScopedInterface::new(::std::mem::transmute(&this.vtable)) }
                     }
                 }
             }
@@ -294,16 +343,43 @@ impl Interface {
     }
 
     /// Generates various conversions such as from and to `IUnknown`
-    fn gen_conversions(&self) -> proc_macro2::TokenStream {
+    fn gen_conversions(&self, krate: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
         let name = &self.name;
         let name_string = format!("{name}");
+
+        // Interfaces may be converted to, passed as, and call the methods of their parent.
+        let parent = match &self.parent {
+            Some(parent) if !self.parent_is_iunknown() => quote! {
+                impl ::core::convert::From<#name> for #parent {
+                    fn from(value: #name) -> Self {
+                        unsafe { ::core::mem::transmute(value) }
+                    }
+                }
+                impl ::core::convert::From<&#name> for #parent {
+                    fn from(value: &#name) -> Self {
+                        ::core::convert::From::from(::core::clone::Clone::clone(value))
+                    }
+                }
+                impl #krate::CanInto<#parent> for #name {}
+                impl ::core::ops::Deref for #name {
+                    type Target = #parent;
+                    fn deref(&self) -> &Self::Target {
+                        unsafe { ::core::mem::transmute(self) }
+                    }
+                }
+            },
+            _ => quote! {},
+        };
+
         quote! {
-            impl ::core::convert::From<#name> for ::windows::core::IUnknown {
+            #parent
+            impl #krate::CanInto<#krate::IUnknown> for #name {}
+            impl ::core::convert::From<#name> for #krate::IUnknown {
                 fn from(value: #name) -> Self {
                     unsafe { ::core::mem::transmute(value) }
                 }
             }
-            impl ::core::convert::From<&#name> for ::windows::core::IUnknown {
+            impl ::core::convert::From<&#name> for #krate::IUnknown {
                 fn from(value: &#name) -> Self {
                     ::core::convert::From::from(::core::clone::Clone::clone(value))
                 }
@@ -336,12 +412,18 @@ impl Interface {
     }
 
     fn parent_vtable(&self) -> Option<proc_macro2::TokenStream> {
-        if let Some(i) = self.parent_ident() {
-            let i = quote::format_ident!("{}_Vtbl", i);
-            Some(quote!(#i))
-        } else {
-            None
-        }
+        self.parent_with_suffix("Vtbl")
+    }
+
+    /// Returns the path to the parent interface with the given suffix appended to its name, preserving the rest of
+    /// the path so that parents may be defined in other modules or crates.
+    fn parent_with_suffix(&self, suffix: &str) -> Option<proc_macro2::TokenStream> {
+        let parent = self.parent.as_ref()?;
+        let leading_colon = &parent.leading_colon;
+        let segments = parent.segments.iter().collect::<Vec<_>>();
+        let (last, rest) = segments.split_last().expect("segments should never be empty");
+        let ident = quote::format_ident!("{}_{}", last.ident, suffix);
+        Some(quote!(#leading_colon #(#rest::)* #ident))
     }
 
     fn parent_is_iunknown(&self) -> bool {
@@ -352,6 +434,14 @@ impl Interface {
         }
     }
 
+    fn parent_is_iinspectable(&self) -> bool {
+        if let Some(ident) = self.parent_ident() {
+            ident == "IInspectable"
+        } else {
+            false
+        }
+    }
+
     fn parent_ident(&self) -> Option<&syn::Ident> {
         if let Some(parent) = &self.parent {
             Some(&parent.segments.last().as_ref().expect("segements should never be empty").ident)
@@ -360,16 +450,12 @@ impl Interface {
         }
     }
 
-    /// Gets the parent trait constrait which is nothing if the parent is IUnknown
+    /// Gets the parent trait constrait which is nothing if the parent is IUnknown or IInspectable
     fn parent_trait_constraint(&self) -> proc_macro2::TokenStream {
-        if let Some(i) = self.parent_ident() {
-            if i == "IUnknown" {
-                return quote!();
-            }
-            let i = quote::format_ident!("{}_Impl", i);
-            quote!(#i)
-        } else {
+        if self.parent_is_iunknown() || self.parent_is_iinspectable() {
             quote!()
+        } else {
+            self.parent_with_suffix("Impl").unwrap_or_default()
         }
     }
 }
@@ -415,7 +501,7 @@ impl syn::parse::Parse for Interface {
 struct Guid(Option<syn::LitStr>);
 
 impl Guid {
-    fn to_tokens(&self) -> syn::Result<proc_macro2::TokenStream> {
+    fn to_tokens(&self, krate: &proc_macro2::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
         fn hex_lit(num: &str) -> syn::LitInt {
             syn::LitInt::new(&format!("0x{num}"), proc_macro2::Span::call_site())
         }
@@ -460,7 +546,7 @@ impl Guid {
             let data4_7 = hex_lit(data4_7);
             let data4_8 = hex_lit(data4_8);
             Ok(quote! {
-                ::windows::core::GUID {
+                #krate::GUID {
                     data1: #data1,
                     data2: #data2,
                     data3: #data3,
@@ -469,7 +555,7 @@ impl Guid {
             })
         } else {
             Ok(quote! {
-                ::windows::core::GUID::zeroed()
+                #krate::GUID::zeroed()
             })
         }
     }
@@ -483,6 +569,71 @@ impl syn::parse::Parse for Guid {
     }
 }
 
+/// Parsed interface attributes, being the optional guid followed by an optional crate path
+///
+/// ```rust,ignore
+/// #[windows_interface::interface("8CEEB155-2849-4ce5-9448-91FF70E1E4D9", crate = ::windows_core)]
+///                              //^ parses this
+/// unsafe trait IUIAnimationVariable: IUnknown {
+///     fn GetValue(&self, value: *mut f64) -> HRESULT;
+/// }
+/// ```
+struct Attributes {
+    guid: Guid,
+    krate: Option<syn::Path>,
+}
+
+impl syn::parse::Parse for Attributes {
+    fn parse(cursor: syn::parse::ParseStream<'_>) -> syn::Result<Self> {
+        let guid = cursor.parse::<Guid>()?;
+
+        if guid.0.is_some() && !cursor.is_empty() {
+            cursor.parse::<syn::Token![,]>()?;
+        }
+
+        let krate = if cursor.is_empty() {
+            None
+        } else {
+            cursor.parse::<syn::Token![crate]>()?;
+            cursor.parse::<syn::Token![=]>()?;
+            Some(cursor.parse::<syn::Path>()?)
+        };
+
+        if !cursor.is_empty() {
+            return Err(cursor.error("unexpected token"));
+        }
+
+        Ok(Self { guid, krate })
+    }
+}
+
+/// Returns the path to the `windows-core` API: either the `crate = path` argument, the `core` module of the `windows`
+/// crate, or the `windows-core` crate itself, under whatever name the calling crate depends on them.
+fn crate_path(path: Option<&syn::Path>) -> proc_macro2::TokenStream {
+    use proc_macro_crate::{crate_name, FoundCrate};
+
+    if let Some(path) = path {
+        return quote! { #path };
+    }
+
+    match crate_name("windows") {
+        Ok(FoundCrate::Name(name)) => {
+            let name = quote::format_ident!("{}", name);
+            quote! { ::#name::core }
+        }
+        // Both crates declare `extern crate self` so that they may refer to themselves by name.
+        Ok(FoundCrate::Itself) => quote! { ::windows::core },
+        Err(_) => match crate_name("windows-core") {
+            Ok(FoundCrate::Name(name)) => {
+                let name = quote::format_ident!("{}", name);
+                quote! { ::#name }
+            }
+            Ok(FoundCrate::Itself) => quote! { ::windows_core },
+            Err(_) => quote! { ::windows::core },
+        },
+    }
+}
+
 /// A parsed interface method
 ///
 /// ```rust,ignore
@@ -512,6 +663,37 @@ impl InterfaceMethod {
             })
             .collect::<Vec<_>>()
     }
+
+    /// Generates the arguments as they are passed through the vtable, where interfaces are raw pointers
+    fn gen_abi_args(&self) -> Vec<proc_macro2::TokenStream> {
+        self.args
+            .iter()
+            .map(|a| {
+                let pat = &a.pat;
+                let ty = &a.ty;
+                if a.interface_type().is_some() {
+                    quote! { #pat: *mut ::core::ffi::c_void }
+                } else {
+                    quote! { #pat: #ty }
+                }
+            })
+            .collect::<Vec<_>>()
+    }
+
+    /// Generates the parameters passed from the vtable to the implementation
+    fn gen_abi_params(&self, krate: &proc_macro2::TokenStream) -> Vec<proc_macro2::TokenStream> {
+        self.args
+            .iter()
+            .map(|a| {
+                let pat = &a.pat;
+                if let Some(interface) = a.interface_type() {
+                    quote! { <#interface as #krate::Interface>::from_raw_borrowed(&#pat) }
+                } else {
+                    quote! { #pat }
+                }
+            })
+            .collect::<Vec<_>>()
+    }
 }
 
 impl syn::parse::Parse for InterfaceMethod {
@@ -535,9 +717,36 @@ impl syn::parse::Parse for InterfaceMethod {
                 syn::FnArg::Receiver(_) => None,
                 syn::FnArg::Typed(p) => Some(p),
             })
-            .map(|p| Ok(InterfaceMethodArg { ty: p.ty, pat: p.pat }))
+            .map(|p| {
+                let mut retval = false;
+                for attr in &p.attrs {
+                    if attr.path().is_ident("retval") {
+                        retval = true;
+                    } else {
+                        bail!(attr, "unexpected attribute");
+                    }
+                }
+                Ok(InterfaceMethodArg { ty: p.ty, pat: p.pat, retval })
+            })
             .collect::<Result<Vec<InterfaceMethodArg>, syn::Error>>()?;
 
+        if let Some((last, rest)) = args.split_last() {
+            if let Some(arg) = rest.iter().find(|arg| arg.retval) {
+                bail!(arg.pat, "only the last parameter may be a `#[retval]` parameter");
+            }
+            if last.retval {
+                if last.retval_type().is_none() {
+                    bail!(last.ty, "a `#[retval]` parameter must be a `*mut` pointer");
+                }
+                match &sig.output {
+                    syn::ReturnType::Type(_, ty) if matches!(&**ty, syn::Type::Path(path) if path.path.is_ident("HRESULT") || path.path.segments.last().map_or(false, |last| last.ident == "HRESULT")) => {}
+                    _ => {
+                        bail!(last.pat, "a method with a `#[retval]` parameter must return `HRESULT`");
+                    }
+                }
+            }
+        }
+
         let ret = sig.output;
         Ok(InterfaceMethod { name: sig.ident, visibility, args, ret, docs })
     }
@@ -549,4 +758,37 @@ struct InterfaceMethodArg {
     pub ty: Box<syn::Type>,
     /// The name of the argument
     pub pat: Box<syn::Pat>,
+    /// Whether the argument is returned by the method, as indicated by the `#[retval]` attribute
+    pub retval: bool,
+}
+
+impl InterfaceMethodArg {
+    /// The type pointed to by a `*mut T` argument
+    fn retval_type(&self) -> Option<&syn::Type> {
+        match &*self.ty {
+            syn::Type::Ptr(ptr) if ptr.mutability.is_some() => Some(&ptr.elem),
+            _ => None,
+        }
+    }
+
+    /// The interface type of an `Option<&I>` argument, which is passed through the vtable as a raw pointer
+    fn interface_type(&self) -> Option<&syn::Type> {
+        let syn::Pat::Ident(_) = &*self.pat else {
+            return None;
+        };
+        let syn::Type::Path(path) = &*self.ty else {
+            return None;
+        };
+        let last = path.path.segments.last()?;
+        if last.ident != "Option" {
+            return None;
+        }
+        let syn::PathArguments::AngleBracketed(generics) = &last.arguments else {
+            return None;
+        };
+        match generics.args.first()? {
+            syn::GenericArgument::Type(syn::Type::Reference(reference)) if generics.args.len() == 1 && reference.mutability.is_none() => Some(&reference.elem),
+            _ => None,
+        }
+    }
 }
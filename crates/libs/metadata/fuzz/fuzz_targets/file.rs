@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Parsing arbitrary bytes must fail with an error rather than panic or read out of bounds.
+fuzz_target!(|data: &[u8]| {
+    _ = windows_metadata::File::new(data.to_vec());
+});
@@ -0,0 +1,64 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use windows_metadata::*;
+
+// Once a file has been parsed, every column of every row in its tables must be readable, whether as a value, a string,
+// a blob, a row or a list of rows, even if the column doesn't hold that kind of value.
+fn columns<'a, R: AsRow<'a>>(file: &'a File) {
+    for row in file.table::<R>() {
+        for column in 0..file.tables[R::TABLE].columns.len() {
+            row.usize(column);
+            row.str(column);
+
+            let mut blob = row.blob(column);
+            blob.read_str();
+            blob.read_usize();
+
+            let target: TypeDef = row.row(column);
+            target.name();
+
+            for def in row.list::<TypeDef>(column) {
+                def.name();
+            }
+
+            for field in row.list::<Field>(column) {
+                field.name();
+            }
+
+            for method in row.list::<MethodDef>(column) {
+                method.name();
+            }
+
+            for param in row.list::<Param>(column) {
+                param.name();
+            }
+        }
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(file) = File::new(data.to_vec()) {
+        for (index, table) in file.tables.iter().enumerate() {
+            for row in 0..table.len {
+                for column in 0..table.columns.len() {
+                    file.usize(row, index, column);
+                }
+            }
+        }
+
+        columns::<TypeDef>(&file);
+        columns::<TypeRef>(&file);
+        columns::<Field>(&file);
+        columns::<MethodDef>(&file);
+        columns::<Param>(&file);
+        columns::<Attribute>(&file);
+        columns::<Constant>(&file);
+        columns::<ImplMap>(&file);
+        columns::<ModuleRef>(&file);
+        columns::<NestedClass>(&file);
+        columns::<GenericParam>(&file);
+        columns::<Property>(&file);
+        columns::<Event>(&file);
+    }
+});
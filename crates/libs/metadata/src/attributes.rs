@@ -40,6 +40,12 @@ macro_rules! flags {
     };
 }
 
+flags!(EventAttributes, u16);
+impl EventAttributes {
+    pub const SpecialName: Self = Self(0x200);
+    pub const RTSpecialName: Self = Self(0x400);
+}
+
 flags!(FieldAttributes, u16);
 impl FieldAttributes {
     pub const Private: Self = Self(0x1);
@@ -66,6 +72,16 @@ impl MethodImplAttributes {
     pub const PreserveSig: Self = Self(0x80);
 }
 
+flags!(MethodSemanticsAttributes, u16);
+impl MethodSemanticsAttributes {
+    pub const Setter: Self = Self(0x1);
+    pub const Getter: Self = Self(0x2);
+    pub const Other: Self = Self(0x4);
+    pub const AddOn: Self = Self(0x8);
+    pub const RemoveOn: Self = Self(0x10);
+    pub const Fire: Self = Self(0x20);
+}
+
 // These are not really ECMA-335 attributes but instead the flags found in the method signature.
 flags!(MethodCallAttributes, u8);
 impl MethodCallAttributes {
@@ -90,6 +106,13 @@ impl PInvokeAttributes {
     pub const CallConvFastcall: Self = Self(0x500);
 }
 
+flags!(PropertyAttributes, u16);
+impl PropertyAttributes {
+    pub const SpecialName: Self = Self(0x200);
+    pub const RTSpecialName: Self = Self(0x400);
+    pub const HasDefault: Self = Self(0x1000);
+}
+
 flags!(TypeAttributes, u32);
 impl TypeAttributes {
     pub const Public: Self = Self(0x1);
@@ -1,11 +1,11 @@
 use super::*;
 
-pub struct Blob {
-    pub file: &'static File,
-    pub slice: &'static [u8],
+pub struct Blob<'a> {
+    pub file: &'a File,
+    pub slice: &'a [u8],
 }
 
-impl std::ops::Deref for Blob {
+impl std::ops::Deref for Blob<'_> {
     type Target = [u8];
 
     fn deref(&self) -> &[u8] {
@@ -13,18 +13,22 @@ impl std::ops::Deref for Blob {
     }
 }
 
-impl Blob {
-    pub fn new(file: &'static File, slice: &'static [u8]) -> Self {
+impl<'a> Blob<'a> {
+    pub fn new(file: &'a File, slice: &'a [u8]) -> Self {
         Self { file, slice }
     }
 
+    /// Returns the compressed unsigned integer at the start of the blob and its length. Bytes past the end of the blob
+    /// read as zero.
     pub fn peek_usize(&self) -> (usize, usize) {
-        if self[0] & 0x80 == 0 {
-            (self[0] as usize, 1)
-        } else if self[0] & 0xC0 == 0x80 {
-            ((((self[0] & 0x3F) as usize) << 8) | self[1] as usize, 2)
+        let byte = |index: usize| *self.get(index).unwrap_or(&0) as usize;
+
+        if byte(0) & 0x80 == 0 {
+            (byte(0), 1)
+        } else if byte(0) & 0xC0 == 0x80 {
+            (((byte(0) & 0x3F) << 8) | byte(1), 2)
         } else {
-            ((((self[0] & 0x1F) as usize) << 24) | (self[1] as usize) << 16 | (self[2] as usize) << 8 | self[3] as usize, 4)
+            (((byte(0) & 0x1F) << 24) | byte(1) << 16 | byte(2) << 8 | byte(3), 4)
         }
     }
 
@@ -44,7 +48,7 @@ impl Blob {
         }
     }
 
-    pub fn read_modifiers(&mut self) -> Vec<TypeDefOrRef> {
+    pub fn read_modifiers(&mut self) -> Vec<TypeDefOrRef<'a>> {
         let mut mods = vec![];
         loop {
             let (value, offset) = self.peek_usize();
@@ -58,11 +62,22 @@ impl Blob {
         mods
     }
 
-    pub fn read_str(&mut self) -> &'static str {
+    /// Reads a `SerString`, where a null string reads as an empty string.
+    pub fn read_str(&mut self) -> &'a str {
+        self.read_nullable_str().unwrap_or_default()
+    }
+
+    /// Reads a `SerString`, where a null string, encoded as a single 0xFF byte, reads as `None`.
+    pub fn read_nullable_str(&mut self) -> Option<&'a str> {
+        if self.first() == Some(&0xFF) {
+            self.offset(1);
+            return None;
+        }
+
         let len = self.read_usize();
-        let value = unsafe { std::str::from_utf8_unchecked(&self.slice[..len]) };
-        self.offset(len);
-        value
+        let bytes = self.slice.get(..len).unwrap_or(self.slice);
+        self.offset(bytes.len());
+        Some(std::str::from_utf8(bytes).unwrap_or_default())
     }
 
     pub fn read_string(self) -> String {
@@ -145,7 +160,7 @@ impl Blob {
         value
     }
 
-    pub fn read_integer(&mut self, ty: Type) -> Value {
+    pub fn read_integer(&mut self, ty: Type<'_>) -> Value<'a> {
         match ty {
             Type::I8 => Value::I8(self.read_i8()),
             Type::U8 => Value::U8(self.read_u8()),
@@ -159,7 +174,81 @@ impl Blob {
         }
     }
 
+    /// Reads a `FixedArg` of the given type from a custom attribute blob. See II.23.3 in ECMA-335.
+    pub fn read_fixed_arg(&mut self, ty: &Type<'a>) -> Value<'a> {
+        if let Type::WinrtArray(elem) = ty {
+            // A null array is encoded with a length of 0xFFFFFFFF.
+            let len = self.read_u32();
+
+            if len == u32::MAX {
+                return Value::Null(ty.clone());
+            }
+
+            let mut values = Vec::new();
+
+            for _ in 0..len {
+                match self.read_elem(elem) {
+                    Value::Invalid => return Value::Invalid,
+                    value => values.push(value),
+                }
+            }
+
+            return Value::Array((**elem).clone(), values);
+        }
+
+        self.read_elem(ty)
+    }
+
+    /// Reads a `FieldOrPropType`, which describes the type of a named or boxed argument in a custom attribute blob,
+    /// returning `None` if the type can't be encoded in a custom attribute.
+    pub fn read_field_or_prop_type(&mut self) -> Option<Type<'a>> {
+        match self.read_u8() {
+            ELEMENT_TYPE_SZARRAY => Some(Type::WinrtArray(Box::new(self.read_field_or_prop_type()?))),
+            SERIALIZATION_TYPE_TYPE => Some(Type::Type),
+            SERIALIZATION_TYPE_TAGGED_OBJECT => Some(Type::IInspectable),
+            SERIALIZATION_TYPE_ENUM => {
+                let type_name = TypeName::parse_serialized(self.read_str());
+                Some(self.reader().resolve_type_def(type_name).map_or(Type::TypeRef(type_name), |def| Type::TypeDef(def, Vec::new())))
+            }
+            code => Type::from_code(code as usize),
+        }
+    }
+
+    fn read_elem(&mut self, ty: &Type<'a>) -> Value<'a> {
+        match ty {
+            Type::Bool => Value::Bool(self.read_bool()),
+            Type::Char => Value::Char(self.read_u16()),
+            Type::I8 => Value::I8(self.read_i8()),
+            Type::U8 => Value::U8(self.read_u8()),
+            Type::I16 => Value::I16(self.read_i16()),
+            Type::U16 => Value::U16(self.read_u16()),
+            Type::I32 => Value::I32(self.read_i32()),
+            Type::U32 => Value::U32(self.read_u32()),
+            Type::I64 => Value::I64(self.read_i64()),
+            Type::U64 => Value::U64(self.read_u64()),
+            Type::F32 => Value::F32(self.read_f32()),
+            Type::F64 => Value::F64(self.read_f64()),
+            Type::String => self.read_nullable_str().map_or(Value::Null(Type::String), |value| Value::String(value.to_string())),
+            Type::Type => self.read_nullable_str().map_or(Value::Null(Type::Type), |value| Value::TypeName(TypeName::parse_serialized(value))),
+            Type::IInspectable => match self.read_field_or_prop_type() {
+                Some(ty) => match self.read_fixed_arg(&ty) {
+                    Value::Invalid => Value::Invalid,
+                    value => Value::Object(Box::new(value)),
+                },
+                None => Value::Invalid,
+            },
+            Type::TypeDef(def, _) => Value::EnumDef(*def, Box::new(self.read_integer(def.underlying_type()))),
+            Type::TypeRef(type_name) => Value::EnumRef(*type_name, Box::new(Value::I32(self.read_i32()))),
+            _ => Value::Invalid,
+        }
+    }
+
+    fn reader(&self) -> &'a Reader {
+        // Safety: At this point the File is already pointing to a valid Reader.
+        unsafe { &*self.file.reader }
+    }
+
     fn offset(&mut self, offset: usize) {
-        self.slice = &self.slice[offset..];
+        self.slice = self.slice.get(offset..).unwrap_or_default();
     }
 }
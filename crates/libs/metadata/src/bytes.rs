@@ -0,0 +1,156 @@
+/// The bytes backing a [`File`](crate::File), either owned or memory-mapped from disk.
+pub enum Bytes {
+    Owned(Vec<u8>),
+    Mapped(Mapping),
+}
+
+impl std::ops::Deref for Bytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            Self::Owned(bytes) => bytes,
+            Self::Mapped(mapping) => mapping,
+        }
+    }
+}
+
+impl From<Vec<u8>> for Bytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::Owned(bytes)
+    }
+}
+
+/// A read-only view of a file mapped into memory. The view is unmapped when dropped.
+pub struct Mapping {
+    data: *const u8,
+    len: usize,
+}
+
+// Safety: The mapping is read-only and uniquely owned.
+unsafe impl Send for Mapping {}
+unsafe impl Sync for Mapping {}
+
+impl std::ops::Deref for Mapping {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        // Safety: The view remains mapped until the `Mapping` is dropped.
+        unsafe { std::slice::from_raw_parts(self.data, self.len) }
+    }
+}
+
+impl Bytes {
+    /// Maps the file at `path` into memory, falling back to reading it if the platform doesn't support mapping.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated for as long as the returned bytes are alive.
+    pub unsafe fn map(path: &std::path::Path) -> std::io::Result<Self> {
+        if cfg!(not(any(unix, windows))) {
+            return std::fs::read(path).map(Self::Owned);
+        }
+
+        let file = std::fs::File::open(path)?;
+        let len = usize::try_from(file.metadata()?.len()).map_err(|_| std::io::Error::from(std::io::ErrorKind::OutOfMemory))?;
+
+        // Zero-length views can't be mapped on any platform.
+        if len == 0 {
+            return Ok(Self::Owned(vec![]));
+        }
+
+        Mapping::new(&file, len).map(Self::Mapped)
+    }
+}
+
+#[cfg(unix)]
+impl Mapping {
+    unsafe fn new(file: &std::fs::File, len: usize) -> std::io::Result<Self> {
+        use std::os::unix::io::AsRawFd;
+
+        extern "C" {
+            fn mmap(addr: *mut std::ffi::c_void, len: usize, prot: i32, flags: i32, fd: i32, offset: isize) -> *mut std::ffi::c_void;
+        }
+
+        const PROT_READ: i32 = 1;
+        const MAP_PRIVATE: i32 = 2;
+
+        let data = mmap(std::ptr::null_mut(), len, PROT_READ, MAP_PRIVATE, file.as_raw_fd(), 0);
+
+        // MAP_FAILED
+        if data as isize == -1 {
+            return Err(std::io::Error::last_os_error());
+        }
+
+        Ok(Self { data: data as *const u8, len })
+    }
+}
+
+#[cfg(unix)]
+impl Drop for Mapping {
+    fn drop(&mut self) {
+        extern "C" {
+            fn munmap(addr: *mut std::ffi::c_void, len: usize) -> i32;
+        }
+
+        unsafe {
+            munmap(self.data as *mut _, self.len);
+        }
+    }
+}
+
+#[cfg(windows)]
+impl Mapping {
+    unsafe fn new(file: &std::fs::File, len: usize) -> std::io::Result<Self> {
+        use std::os::windows::io::AsRawHandle;
+
+        #[link(name = "kernel32")]
+        extern "system" {
+            fn CreateFileMappingW(file: *mut std::ffi::c_void, attributes: *const std::ffi::c_void, protect: u32, size_high: u32, size_low: u32, name: *const u16) -> *mut std::ffi::c_void;
+            fn MapViewOfFile(mapping: *mut std::ffi::c_void, access: u32, offset_high: u32, offset_low: u32, len: usize) -> *mut std::ffi::c_void;
+            fn CloseHandle(handle: *mut std::ffi::c_void) -> i32;
+        }
+
+        const PAGE_READONLY: u32 = 2;
+        const FILE_MAP_READ: u32 = 4;
+
+        let mapping = CreateFileMappingW(file.as_raw_handle() as *mut _, std::ptr::null(), PAGE_READONLY, 0, 0, std::ptr::null());
+
+        if mapping.is_null() {
+            return Err(std::io::Error::last_os_error());
+        }
+
+        let data = MapViewOfFile(mapping, FILE_MAP_READ, 0, 0, len);
+        let error = std::io::Error::last_os_error();
+
+        // The view keeps the mapping alive so the handle is no longer needed.
+        CloseHandle(mapping);
+
+        if data.is_null() {
+            return Err(error);
+        }
+
+        Ok(Self { data: data as *const u8, len })
+    }
+}
+
+#[cfg(windows)]
+impl Drop for Mapping {
+    fn drop(&mut self) {
+        #[link(name = "kernel32")]
+        extern "system" {
+            fn UnmapViewOfFile(address: *const std::ffi::c_void) -> i32;
+        }
+
+        unsafe {
+            UnmapViewOfFile(self.data as *const _);
+        }
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+impl Mapping {
+    unsafe fn new(_: &std::fs::File, _: usize) -> std::io::Result<Self> {
+        unreachable!()
+    }
+}
@@ -1,17 +1,17 @@
 use super::*;
 
-pub trait Decode {
-    fn decode(file: &'static File, code: usize) -> Self;
+pub trait Decode<'a> {
+    fn decode(file: &'a File, code: usize) -> Self;
 }
 
 macro_rules! code {
     ($name:ident($size:literal) $(($table:ident, $code:literal))+) => {
         #[derive(Clone, Debug, Hash, PartialEq, Eq, Ord, PartialOrd)]
-        pub enum $name {
-            $($table($table),)*
+        pub enum $name<'a> {
+            $($table($table<'a>),)*
         }
-        impl Decode for $name {
-            fn decode(file: &'static File, code: usize) -> Self {
+        impl<'a> Decode<'a> for $name<'a> {
+            fn decode(file: &'a File, code: usize) -> Self {
                 let (kind, row) = (code & ((1 << $size) - 1), (code >> $size) - 1);
                 match kind {
                     $($code => Self::$table($table(Row::new(file, row))),)*
@@ -19,7 +19,7 @@ macro_rules! code {
                 }
             }
         }
-        impl $name {
+        impl $name<'_> {
             pub fn encode(&self) -> usize {
                 match self {
                     $(Self::$table(row) => (row.index() + 1) << $size | $code,)*
@@ -27,8 +27,8 @@ macro_rules! code {
             }
         }
         $(
-            impl From<$table> for $name {
-                fn from(from: $table) -> Self {
+            impl<'a> From<$table<'a>> for $name<'a> {
+                fn from(from: $table<'a>) -> Self {
                     Self::$table(from)
                 }
             }
@@ -48,12 +48,27 @@ code! { HasAttribute(5)
     (Param, 4)
     (InterfaceImpl, 5)
     (MemberRef, 6)
+    (Property, 9)
+    (Event, 10)
     (TypeSpec, 13)
     (GenericParam, 19)
+    (GenericParamConstraint, 20)
 }
 
 code! { HasConstant(2)
     (Field, 0)
+    (Param, 1)
+    (Property, 2)
+}
+
+code! { HasFieldMarshal(1)
+    (Field, 0)
+    (Param, 1)
+}
+
+code! { HasSemantics(1)
+    (Event, 0)
+    (Property, 1)
 }
 
 code! { MemberForwarded(1)
@@ -81,8 +96,8 @@ code! { ResolutionScope(2)
     (TypeRef, 3)
 }
 
-impl TypeDefOrRef {
-    pub fn type_name(&self) -> TypeName {
+impl<'a> TypeDefOrRef<'a> {
+    pub fn type_name(&self) -> TypeName<'a> {
         match self {
             Self::TypeDef(row) => row.type_name(),
             Self::TypeRef(row) => row.type_name(),
@@ -0,0 +1,52 @@
+/// The reason a winmd file could not be loaded by [`File::new`](crate::File::new) or [`File::map`](crate::File::map).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The file does not start with a DOS header.
+    InvalidDosSignature,
+    /// The DOS header does not point to a PE header.
+    InvalidPeSignature,
+    /// The PE optional header is neither PE32 nor PE32+.
+    InvalidOptionalHeader(u16),
+    /// The file has no CLI header, so it does not contain any metadata.
+    MissingCliHeader,
+    /// The CLI header has an unexpected size.
+    InvalidCliHeader,
+    /// The metadata root does not start with the expected signature.
+    InvalidMetadataSignature,
+    /// The metadata contains a stream other than `#~`, `#Strings`, `#Blob`, `#GUID` or `#US`.
+    UnsupportedStream(String),
+    /// The metadata does not contain a required stream.
+    MissingStream(&'static str),
+    /// The tables stream has a valid bit set for a table that ECMA-335 does not define.
+    UnknownTable(u8),
+    /// A table extends past the end of the tables stream.
+    TableOutOfBounds { table: u8, offset: usize },
+    /// A structure extends past the end of the file.
+    OutOfBounds { offset: usize, len: usize },
+    /// A structure is not aligned as its type requires.
+    Misaligned { offset: usize, align: usize },
+    /// The file could not be opened or mapped.
+    Io(std::io::ErrorKind),
+}
+
+impl std::error::Error for Error {}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidDosSignature => write!(f, "invalid DOS signature"),
+            Self::InvalidPeSignature => write!(f, "invalid PE signature"),
+            Self::InvalidOptionalHeader(magic) => write!(f, "unsupported optional header magic `{magic:#x}`"),
+            Self::MissingCliHeader => write!(f, "missing CLI header"),
+            Self::InvalidCliHeader => write!(f, "invalid CLI header"),
+            Self::InvalidMetadataSignature => write!(f, "invalid metadata signature"),
+            Self::UnsupportedStream(name) => write!(f, "unsupported stream `{name}`"),
+            Self::MissingStream(name) => write!(f, "missing stream `{name}`"),
+            Self::UnknownTable(table) => write!(f, "unknown table `{table:#x}`"),
+            Self::TableOutOfBounds { table, offset } => write!(f, "table `{table:#x}` out of bounds at offset {offset}"),
+            Self::OutOfBounds { offset, len } => write!(f, "read of {len} bytes out of bounds at offset {offset}"),
+            Self::Misaligned { offset, align } => write!(f, "read at offset {offset} is not aligned to {align} bytes"),
+            Self::Io(kind) => write!(f, "failed to map file: {kind}"),
+        }
+    }
+}
@@ -2,10 +2,10 @@ use super::*;
 
 pub struct File {
     pub reader: *const Reader,
-    pub bytes: Vec<u8>,
+    pub bytes: Bytes,
     pub strings: usize,
     pub blobs: usize,
-    pub tables: [Table; 17],
+    pub tables: [Table; 24],
 }
 
 impl std::fmt::Debug for File {
@@ -43,16 +43,35 @@ impl PartialOrd for File {
 unsafe impl Sync for File {}
 
 impl File {
-    pub fn new(bytes: Vec<u8>) -> Option<Self> {
+    pub fn new(bytes: Vec<u8>) -> Result<Self, Error> {
+        Self::from_bytes(bytes.into())
+    }
+
+    /// Memory-maps the winmd file at `path` rather than reading it into memory.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated for as long as the returned `File` is alive.
+    pub unsafe fn map<P: AsRef<std::path::Path>>(path: P) -> Result<Self, Error> {
+        Self::from_bytes(Bytes::map(path.as_ref()).map_err(|error| Error::Io(error.kind()))?)
+    }
+
+    pub fn from_bytes(bytes: Bytes) -> Result<Self, Error> {
         let mut result = File { bytes, reader: std::ptr::null(), strings: 0, blobs: 0, tables: Default::default() };
 
         let dos = result.bytes.view_as::<IMAGE_DOS_HEADER>(0)?;
 
-        if dos.e_magic != IMAGE_DOS_SIGNATURE || result.bytes.copy_as::<u32>(dos.e_lfanew as usize)? != IMAGE_NT_SIGNATURE {
-            return None;
+        if dos.e_magic != IMAGE_DOS_SIGNATURE {
+            return Err(Error::InvalidDosSignature);
         }
 
-        let file_offset = dos.e_lfanew as usize + std::mem::size_of::<u32>();
+        let pe_offset = usize::try_from(dos.e_lfanew).map_err(|_| Error::InvalidPeSignature)?;
+
+        if result.bytes.copy_as::<u32>(pe_offset)? != IMAGE_NT_SIGNATURE {
+            return Err(Error::InvalidPeSignature);
+        }
+
+        let file_offset = pe_offset + std::mem::size_of::<u32>();
         let file = result.bytes.view_as::<IMAGE_FILE_HEADER>(file_offset)?;
 
         let optional_offset = file_offset + std::mem::size_of::<IMAGE_FILE_HEADER>();
@@ -66,37 +85,44 @@ impl File {
                 let optional = result.bytes.view_as::<IMAGE_OPTIONAL_HEADER64>(optional_offset)?;
                 (optional.DataDirectory[IMAGE_DIRECTORY_ENTRY_COM_DESCRIPTOR as usize].VirtualAddress, result.bytes.view_as_slice_of::<IMAGE_SECTION_HEADER>(optional_offset + std::mem::size_of::<IMAGE_OPTIONAL_HEADER64>(), file.NumberOfSections as usize)?)
             }
-            _ => return None,
+            magic => return Err(Error::InvalidOptionalHeader(magic)),
         };
 
-        let clr = result.bytes.view_as::<IMAGE_COR20_HEADER>(offset_from_rva(section_from_rva(sections, com_virtual_address)?, com_virtual_address))?;
+        let clr = result.bytes.view_as::<IMAGE_COR20_HEADER>(offset_from_rva(sections, com_virtual_address).ok_or(Error::MissingCliHeader)?)?;
 
         if clr.cb != std::mem::size_of::<IMAGE_COR20_HEADER>() as u32 {
-            return None;
+            return Err(Error::InvalidCliHeader);
         }
 
-        let metadata_offset = offset_from_rva(section_from_rva(sections, clr.MetaData.VirtualAddress)?, clr.MetaData.VirtualAddress);
+        let metadata_offset = offset_from_rva(sections, clr.MetaData.VirtualAddress).ok_or(Error::InvalidCliHeader)?;
         let metadata = result.bytes.view_as::<METADATA_HEADER>(metadata_offset)?;
 
         if metadata.signature != METADATA_SIGNATURE {
-            return None;
+            return Err(Error::InvalidMetadataSignature);
         }
 
         // The METADATA_HEADER struct is not a fixed size so have to offset a little more carefully.
         let mut view = metadata_offset + metadata.length as usize + 20;
-        let mut tables_data: (usize, usize) = (0, 0);
+        let mut tables_data: Option<(usize, usize)> = None;
+        let mut strings = None;
+        let mut blobs = None;
 
         for _ in 0..result.bytes.copy_as::<u16>(metadata_offset + metadata.length as usize + 18)? {
-            let stream_offset = result.bytes.copy_as::<u32>(view)? as usize;
+            let stream_offset = metadata_offset + result.bytes.copy_as::<u32>(view)? as usize;
             let stream_len = result.bytes.copy_as::<u32>(view + 4)? as usize;
             let stream_name = result.bytes.view_as_str(view + 8)?;
+
+            if stream_offset + stream_len > result.bytes.len() {
+                return Err(Error::OutOfBounds { offset: stream_offset, len: stream_len });
+            }
+
             match stream_name {
-                b"#Strings" => result.strings = metadata_offset + stream_offset,
-                b"#Blob" => result.blobs = metadata_offset + stream_offset,
-                b"#~" => tables_data = (metadata_offset + stream_offset, stream_len),
+                b"#Strings" => strings = Some(stream_offset),
+                b"#Blob" => blobs = Some(stream_offset),
+                b"#~" => tables_data = Some((stream_offset, stream_len)),
                 b"#GUID" => {}
                 b"#US" => {}
-                rest => unimplemented!("{rest:?}"),
+                rest => return Err(Error::UnsupportedStream(String::from_utf8_lossy(rest).into_owned())),
             }
             let mut padding = 4 - stream_name.len() % 4;
             if padding == 0 {
@@ -105,6 +131,11 @@ impl File {
             view += 8 + stream_name.len() + padding;
         }
 
+        result.strings = strings.ok_or(Error::MissingStream("#Strings"))?;
+        result.blobs = blobs.ok_or(Error::MissingStream("#Blob"))?;
+        let tables_data = tables_data.ok_or(Error::MissingStream("#~"))?;
+        let tables_end = tables_data.0 + tables_data.1;
+
         let heap_sizes = result.bytes.copy_as::<u8>(tables_data.0 + 6)?;
         let string_index_size = if (heap_sizes & 1) == 1 { 4 } else { 2 };
         let guid_index_size = if (heap_sizes >> 1 & 1) == 1 { 4 } else { 2 };
@@ -120,23 +151,16 @@ impl File {
         let mut unused_assembly_ref_os = Table::default();
         let mut unused_assembly_ref_processor = Table::default();
         let mut unused_decl_security = Table::default();
-        let mut unused_event = Table::default();
-        let mut unused_event_map = Table::default();
         let mut unused_exported_type = Table::default();
         let mut unused_field_layout = Table::default();
-        let mut unused_field_marshal = Table::default();
         let mut unused_field_rva = Table::default();
         let mut unused_file = Table::default();
-        let mut unused_generic_param_constraint = Table::default();
         let mut unused_manifest_resource = Table::default();
         let mut unused_method_impl = Table::default();
-        let mut unused_method_semantics = Table::default();
         let mut unused_method_spec = Table::default();
-        let mut unused_property = Table::default();
-        let mut unused_property_map = Table::default();
         let mut unused_standalone_sig = Table::default();
 
-        for i in 0..64 {
+        for i in 0..64u8 {
             if (valid_bits >> i & 1) == 0 {
                 continue;
             }
@@ -155,16 +179,16 @@ impl File {
                 0x0a => result.tables[MemberRef::TABLE].len = len,
                 0x0b => result.tables[Constant::TABLE].len = len,
                 0x0c => result.tables[Attribute::TABLE].len = len,
-                0x0d => unused_field_marshal.len = len,
+                0x0d => result.tables[FieldMarshal::TABLE].len = len,
                 0x0e => unused_decl_security.len = len,
                 0x0f => result.tables[ClassLayout::TABLE].len = len,
                 0x10 => unused_field_layout.len = len,
                 0x11 => unused_standalone_sig.len = len,
-                0x12 => unused_event_map.len = len,
-                0x14 => unused_event.len = len,
-                0x15 => unused_property_map.len = len,
-                0x17 => unused_property.len = len,
-                0x18 => unused_method_semantics.len = len,
+                0x12 => result.tables[EventMap::TABLE].len = len,
+                0x14 => result.tables[Event::TABLE].len = len,
+                0x15 => result.tables[PropertyMap::TABLE].len = len,
+                0x17 => result.tables[Property::TABLE].len = len,
+                0x18 => result.tables[MethodSemantics::TABLE].len = len,
                 0x19 => unused_method_impl.len = len,
                 0x1a => result.tables[ModuleRef::TABLE].len = len,
                 0x1b => result.tables[TypeSpec::TABLE].len = len,
@@ -182,18 +206,18 @@ impl File {
                 0x29 => result.tables[NestedClass::TABLE].len = len,
                 0x2a => result.tables[GenericParam::TABLE].len = len,
                 0x2b => unused_method_spec.len = len,
-                0x2c => unused_generic_param_constraint.len = len,
-                _ => unreachable!(),
+                0x2c => result.tables[GenericParamConstraint::TABLE].len = len,
+                table => return Err(Error::UnknownTable(table)),
             };
         }
 
         let tables = &result.tables;
         let type_def_or_ref = coded_index_size(&[tables[TypeDef::TABLE].len, tables[TypeRef::TABLE].len, tables[TypeSpec::TABLE].len]);
-        let has_constant = coded_index_size(&[tables[Field::TABLE].len, tables[Param::TABLE].len, unused_property.len]);
+        let has_constant = coded_index_size(&[tables[Field::TABLE].len, tables[Param::TABLE].len, tables[Property::TABLE].len]);
         let has_field_marshal = coded_index_size(&[tables[Field::TABLE].len, tables[Param::TABLE].len]);
         let has_decl_security = coded_index_size(&[tables[TypeDef::TABLE].len, tables[MethodDef::TABLE].len, unused_assembly.len]);
         let member_ref_parent = coded_index_size(&[tables[TypeDef::TABLE].len, tables[TypeRef::TABLE].len, tables[ModuleRef::TABLE].len, tables[MethodDef::TABLE].len, tables[TypeSpec::TABLE].len]);
-        let has_semantics = coded_index_size(&[unused_event.len, unused_property.len]);
+        let has_semantics = coded_index_size(&[tables[Event::TABLE].len, tables[Property::TABLE].len]);
         let method_def_or_ref = coded_index_size(&[tables[MethodDef::TABLE].len, tables[MemberRef::TABLE].len]);
         let member_forwarded = coded_index_size(&[tables[Field::TABLE].len, tables[MethodDef::TABLE].len]);
         let implementation = coded_index_size(&[unused_file.len, tables[AssemblyRef::TABLE].len, unused_exported_type.len]);
@@ -210,8 +234,8 @@ impl File {
             tables[InterfaceImpl::TABLE].len,
             tables[MemberRef::TABLE].len,
             tables[Module::TABLE].len,
-            unused_property.len,
-            unused_event.len,
+            tables[Property::TABLE].len,
+            tables[Event::TABLE].len,
             unused_standalone_sig.len,
             tables[ModuleRef::TABLE].len,
             tables[TypeSpec::TABLE].len,
@@ -221,7 +245,7 @@ impl File {
             unused_exported_type.len,
             unused_manifest_resource.len,
             tables[GenericParam::TABLE].len,
-            unused_generic_param_constraint.len,
+            tables[GenericParamConstraint::TABLE].len,
             unused_method_spec.len,
         ]);
 
@@ -235,77 +259,84 @@ impl File {
         result.tables[Constant::TABLE].set_columns(2, has_constant, blob_index_size, 0, 0, 0);
         result.tables[Attribute::TABLE].set_columns(has_custom_attribute, custom_attribute_type, blob_index_size, 0, 0, 0);
         unused_decl_security.set_columns(2, has_decl_security, blob_index_size, 0, 0, 0);
-        unused_event_map.set_columns(result.tables[TypeDef::TABLE].index_width(), unused_event.index_width(), 0, 0, 0, 0);
-        unused_event.set_columns(2, string_index_size, type_def_or_ref, 0, 0, 0);
+        result.tables[EventMap::TABLE].set_columns(result.tables[TypeDef::TABLE].index_width(), result.tables[Event::TABLE].index_width(), 0, 0, 0, 0);
+        result.tables[Event::TABLE].set_columns(2, string_index_size, type_def_or_ref, 0, 0, 0);
         unused_exported_type.set_columns(4, 4, string_index_size, string_index_size, implementation, 0);
         result.tables[Field::TABLE].set_columns(2, string_index_size, blob_index_size, 0, 0, 0);
         unused_field_layout.set_columns(4, result.tables[Field::TABLE].index_width(), 0, 0, 0, 0);
-        unused_field_marshal.set_columns(has_field_marshal, blob_index_size, 0, 0, 0, 0);
+        result.tables[FieldMarshal::TABLE].set_columns(has_field_marshal, blob_index_size, 0, 0, 0, 0);
         unused_field_rva.set_columns(4, result.tables[Field::TABLE].index_width(), 0, 0, 0, 0);
         unused_file.set_columns(4, string_index_size, blob_index_size, 0, 0, 0);
         result.tables[GenericParam::TABLE].set_columns(2, 2, type_or_method_def, string_index_size, 0, 0);
-        unused_generic_param_constraint.set_columns(result.tables[GenericParam::TABLE].index_width(), type_def_or_ref, 0, 0, 0, 0);
+        result.tables[GenericParamConstraint::TABLE].set_columns(result.tables[GenericParam::TABLE].index_width(), type_def_or_ref, 0, 0, 0, 0);
         result.tables[ImplMap::TABLE].set_columns(2, member_forwarded, string_index_size, result.tables[ModuleRef::TABLE].index_width(), 0, 0);
         result.tables[InterfaceImpl::TABLE].set_columns(result.tables[TypeDef::TABLE].index_width(), type_def_or_ref, 0, 0, 0, 0);
         unused_manifest_resource.set_columns(4, 4, string_index_size, implementation, 0, 0);
         result.tables[MemberRef::TABLE].set_columns(member_ref_parent, string_index_size, blob_index_size, 0, 0, 0);
         result.tables[MethodDef::TABLE].set_columns(4, 2, 2, string_index_size, blob_index_size, result.tables[Param::TABLE].index_width());
         unused_method_impl.set_columns(result.tables[TypeDef::TABLE].index_width(), method_def_or_ref, method_def_or_ref, 0, 0, 0);
-        unused_method_semantics.set_columns(2, result.tables[MethodDef::TABLE].index_width(), has_semantics, 0, 0, 0);
+        result.tables[MethodSemantics::TABLE].set_columns(2, result.tables[MethodDef::TABLE].index_width(), has_semantics, 0, 0, 0);
         unused_method_spec.set_columns(method_def_or_ref, blob_index_size, 0, 0, 0, 0);
         result.tables[Module::TABLE].set_columns(2, string_index_size, guid_index_size, guid_index_size, guid_index_size, 0);
         result.tables[ModuleRef::TABLE].set_columns(string_index_size, 0, 0, 0, 0, 0);
         result.tables[NestedClass::TABLE].set_columns(result.tables[TypeDef::TABLE].index_width(), result.tables[TypeDef::TABLE].index_width(), 0, 0, 0, 0);
         result.tables[Param::TABLE].set_columns(2, 2, string_index_size, 0, 0, 0);
-        unused_property.set_columns(2, string_index_size, blob_index_size, 0, 0, 0);
-        unused_property_map.set_columns(result.tables[TypeDef::TABLE].index_width(), unused_property.index_width(), 0, 0, 0, 0);
+        result.tables[Property::TABLE].set_columns(2, string_index_size, blob_index_size, 0, 0, 0);
+        result.tables[PropertyMap::TABLE].set_columns(result.tables[TypeDef::TABLE].index_width(), result.tables[Property::TABLE].index_width(), 0, 0, 0, 0);
         unused_standalone_sig.set_columns(blob_index_size, 0, 0, 0, 0, 0);
         result.tables[TypeDef::TABLE].set_columns(4, string_index_size, string_index_size, type_def_or_ref, result.tables[Field::TABLE].index_width(), result.tables[MethodDef::TABLE].index_width());
         result.tables[TypeRef::TABLE].set_columns(resolution_scope, string_index_size, string_index_size, 0, 0, 0);
         result.tables[TypeSpec::TABLE].set_columns(blob_index_size, 0, 0, 0, 0, 0);
 
-        result.tables[Module::TABLE].set_data(&mut view);
-        result.tables[TypeRef::TABLE].set_data(&mut view);
-        result.tables[TypeDef::TABLE].set_data(&mut view);
-        result.tables[Field::TABLE].set_data(&mut view);
-        result.tables[MethodDef::TABLE].set_data(&mut view);
-        result.tables[Param::TABLE].set_data(&mut view);
-        result.tables[InterfaceImpl::TABLE].set_data(&mut view);
-        result.tables[MemberRef::TABLE].set_data(&mut view);
-        result.tables[Constant::TABLE].set_data(&mut view);
-        result.tables[Attribute::TABLE].set_data(&mut view);
-        unused_field_marshal.set_data(&mut view);
-        unused_decl_security.set_data(&mut view);
-        result.tables[ClassLayout::TABLE].set_data(&mut view);
-        unused_field_layout.set_data(&mut view);
-        unused_standalone_sig.set_data(&mut view);
-        unused_event_map.set_data(&mut view);
-        unused_event.set_data(&mut view);
-        unused_property_map.set_data(&mut view);
-        unused_property.set_data(&mut view);
-        unused_method_semantics.set_data(&mut view);
-        unused_method_impl.set_data(&mut view);
-        result.tables[ModuleRef::TABLE].set_data(&mut view);
-        result.tables[TypeSpec::TABLE].set_data(&mut view);
-        result.tables[ImplMap::TABLE].set_data(&mut view);
-        unused_field_rva.set_data(&mut view);
-        unused_assembly.set_data(&mut view);
-        unused_assembly_processor.set_data(&mut view);
-        unused_assembly_os.set_data(&mut view);
-        result.tables[AssemblyRef::TABLE].set_data(&mut view);
-        unused_assembly_ref_processor.set_data(&mut view);
-        unused_assembly_ref_os.set_data(&mut view);
-        unused_file.set_data(&mut view);
-        unused_exported_type.set_data(&mut view);
-        unused_manifest_resource.set_data(&mut view);
-        result.tables[NestedClass::TABLE].set_data(&mut view);
-        result.tables[GenericParam::TABLE].set_data(&mut view);
-
-        Some(result)
+        result.tables[Module::TABLE].set_data(&mut view, tables_end, 0x00)?;
+        result.tables[TypeRef::TABLE].set_data(&mut view, tables_end, 0x01)?;
+        result.tables[TypeDef::TABLE].set_data(&mut view, tables_end, 0x02)?;
+        result.tables[Field::TABLE].set_data(&mut view, tables_end, 0x04)?;
+        result.tables[MethodDef::TABLE].set_data(&mut view, tables_end, 0x06)?;
+        result.tables[Param::TABLE].set_data(&mut view, tables_end, 0x08)?;
+        result.tables[InterfaceImpl::TABLE].set_data(&mut view, tables_end, 0x09)?;
+        result.tables[MemberRef::TABLE].set_data(&mut view, tables_end, 0x0a)?;
+        result.tables[Constant::TABLE].set_data(&mut view, tables_end, 0x0b)?;
+        result.tables[Attribute::TABLE].set_data(&mut view, tables_end, 0x0c)?;
+        result.tables[FieldMarshal::TABLE].set_data(&mut view, tables_end, 0x0d)?;
+        unused_decl_security.set_data(&mut view, tables_end, 0x0e)?;
+        result.tables[ClassLayout::TABLE].set_data(&mut view, tables_end, 0x0f)?;
+        unused_field_layout.set_data(&mut view, tables_end, 0x10)?;
+        unused_standalone_sig.set_data(&mut view, tables_end, 0x11)?;
+        result.tables[EventMap::TABLE].set_data(&mut view, tables_end, 0x12)?;
+        result.tables[Event::TABLE].set_data(&mut view, tables_end, 0x14)?;
+        result.tables[PropertyMap::TABLE].set_data(&mut view, tables_end, 0x15)?;
+        result.tables[Property::TABLE].set_data(&mut view, tables_end, 0x17)?;
+        result.tables[MethodSemantics::TABLE].set_data(&mut view, tables_end, 0x18)?;
+        unused_method_impl.set_data(&mut view, tables_end, 0x19)?;
+        result.tables[ModuleRef::TABLE].set_data(&mut view, tables_end, 0x1a)?;
+        result.tables[TypeSpec::TABLE].set_data(&mut view, tables_end, 0x1b)?;
+        result.tables[ImplMap::TABLE].set_data(&mut view, tables_end, 0x1c)?;
+        unused_field_rva.set_data(&mut view, tables_end, 0x1d)?;
+        unused_assembly.set_data(&mut view, tables_end, 0x20)?;
+        unused_assembly_processor.set_data(&mut view, tables_end, 0x21)?;
+        unused_assembly_os.set_data(&mut view, tables_end, 0x22)?;
+        result.tables[AssemblyRef::TABLE].set_data(&mut view, tables_end, 0x23)?;
+        unused_assembly_ref_processor.set_data(&mut view, tables_end, 0x24)?;
+        unused_assembly_ref_os.set_data(&mut view, tables_end, 0x25)?;
+        unused_file.set_data(&mut view, tables_end, 0x26)?;
+        unused_exported_type.set_data(&mut view, tables_end, 0x27)?;
+        unused_manifest_resource.set_data(&mut view, tables_end, 0x28)?;
+        result.tables[NestedClass::TABLE].set_data(&mut view, tables_end, 0x29)?;
+        result.tables[GenericParam::TABLE].set_data(&mut view, tables_end, 0x2a)?;
+        unused_method_spec.set_data(&mut view, tables_end, 0x2b)?;
+        result.tables[GenericParamConstraint::TABLE].set_data(&mut view, tables_end, 0x2c)?;
+
+        Ok(result)
     }
 
     pub fn usize(&self, row: usize, table: usize, column: usize) -> usize {
         let table = &self.tables[table];
+
+        if row >= table.len {
+            return 0;
+        }
+
         let column = &table.columns[column];
         let offset = table.offset + row * table.width + column.offset;
         match column.width {
@@ -346,69 +377,66 @@ impl File {
         first
     }
 
-    pub fn table<R: AsRow>(&'static self) -> RowIterator<R> {
+    pub fn table<'a, R: AsRow<'a>>(&'a self) -> RowIterator<'a, R> {
         RowIterator::new(self, 0..self.tables[R::TABLE].len)
     }
 }
 
-fn section_from_rva(sections: &[IMAGE_SECTION_HEADER], rva: u32) -> Option<&IMAGE_SECTION_HEADER> {
-    sections.iter().find(|&s| rva >= s.VirtualAddress && rva < s.VirtualAddress + unsafe { s.Misc.VirtualSize })
-}
-
-fn offset_from_rva(section: &IMAGE_SECTION_HEADER, rva: u32) -> usize {
-    (rva - section.VirtualAddress + section.PointerToRawData) as usize
+fn offset_from_rva(sections: &[IMAGE_SECTION_HEADER], rva: u32) -> Option<usize> {
+    let rva = rva as usize;
+    let section = sections.iter().find(|s| rva >= s.VirtualAddress as usize && rva < s.VirtualAddress as usize + unsafe { s.Misc.VirtualSize as usize })?;
+    Some(rva - section.VirtualAddress as usize + section.PointerToRawData as usize)
 }
 
 trait View {
-    fn view_as<T>(&self, offset: usize) -> Option<&T>;
-    fn view_as_slice_of<T>(&self, offset: usize, len: usize) -> Option<&[T]>;
-    fn copy_as<T: Copy>(&self, offset: usize) -> Option<T>;
-    fn view_as_str(&self, offset: usize) -> Option<&[u8]>;
-    fn is_proper_length<T>(&self, offset: usize) -> Option<()>;
-    fn is_proper_length_and_alignment<T>(&self, offset: usize, count: usize) -> Option<*const T>;
+    fn view_as<T>(&self, offset: usize) -> Result<&T, Error>;
+    fn view_as_slice_of<T>(&self, offset: usize, len: usize) -> Result<&[T], Error>;
+    fn copy_as<T: Copy>(&self, offset: usize) -> Result<T, Error>;
+    fn view_as_str(&self, offset: usize) -> Result<&[u8], Error>;
+    fn is_proper_length(&self, offset: usize, len: usize) -> Result<(), Error>;
+    fn is_proper_length_and_alignment<T>(&self, offset: usize, count: usize) -> Result<*const T, Error>;
 }
 
 impl View for [u8] {
-    fn view_as<T>(&self, offset: usize) -> Option<&T> {
-        unsafe { Some(&*self.is_proper_length_and_alignment(offset, 1)?) }
+    fn view_as<T>(&self, offset: usize) -> Result<&T, Error> {
+        unsafe { Ok(&*self.is_proper_length_and_alignment(offset, 1)?) }
     }
 
-    fn view_as_slice_of<T>(&self, offset: usize, len: usize) -> Option<&[T]> {
-        unsafe { Some(std::slice::from_raw_parts(self.is_proper_length_and_alignment(offset, len)?, len)) }
+    fn view_as_slice_of<T>(&self, offset: usize, len: usize) -> Result<&[T], Error> {
+        unsafe { Ok(std::slice::from_raw_parts(self.is_proper_length_and_alignment(offset, len)?, len)) }
     }
 
-    fn copy_as<T>(&self, offset: usize) -> Option<T> {
-        self.is_proper_length::<T>(offset)?;
+    fn copy_as<T>(&self, offset: usize) -> Result<T, Error> {
+        self.is_proper_length(offset, std::mem::size_of::<T>())?;
 
         unsafe {
             let mut data = std::mem::MaybeUninit::zeroed().assume_init();
             std::ptr::copy_nonoverlapping(self[offset..].as_ptr(), &mut data as *mut T as *mut u8, std::mem::size_of::<T>());
-            Some(data)
+            Ok(data)
         }
     }
 
-    fn view_as_str(&self, offset: usize) -> Option<&[u8]> {
-        let buffer = &self[offset..];
-        let index = buffer.iter().position(|c| *c == b'\0')?;
-        Some(&self[offset..offset + index])
+    fn view_as_str(&self, offset: usize) -> Result<&[u8], Error> {
+        let buffer = self.get(offset..).unwrap_or_default();
+        let index = buffer.iter().position(|c| *c == b'\0').ok_or(Error::OutOfBounds { offset, len: buffer.len() + 1 })?;
+        Ok(&self[offset..offset + index])
     }
 
-    fn is_proper_length<T>(&self, offset: usize) -> Option<()> {
-        if offset + std::mem::size_of::<T>() <= self.len() {
-            Some(())
-        } else {
-            None
+    fn is_proper_length(&self, offset: usize, len: usize) -> Result<(), Error> {
+        match offset.checked_add(len) {
+            Some(end) if end <= self.len() => Ok(()),
+            _ => Err(Error::OutOfBounds { offset, len }),
         }
     }
 
-    fn is_proper_length_and_alignment<T>(&self, offset: usize, count: usize) -> Option<*const T> {
-        self.is_proper_length::<T>(offset * count)?;
-        let ptr = &self[offset] as *const u8 as *const T;
+    fn is_proper_length_and_alignment<T>(&self, offset: usize, count: usize) -> Result<*const T, Error> {
+        self.is_proper_length(offset, std::mem::size_of::<T>().saturating_mul(count))?;
+        let ptr = self[offset..].as_ptr() as *const T;
 
         if ptr.align_offset(std::mem::align_of::<T>()) == 0 {
-            Some(ptr)
+            Ok(ptr)
         } else {
-            None
+            Err(Error::Misaligned { offset, align: std::mem::align_of::<T>() })
         }
     }
 }
@@ -0,0 +1,132 @@
+use super::*;
+
+/// Maps each namespace defined by a [`File`] to the rows of the TypeDef table that belong to it.
+///
+/// Building the index only reads the TypeDef table and the names it refers to, and it may be cached on disk with
+/// [`Index::cached`] so that subsequent loads of the same file don't need to read even that much. The index is kept
+/// apart from the file and handed to [`Reader::filter_indexed_owned`] along with it.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Index {
+    fingerprint: u64,
+    namespaces: BTreeMap<String, Vec<usize>>,
+}
+
+const MAGIC: &[u8; 8] = b"WINMDIDX";
+const VERSION: u32 = 1;
+
+impl Index {
+    pub fn new(file: &File) -> Self {
+        let mut namespaces = BTreeMap::<String, Vec<usize>>::new();
+
+        for def in file.table::<TypeDef<'_>>() {
+            let namespace = def.namespace();
+
+            // Nested types and the `<Module>` type don't have a namespace and are only reachable through their outer types.
+            if !namespace.is_empty() {
+                namespaces.entry(namespace.to_string()).or_default().push(def.index());
+            }
+        }
+
+        Self { fingerprint: fingerprint(file), namespaces }
+    }
+
+    /// Loads the index for `file` from `path` if it was previously cached for this file, or otherwise builds the index
+    /// and writes it to `path` so that it can be loaded next time.
+    pub fn cached<P: AsRef<std::path::Path>>(file: &File, path: P) -> std::io::Result<Self> {
+        let path = path.as_ref();
+
+        if let Some(index) = std::fs::read(path).ok().and_then(|bytes| Self::from_bytes(file, &bytes)) {
+            return Ok(index);
+        }
+
+        let index = Self::new(file);
+        std::fs::write(path, index.to_bytes())?;
+        Ok(index)
+    }
+
+    pub fn namespaces(&self) -> impl Iterator<Item = (&str, &[usize])> + '_ {
+        self.namespaces.iter().map(|(namespace, rows)| (namespace.as_str(), rows.as_slice()))
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&VERSION.to_le_bytes());
+        bytes.extend_from_slice(&self.fingerprint.to_le_bytes());
+        bytes.extend_from_slice(&(self.namespaces.len() as u32).to_le_bytes());
+
+        for (namespace, rows) in &self.namespaces {
+            bytes.extend_from_slice(&(namespace.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(namespace.as_bytes());
+            bytes.extend_from_slice(&(rows.len() as u32).to_le_bytes());
+
+            for row in rows {
+                bytes.extend_from_slice(&(*row as u32).to_le_bytes());
+            }
+        }
+
+        bytes
+    }
+
+    /// Reads an index previously written by [`Index::to_bytes`], returning `None` if the bytes are malformed or were
+    /// produced for a different file.
+    pub fn from_bytes(file: &File, bytes: &[u8]) -> Option<Self> {
+        let mut bytes = bytes.strip_prefix(MAGIC)?;
+
+        if read_u32(&mut bytes)? != VERSION {
+            return None;
+        }
+
+        let fingerprint = u64::from_le_bytes(take(&mut bytes, 8)?.try_into().ok()?);
+
+        if fingerprint != self::fingerprint(file) {
+            return None;
+        }
+
+        let type_defs = file.tables[TypeDef::TABLE].len;
+        let mut namespaces = BTreeMap::new();
+
+        for _ in 0..read_u32(&mut bytes)? {
+            let len = read_u32(&mut bytes)? as usize;
+            let namespace = std::str::from_utf8(take(&mut bytes, len)?).ok()?.to_string();
+            let len = read_u32(&mut bytes)? as usize;
+            let mut rows = Vec::with_capacity(len.min(type_defs));
+
+            for _ in 0..len {
+                let row = read_u32(&mut bytes)? as usize;
+
+                if row >= type_defs {
+                    return None;
+                }
+
+                rows.push(row);
+            }
+
+            namespaces.insert(namespace, rows);
+        }
+
+        bytes.is_empty().then_some(Self { fingerprint, namespaces })
+    }
+}
+
+/// Identifies the file an index was built for by hashing its length and TypeDef table with FNV-1a.
+fn fingerprint(file: &File) -> u64 {
+    let table = &file.tables[TypeDef::TABLE];
+    let rows = file.bytes.get(table.offset..table.offset + table.len * table.width).unwrap_or_default();
+
+    (file.bytes.len() as u64).to_le_bytes().iter().chain(rows).fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ *byte as u64).wrapping_mul(0x100_0000_01b3))
+}
+
+fn take<'a>(bytes: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+    if bytes.len() < len {
+        return None;
+    }
+
+    let (value, rest) = bytes.split_at(len);
+    *bytes = rest;
+    Some(value)
+}
+
+fn read_u32(bytes: &mut &[u8]) -> Option<u32> {
+    Some(u32::from_le_bytes(take(bytes, 4)?.try_into().ok()?))
+}
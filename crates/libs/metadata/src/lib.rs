@@ -10,10 +10,13 @@ use std::collections::*;
 mod attributes;
 mod bindings;
 mod blob;
+mod bytes;
 mod codes;
 mod column;
+mod error;
 mod file;
 mod filter;
+mod index;
 mod reader;
 mod row;
 mod table;
@@ -24,10 +27,13 @@ mod type_name;
 pub use attributes::*;
 pub use bindings::*;
 pub use blob::*;
+pub use bytes::*;
 pub use codes::*;
 use column::*;
+pub use error::*;
 pub use file::*;
 use filter::*;
+pub use index::*;
 pub use r#type::*;
 pub use reader::*;
 pub use row::*;
@@ -50,6 +56,14 @@ pub struct METADATA_HEADER {
 
 pub const METADATA_SIGNATURE: u32 = 0x424A_5342;
 
+// Custom attribute blobs (see II.23.3 in ECMA-335) use these codes in addition to the `ELEMENT_TYPE` codes to describe
+// the types of named and boxed arguments.
+pub const SERIALIZATION_TYPE_TYPE: u8 = 0x50;
+pub const SERIALIZATION_TYPE_TAGGED_OBJECT: u8 = 0x51;
+pub const SERIALIZATION_TYPE_FIELD: u8 = 0x53;
+pub const SERIALIZATION_TYPE_PROPERTY: u8 = 0x54;
+pub const SERIALIZATION_TYPE_ENUM: u8 = 0x55;
+
 /// A coded index (see codes.rs) is a table index that may refer to different tables. The size of the column in memory
 /// must therefore be large enough to hold an index for a row in the largest possible table. This function determines
 /// this size for the given winmd file.
@@ -80,8 +94,9 @@ pub fn coded_index_size(tables: &[usize]) -> usize {
 }
 
 #[derive(Debug)]
-pub enum Value {
+pub enum Value<'a> {
     Bool(bool),
+    Char(u16),
     U8(u8),
     I8(i8),
     U16(u16),
@@ -93,17 +108,36 @@ pub enum Value {
     F32(f32),
     F64(f64),
     String(String),
-    TypeName(TypeName),
-    EnumDef(TypeDef, Box<Self>),
+    TypeName(TypeName<'a>),
+    EnumDef(TypeDef<'a>, Box<Self>),
+    /// An enum whose definition isn't available to the reader. Its underlying type is assumed to be `i32`, since the
+    /// blob doesn't record it, so the value may be misread if the enum has some other underlying type.
+    EnumRef(TypeName<'a>, Box<Self>),
+    /// A single-dimensional array along with the type of its elements.
+    Array(Type<'a>, Vec<Self>),
+    /// A value boxed by a `System.Object` argument.
+    Object(Box<Self>),
+    /// A null string, `System.Type` or array along with its type, as distinct from an empty one.
+    Null(Type<'a>),
+    /// A value of a type that can't be encoded in a custom attribute. The rest of the blob can't be read past it.
+    Invalid,
+}
+
+/// Whether a custom attribute argument is passed to the constructor or sets a named field or property.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ArgKind {
+    Fixed,
+    Field,
+    Property,
 }
 
-pub struct MethodDefSig {
+pub struct MethodDefSig<'a> {
     pub call_flags: MethodCallAttributes,
-    pub return_type: Type,
-    pub params: Vec<Type>,
+    pub return_type: Type<'a>,
+    pub params: Vec<Type<'a>>,
 }
 
-impl MethodDefSig {
+impl MethodDefSig<'_> {
     pub fn size(&self) -> usize {
         self.params.iter().fold(0, |sum, param| sum + std::cmp::max(4, param.size()))
     }
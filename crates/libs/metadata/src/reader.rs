@@ -1,79 +1,154 @@
 use super::*;
 
 #[derive(Clone)]
-pub enum Item {
-    Type(TypeDef),
-    Const(Field),
+pub enum Item<'a> {
+    Type(TypeDef<'a>),
+    Const(Field<'a>),
     // TODO: get rid of the trailing String - that's just a hack to get around a silly Win32 metadata deficiency where parsing method signatures
     // requires knowing which namespace the method's surrounding interface was defined in.
-    Fn(MethodDef, &'static str),
+    Fn(MethodDef<'a>, &'a str),
 }
 
 pub struct Reader {
-    // TODO: get rid of inner Vec - that's just a hack to support multi-arch structs in Win32 metadata.
-    items: BTreeMap<&'static str, BTreeMap<&'static str, Vec<Item>>>,
+    // The `'static` lifetimes are a private fiction: everything in here borrows from `files` and is only ever handed out
+    // with lifetimes bound to the reader itself.
+    namespaces: BTreeMap<&'static str, Namespace>,
 
     // TODO: riddle should just avoid nested structs
-    nested: HashMap<TypeDef, BTreeMap<&'static str, TypeDef>>,
+    nested: std::sync::OnceLock<HashMap<TypeDef<'static>, BTreeMap<&'static str, TypeDef<'static>>>>,
 
     // The reader needs to store the filter since standalone code generation needs more than just the filtered items
     // in order to chase dependencies automatically. This is why `Reader::filter` can't just filter everything up front.
     filter: Filter,
 
     sys: bool,
-}
-
-impl Reader {
-    pub fn new(files: Vec<File>) -> &'static Self {
-        let mut config = BTreeMap::new();
-        config.insert("sys", "");
-        Self::filter(files, &[], &[], &config)
-    }
 
-    pub fn filter(files: Vec<File>, include: &[&str], exclude: &[&str], config: &BTreeMap<&str, &str>) -> &'static Self {
-        let reader: &'static mut Reader = Box::leak(Box::new(Self { items: Default::default(), nested: Default::default(), filter: Filter::new(include, exclude), sys: config.contains_key("sys") }));
+    files: Vec<File>,
+}
 
-        for mut file in files {
-            file.reader = reader as *mut Reader;
-            let file = Box::leak(Box::new(file));
+/// The type definitions in a namespace, across all files, along with the items they define. The items are only
+/// gathered once the namespace is first used.
+#[derive(Default)]
+struct Namespace {
+    defs: Vec<TypeDef<'static>>,
 
-            for def in file.table::<TypeDef>() {
-                let namespace = def.namespace();
+    // TODO: get rid of inner Vec - that's just a hack to support multi-arch structs in Win32 metadata.
+    items: std::sync::OnceLock<BTreeMap<&'static str, Vec<Item<'static>>>>,
+}
 
-                if namespace.is_empty() {
-                    continue;
-                }
+impl Namespace {
+    fn items(&self) -> &BTreeMap<&'static str, Vec<Item<'static>>> {
+        self.items.get_or_init(|| {
+            let mut items = BTreeMap::<&str, Vec<Item<'_>>>::new();
 
-                let namespace_items = reader.items.entry(namespace).or_default();
+            for def in &self.defs {
                 let name = def.name();
 
                 if name == "Apis" {
                     for method in def.methods() {
-                        namespace_items.entry(method.name()).or_default().push(Item::Fn(method, namespace));
+                        items.entry(method.name()).or_default().push(Item::Fn(method, def.namespace()));
                     }
 
                     for field in def.fields() {
-                        namespace_items.entry(field.name()).or_default().push(Item::Const(field));
+                        items.entry(field.name()).or_default().push(Item::Const(field));
                     }
                 } else {
-                    namespace_items.entry(name).or_default().push(Item::Type(def));
+                    items.entry(name).or_default().push(Item::Type(*def));
 
                     // TODO: these should all be fields on the Apis class so we don't have to go looking for all of these as well.
                     if def.extends() == Some(TypeName::Enum) && !def.flags().contains(TypeAttributes::WindowsRuntime) && !def.has_attribute("ScopedEnumAttribute") {
                         for field in def.fields().filter(|field| field.flags().contains(FieldAttributes::Literal)) {
-                            namespace_items.entry(field.name()).or_default().push(Item::Const(field));
+                            items.entry(field.name()).or_default().push(Item::Const(field));
                         }
                     }
                 }
             }
 
-            for key in file.table::<NestedClass>() {
-                let inner = key.inner();
-                reader.nested.entry(key.outer()).or_default().insert(inner.name(), inner);
+            items
+        })
+    }
+}
+
+impl Reader {
+    /// Creates a reader that lives for the rest of the process. Use [`Reader::new_owned`] to free the files once the
+    /// reader is no longer needed.
+    pub fn new(files: Vec<File>) -> &'static Self {
+        Self::new_owned(files).leak()
+    }
+
+    /// Creates a filtered reader that lives for the rest of the process. Use [`Reader::filter_owned`] to free the files
+    /// once the reader is no longer needed.
+    pub fn filter(files: Vec<File>, include: &[&str], exclude: &[&str], config: &BTreeMap<&str, &str>) -> &'static Self {
+        Self::filter_owned(files, include, exclude, config).leak()
+    }
+
+    pub fn new_owned(files: Vec<File>) -> OwnedReader {
+        let mut config = BTreeMap::new();
+        config.insert("sys", "");
+        Self::filter_owned(files, &[], &[], &config)
+    }
+
+    /// Creates a filtered reader. Only each file's namespace [`Index`] is read up front; the items in a namespace are
+    /// gathered the first time the namespace is used.
+    pub fn filter_owned(files: Vec<File>, include: &[&str], exclude: &[&str], config: &BTreeMap<&str, &str>) -> OwnedReader {
+        let files = files.into_iter().map(|file| {
+            let index = Index::new(&file);
+            (file, index)
+        });
+
+        Self::filter_indexed_owned(files.collect(), include, exclude, config)
+    }
+
+    /// Creates a filtered reader from files whose namespace [`Index`] has already been built or loaded from a cache.
+    pub fn filter_indexed_owned(files: Vec<(File, Index)>, include: &[&str], exclude: &[&str], config: &BTreeMap<&str, &str>) -> OwnedReader {
+        let (files, indexes): (Vec<File>, Vec<Index>) = files.into_iter().unzip();
+        let reader = Box::into_raw(Box::new(Self { namespaces: Default::default(), nested: Default::default(), filter: Filter::new(include, exclude), sys: config.contains_key("sys"), files }));
+
+        // Safety: The reader is only accessed through disjoint fields while it is being indexed. The files are neither
+        // moved nor dropped until the reader itself is dropped, so the rows borrowing from them remain valid for as long
+        // as `namespaces` and `nested` do.
+        unsafe {
+            for file in &mut (*reader).files {
+                file.reader = reader;
             }
+
+            let files: &'static [File] = &(*reader).files;
+            let namespaces = &mut (*reader).namespaces;
+
+            for (file, index) in files.iter().zip(&indexes) {
+                for (_, rows) in index.namespaces() {
+                    let defs: Vec<TypeDef<'_>> = rows.iter().map(|row| TypeDef::from_row(Row::new(file, *row))).collect();
+
+                    // The namespace is borrowed from the file rather than the index, which is dropped once it's been read.
+                    if let Some(def) = defs.first() {
+                        namespaces.entry(def.namespace()).or_default().defs.extend(defs);
+                    }
+                }
+            }
+
+            OwnedReader(std::ptr::NonNull::new_unchecked(reader))
         }
+    }
 
-        reader
+    fn namespace_map(&self, namespace: &str) -> Option<&BTreeMap<&str, Vec<Item<'_>>>> {
+        self.namespaces.get(namespace).map(|namespace| namespace.items())
+    }
+
+    fn nested_map(&self) -> &HashMap<TypeDef<'_>, BTreeMap<&str, TypeDef<'_>>> {
+        self.nested.get_or_init(|| {
+            // Safety: The files live as long as the reader and the map is only handed out with lifetimes bound to the reader.
+            let files: &'static [File] = unsafe { &*(self.files.as_slice() as *const [File]) };
+            let mut nested = HashMap::<TypeDef<'_>, BTreeMap<&str, TypeDef<'_>>>::new();
+
+            for file in files {
+                for key in file.table::<NestedClass<'_>>() {
+                    let inner = key.inner();
+                    nested.entry(key.outer()).or_default().insert(inner.name(), inner);
+                }
+            }
+
+            nested
+        })
     }
 
     pub fn includes_namespace(&self, namespace: &str) -> bool {
@@ -81,15 +156,15 @@ impl Reader {
     }
 
     pub fn namespaces(&self) -> impl Iterator<Item = &str> + '_ {
-        self.items.keys().copied()
+        self.namespaces.keys().copied()
     }
 
-    pub fn items(&self) -> impl Iterator<Item = Item> + '_ {
-        self.items.iter().filter(move |(namespace, _)| self.filter.includes_namespace(namespace)).flat_map(move |(namespace, items)| items.iter().filter(move |(name, _)| self.filter.includes_type_name(namespace, name))).flat_map(move |(_, items)| items).cloned()
+    pub fn items(&self) -> impl Iterator<Item = Item<'_>> + '_ {
+        self.namespaces.iter().filter(move |(namespace, _)| self.filter.includes_namespace(namespace)).flat_map(move |(namespace, items)| items.items().iter().filter(move |(name, _)| self.filter.includes_type_name(namespace, name))).flat_map(move |(_, items)| items).cloned()
     }
 
-    pub fn namespace_items(&self, namespace: &str) -> impl Iterator<Item = Item> + '_ {
-        self.items.get_key_value(namespace).into_iter().flat_map(move |(namespace, items)| items.iter().filter(move |(name, _)| self.filter.includes_type_name(namespace, name))).flat_map(move |(_, items)| items).cloned()
+    pub fn namespace_items(&self, namespace: &str) -> impl Iterator<Item = Item<'_>> + '_ {
+        self.namespaces.get_key_value(namespace).into_iter().flat_map(move |(namespace, items)| items.items().iter().filter(move |(name, _)| self.filter.includes_type_name(namespace, name))).flat_map(move |(_, items)| items).cloned()
     }
 
     pub fn unused(&self) -> impl Iterator<Item = &str> + '_ {
@@ -98,19 +173,19 @@ impl Reader {
 
     fn is_unused(&self, filter: &str) -> bool {
         // Match namespaces
-        if self.items.contains_key(filter) {
+        if self.namespaces.contains_key(filter) {
             return false;
         }
 
         // Match type names
         if let Some((namespace, name)) = filter.rsplit_once('.') {
-            if self.items.get(namespace).is_some_and(|items| items.contains_key(name)) {
+            if self.namespace_map(namespace).is_some_and(|items| items.contains_key(name)) {
                 return false;
             }
         }
 
         // Match empty parent namespaces
-        for namespace in self.items.keys() {
+        for namespace in self.namespaces.keys() {
             if namespace.len() > filter.len() && namespace.starts_with(filter) && namespace.as_bytes()[filter.len()] == b'.' {
                 return false;
             }
@@ -119,8 +194,8 @@ impl Reader {
         true
     }
 
-    fn get_item(&self, namespace: &str, name: &str) -> impl Iterator<Item = Item> + '_ {
-        if let Some(items) = self.items.get(namespace) {
+    fn get_item(&self, namespace: &str, name: &str) -> impl Iterator<Item = Item<'_>> + '_ {
+        if let Some(items) = self.namespace_map(namespace) {
             if let Some(items) = items.get(name) {
                 return Some(items.iter().cloned()).into_iter().flatten();
             }
@@ -128,19 +203,31 @@ impl Reader {
         None.into_iter().flatten()
     }
 
-    pub fn get_type_def(&self, namespace: &str, name: &str) -> impl Iterator<Item = TypeDef> + '_ {
+    pub fn get_type_def(&self, namespace: &str, name: &str) -> impl Iterator<Item = TypeDef<'_>> + '_ {
         self.get_item(namespace, name).filter_map(|item| if let Item::Type(def) = item { Some(def) } else { None })
     }
 
-    pub fn get_method_def(&self, namespace: &str, name: &str) -> impl Iterator<Item = (MethodDef, &'static str)> + '_ {
+    pub fn get_method_def(&self, namespace: &str, name: &str) -> impl Iterator<Item = (MethodDef<'_>, &str)> + '_ {
         self.get_item(namespace, name).filter_map(|item| if let Item::Fn(def, namespace) = item { Some((def, namespace)) } else { None })
     }
 
-    pub fn nested_types(&self, type_def: TypeDef) -> impl Iterator<Item = TypeDef> + '_ {
-        self.nested.get(&type_def).map(|map| map.values().copied()).into_iter().flatten()
+    /// Finds the type definition for a type name that may refer to a nested type, as in `Namespace.Outer+Inner`.
+    pub fn resolve_type_def(&self, type_name: TypeName<'_>) -> Option<TypeDef<'_>> {
+        let mut names = type_name.name.split('+');
+        let mut def = self.get_type_def(type_name.namespace, names.next()?).next()?;
+
+        for name in names {
+            def = self.nested_types(def).find(|nested| nested.name() == name)?;
+        }
+
+        Some(def)
+    }
+
+    pub fn nested_types<'a>(&'a self, type_def: TypeDef<'a>) -> impl Iterator<Item = TypeDef<'a>> + 'a {
+        self.nested_map().get(&type_def).map(|map| map.values().copied()).into_iter().flatten()
     }
 
-    pub fn remap_types(&self) -> impl Iterator<Item = &(TypeName, TypeName)> + '_ {
+    pub fn remap_types(&self) -> impl Iterator<Item = &(TypeName<'static>, TypeName<'static>)> + '_ {
         if self.sys {
             [].iter()
         } else {
@@ -148,7 +235,7 @@ impl Reader {
         }
     }
 
-    pub fn core_types(&self) -> impl Iterator<Item = &(TypeName, Type)> + '_ {
+    pub fn core_types(&self) -> impl Iterator<Item = &(TypeName<'static>, Type<'static>)> + '_ {
         if self.sys {
             SYS_CORE_TYPES.iter()
         } else {
@@ -156,7 +243,7 @@ impl Reader {
         }
     }
 
-    pub fn type_from_ref(&self, code: TypeDefOrRef, enclosing: Option<TypeDef>, generics: &[Type]) -> Type {
+    pub fn type_from_ref<'a>(&'a self, code: TypeDefOrRef<'a>, enclosing: Option<TypeDef<'a>>, generics: &[Type<'a>]) -> Type<'a> {
         if let TypeDefOrRef::TypeSpec(def) = code {
             let mut blob = def.blob(0);
             return self.type_from_blob_impl(&mut blob, None, generics);
@@ -179,7 +266,7 @@ impl Reader {
 
         if let Some(outer) = enclosing {
             if full_name.namespace.is_empty() {
-                let nested = &self.nested[&outer];
+                let nested = &self.nested_map()[&outer];
                 let Some(inner) = nested.get(full_name.name) else {
                     panic!("Nested type not found: {}.{}", outer.type_name(), full_name.name);
                 };
@@ -194,7 +281,7 @@ impl Reader {
         }
     }
 
-    pub fn type_from_blob(&self, blob: &mut Blob, enclosing: Option<TypeDef>, generics: &[Type]) -> Type {
+    pub fn type_from_blob<'a>(&'a self, blob: &mut Blob<'a>, enclosing: Option<TypeDef<'a>>, generics: &[Type<'a>]) -> Type<'a> {
         // Used by WinRT to indicate that a struct input parameter is passed by reference rather than by value on the ABI.
         let is_const = blob.read_modifiers().iter().any(|def| def.type_name() == TypeName::IsConst);
 
@@ -231,7 +318,7 @@ impl Reader {
         }
     }
 
-    fn type_from_blob_impl(&self, blob: &mut Blob, enclosing: Option<TypeDef>, generics: &[Type]) -> Type {
+    fn type_from_blob_impl<'a>(&'a self, blob: &mut Blob<'a>, enclosing: Option<TypeDef<'a>>, generics: &[Type<'a>]) -> Type<'a> {
         let code = blob.read_usize();
 
         if let Some(code) = Type::from_code(code) {
@@ -267,8 +354,44 @@ impl Reader {
 }
 
 // TODO: this should be in riddle's Rust generator if at all - perhaps as convertible types rather than remapped types since there's already some precedent for that.
-const REMAP_TYPES: [(TypeName, TypeName); 2] = [(TypeName::D2D_MATRIX_3X2_F, TypeName::Matrix3x2), (TypeName::D3DMATRIX, TypeName::Matrix4x4)];
+const REMAP_TYPES: [(TypeName<'static>, TypeName<'static>); 2] = [(TypeName::D2D_MATRIX_3X2_F, TypeName::Matrix3x2), (TypeName::D3DMATRIX, TypeName::Matrix4x4)];
 
 // TODO: get rid of at least the second tuple if not the whole thing.
-const CORE_TYPES: [(TypeName, Type); 13] = [(TypeName::GUID, Type::GUID), (TypeName::IUnknown, Type::IUnknown), (TypeName::HResult, Type::HRESULT), (TypeName::HRESULT, Type::HRESULT), (TypeName::HSTRING, Type::String), (TypeName::BSTR, Type::BSTR), (TypeName::IInspectable, Type::IInspectable), (TypeName::PSTR, Type::PSTR), (TypeName::PWSTR, Type::PWSTR), (TypeName::Type, Type::Type), (TypeName::CHAR, Type::I8), (TypeName::VARIANT, Type::VARIANT), (TypeName::PROPVARIANT, Type::PROPVARIANT)];
-const SYS_CORE_TYPES: [(TypeName, Type); 11] = [(TypeName::GUID, Type::GUID), (TypeName::IUnknown, Type::IUnknown), (TypeName::HResult, Type::HRESULT), (TypeName::HRESULT, Type::HRESULT), (TypeName::HSTRING, Type::String), (TypeName::BSTR, Type::BSTR), (TypeName::IInspectable, Type::IInspectable), (TypeName::PSTR, Type::PSTR), (TypeName::PWSTR, Type::PWSTR), (TypeName::Type, Type::Type), (TypeName::CHAR, Type::I8)];
+const CORE_TYPES: [(TypeName<'static>, Type<'static>); 13] = [(TypeName::GUID, Type::GUID), (TypeName::IUnknown, Type::IUnknown), (TypeName::HResult, Type::HRESULT), (TypeName::HRESULT, Type::HRESULT), (TypeName::HSTRING, Type::String), (TypeName::BSTR, Type::BSTR), (TypeName::IInspectable, Type::IInspectable), (TypeName::PSTR, Type::PSTR), (TypeName::PWSTR, Type::PWSTR), (TypeName::Type, Type::Type), (TypeName::CHAR, Type::I8), (TypeName::VARIANT, Type::VARIANT), (TypeName::PROPVARIANT, Type::PROPVARIANT)];
+const SYS_CORE_TYPES: [(TypeName<'static>, Type<'static>); 11] = [(TypeName::GUID, Type::GUID), (TypeName::IUnknown, Type::IUnknown), (TypeName::HResult, Type::HRESULT), (TypeName::HRESULT, Type::HRESULT), (TypeName::HSTRING, Type::String), (TypeName::BSTR, Type::BSTR), (TypeName::IInspectable, Type::IInspectable), (TypeName::PSTR, Type::PSTR), (TypeName::PWSTR, Type::PWSTR), (TypeName::Type, Type::Type), (TypeName::CHAR, Type::I8)];
+
+/// A [`Reader`] that owns its files and frees them, along with the reader, when dropped.
+///
+/// Everything read from the files borrows from the `OwnedReader`, so the borrow checker ensures that no row outlives
+/// the files it refers to. Use [`OwnedReader::leak`] to obtain a `&'static Reader` instead.
+pub struct OwnedReader(std::ptr::NonNull<Reader>);
+
+impl OwnedReader {
+    /// Leaks the reader, along with its files, for the rest of the process.
+    pub fn leak(self) -> &'static Reader {
+        let reader = self.0;
+        std::mem::forget(self);
+        // Safety: The reader was allocated by `Reader::filter_owned` and is never freed once leaked.
+        unsafe { &*reader.as_ptr() }
+    }
+}
+
+impl std::ops::Deref for OwnedReader {
+    type Target = Reader;
+
+    fn deref(&self) -> &Reader {
+        // Safety: The reader was allocated by `Reader::filter_owned` and is only freed when the `OwnedReader` is dropped.
+        unsafe { self.0.as_ref() }
+    }
+}
+
+impl Drop for OwnedReader {
+    fn drop(&mut self) {
+        // Safety: The reader was allocated by `Reader::filter_owned` and nothing can still borrow from it.
+        unsafe { drop(Box::from_raw(self.0.as_ptr())) }
+    }
+}
+
+// Safety: The `OwnedReader` uniquely owns the `Reader`, which is itself `Send` and `Sync`.
+unsafe impl Send for OwnedReader {}
+unsafe impl Sync for OwnedReader {}
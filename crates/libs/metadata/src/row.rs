@@ -1,13 +1,13 @@
 use super::*;
 
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, Ord, PartialOrd)]
-pub struct Row {
-    pub file: &'static File,
+pub struct Row<'a> {
+    pub file: &'a File,
     pub index: usize,
 }
 
-impl Row {
-    pub fn new(file: &'static File, index: usize) -> Self {
+impl<'a> Row<'a> {
+    pub fn new(file: &'a File, index: usize) -> Self {
         Self { file, index }
     }
 
@@ -16,16 +16,16 @@ impl Row {
     }
 }
 
-pub trait AsRow: Copy {
+pub trait AsRow<'a>: Copy {
     const TABLE: usize;
-    fn to_row(&self) -> Row;
-    fn from_row(row: Row) -> Self;
+    fn to_row(&self) -> Row<'a>;
+    fn from_row(row: Row<'a>) -> Self;
 
-    fn file(&self) -> &'static File {
+    fn file(&self) -> &'a File {
         self.to_row().file
     }
 
-    fn reader(&self) -> &'static Reader {
+    fn reader(&self) -> &'a Reader {
         // Safety: At this point the File is already pointing to a valid Reader.
         unsafe { &*self.file().reader }
     }
@@ -42,53 +42,69 @@ pub trait AsRow: Copy {
         self.file().usize(self.index(), Self::TABLE, column)
     }
 
-    fn str(&self, column: usize) -> &'static str {
+    /// Reads a string from the strings heap, or an empty string if it isn't a null-terminated utf-8 string.
+    fn str(&self, column: usize) -> &'a str {
         let file = self.file();
         let offset = file.strings + self.usize(column);
-        let bytes = &file.bytes[offset..];
-        let nul_pos = bytes.iter().position(|&c| c == 0).expect("expected null-terminated C-string");
-        std::str::from_utf8(&bytes[..nul_pos]).expect("expected valid utf-8 C-string")
+        let bytes = file.bytes.get(offset..).unwrap_or_default();
+
+        match bytes.iter().position(|&c| c == 0) {
+            Some(nul_pos) => std::str::from_utf8(&bytes[..nul_pos]).unwrap_or_default(),
+            None => "",
+        }
     }
 
-    fn row(&self, column: usize) -> Row {
-        Row::new(self.file(), self.usize(column) - 1)
+    /// Reads a row of the `R` table. A null index refers to the row past the end of the table, whose columns read as zero.
+    fn row<R: AsRow<'a>>(&self, column: usize) -> R {
+        let file = self.file();
+        R::from_row(Row::new(file, self.usize(column).checked_sub(1).unwrap_or(file.tables[R::TABLE].len)))
     }
 
-    fn decode<T: Decode>(&self, column: usize) -> T {
+    fn decode<T: Decode<'a>>(&self, column: usize) -> T {
         T::decode(self.file(), self.usize(column))
     }
 
-    fn blob(&self, column: usize) -> Blob {
+    fn blob(&self, column: usize) -> Blob<'a> {
         let file = self.file();
         let offset = file.blobs + self.usize(column);
-        let initial_byte = file.bytes[offset];
+        let Some(&initial_byte) = file.bytes.get(offset) else {
+            return Blob::new(file, &[]);
+        };
 
         let (blob_size, blob_size_bytes) = match initial_byte >> 5 {
             0..=3 => (initial_byte & 0x7f, 1),
             4..=5 => (initial_byte & 0x3f, 2),
             6 => (initial_byte & 0x1f, 4),
-            rest => unimplemented!("{rest:?}"),
+            _ => return Blob::new(file, &[]),
         };
 
         let mut blob_size = blob_size as usize;
 
-        for byte in &file.bytes[offset + 1..offset + blob_size_bytes] {
+        for byte in file.bytes.get(offset + 1..offset + blob_size_bytes).unwrap_or_default() {
             blob_size = blob_size.checked_shl(8).unwrap_or(0) + (*byte as usize);
         }
 
         let offset = offset + blob_size_bytes;
-        Blob::new(file, &file.bytes[offset..offset + blob_size])
+        Blob::new(file, file.bytes.get(offset..offset + blob_size).unwrap_or_default())
     }
 
-    fn list<R: AsRow>(&self, column: usize) -> RowIterator<R> {
+    fn list<R: AsRow<'a>>(&self, column: usize) -> RowIterator<'a, R> {
         let file = self.file();
-        let first = self.usize(column) - 1;
+        let len = file.tables[R::TABLE].len;
+        let first = self.usize(column).saturating_sub(1).min(len);
         let next = self.next();
-        let last = if next.index() < file.tables[Self::TABLE].len { next.usize(column) - 1 } else { file.tables[R::TABLE].len };
+        let last = if next.index() < file.tables[Self::TABLE].len { next.usize(column).saturating_sub(1).clamp(first, len) } else { len };
         RowIterator::new(file, first..last)
     }
 
-    fn equal_range<L: AsRow>(&self, column: usize, value: usize) -> RowIterator<L> {
+    /// Returns the row of the `P` table whose list, starting at `column`, includes this row.
+    fn list_parent<P: AsRow<'a>>(&self, column: usize) -> P {
+        let file = self.file();
+        let last = file.upper_bound_of(P::TABLE, 0, file.tables[P::TABLE].len, column, self.index() + 1);
+        P::from_row(Row::new(file, last - 1))
+    }
+
+    fn equal_range<L: AsRow<'a>>(&self, column: usize, value: usize) -> RowIterator<'a, L> {
         let file = self.file();
         let mut first = 0;
         let mut last = file.tables[L::TABLE].len;
@@ -124,19 +140,19 @@ pub trait AsRow: Copy {
     }
 }
 
-pub struct RowIterator<R: AsRow> {
-    file: &'static File,
+pub struct RowIterator<'a, R: AsRow<'a>> {
+    file: &'a File,
     rows: std::ops::Range<usize>,
     phantom: std::marker::PhantomData<R>,
 }
 
-impl<R: AsRow> RowIterator<R> {
-    pub fn new(file: &'static File, rows: std::ops::Range<usize>) -> Self {
+impl<'a, R: AsRow<'a>> RowIterator<'a, R> {
+    pub fn new(file: &'a File, rows: std::ops::Range<usize>) -> Self {
         Self { file, rows, phantom: std::marker::PhantomData }
     }
 }
 
-impl<R: AsRow> Iterator for RowIterator<R> {
+impl<'a, R: AsRow<'a>> Iterator for RowIterator<'a, R> {
     type Item = R;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -144,18 +160,18 @@ impl<R: AsRow> Iterator for RowIterator<R> {
     }
 }
 
-pub trait HasAttributes {
-    fn attributes(&self) -> RowIterator<Attribute>;
-    fn find_attribute(&self, name: &str) -> Option<Attribute>;
+pub trait HasAttributes<'a> {
+    fn attributes(&self) -> RowIterator<'a, Attribute<'a>>;
+    fn find_attribute(&self, name: &str) -> Option<Attribute<'a>>;
     fn has_attribute(&self, name: &str) -> bool;
 }
 
-impl<R: AsRow + Into<HasAttribute>> HasAttributes for R {
-    fn attributes(&self) -> RowIterator<Attribute> {
-        self.equal_range(0, Into::<HasAttribute>::into(*self).encode())
+impl<'a, R: AsRow<'a> + Into<HasAttribute<'a>>> HasAttributes<'a> for R {
+    fn attributes(&self) -> RowIterator<'a, Attribute<'a>> {
+        self.equal_range(0, Into::<HasAttribute<'a>>::into(*self).encode())
     }
 
-    fn find_attribute(&self, name: &str) -> Option<Attribute> {
+    fn find_attribute(&self, name: &str) -> Option<Attribute<'a>> {
         self.attributes().find(|attribute| attribute.name() == name)
     }
 
@@ -37,11 +37,18 @@ impl Table {
         }
     }
 
-    pub fn set_data(&mut self, offset: &mut usize) {
+    pub fn set_data(&mut self, offset: &mut usize, end: usize, table: u8) -> Result<(), Error> {
         if self.len != 0 {
             let next = *offset + self.len * self.width;
+
+            if next > end {
+                return Err(Error::TableOutOfBounds { table, offset: *offset });
+            }
+
             self.offset = *offset;
             *offset = next;
         }
+
+        Ok(())
     }
 }
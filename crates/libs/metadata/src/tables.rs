@@ -4,13 +4,13 @@ macro_rules! tables {
     ($(($name:ident, $table:literal))+) => {
         $(
         #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, Ord, PartialOrd)]
-        pub struct $name(pub Row);
-        impl AsRow for $name {
+        pub struct $name<'a>(pub Row<'a>);
+        impl<'a> AsRow<'a> for $name<'a> {
             const TABLE: usize = $table;
-            fn to_row(&self) -> Row {
+            fn to_row(&self) -> Row<'a> {
                 self.0
             }
-            fn from_row(row: Row) -> Self {
+            fn from_row(row: Row<'a>) -> Self {
                 $name(row)
             }
         }
@@ -23,43 +23,62 @@ tables! {
     (Attribute, 1)
     (ClassLayout, 16)
     (Constant, 0)
+    (Event, 17)
+    (EventMap, 18)
     (Field, 2)
+    (FieldMarshal, 19)
     (GenericParam, 3)
+    (GenericParamConstraint, 20)
     (ImplMap, 11)
     (InterfaceImpl, 4)
     (MemberRef, 5)
     (MethodDef, 6)
+    (MethodSemantics, 21)
     (Module, 14)
     (ModuleRef, 12)
     (NestedClass, 13)
     (Param, 7)
+    (Property, 22)
+    (PropertyMap, 23)
     (TypeDef, 8)
     (TypeRef, 9)
     (TypeSpec, 10)
 }
 
-impl Attribute {
-    pub fn parent(&self) -> HasAttribute {
+impl<'a> AssemblyRef<'a> {
+    pub fn name(&self) -> &'a str {
+        self.str(3)
+    }
+}
+
+impl<'a> Attribute<'a> {
+    pub fn parent(&self) -> HasAttribute<'a> {
         self.decode(0)
     }
 
-    pub fn ty(&self) -> AttributeType {
+    pub fn ty(&self) -> AttributeType<'a> {
         self.decode(1)
     }
 
-    pub fn name(&self) -> &'static str {
+    pub fn name(&self) -> &'a str {
         let AttributeType::MemberRef(ctor) = self.ty();
         let MemberRefParent::TypeRef(ty) = ctor.parent();
         ty.name()
     }
 
-    pub fn type_name(&self) -> TypeName {
+    pub fn type_name(&self) -> TypeName<'a> {
         let AttributeType::MemberRef(ctor) = self.ty();
         let MemberRefParent::TypeRef(ty) = ctor.parent();
         ty.type_name()
     }
 
-    pub fn args(&self) -> Vec<(&'static str, Value)> {
+    pub fn args(&self) -> Vec<(&'a str, Value<'a>)> {
+        self.kinded_args().into_iter().map(|(_, name, value)| (name, value)).collect()
+    }
+
+    /// Returns the arguments along with whether each is a constructor argument or a named field or property. Reading
+    /// stops at the first `Value::Invalid`, which is included as the last argument.
+    pub fn kinded_args(&self) -> Vec<(ArgKind, &'a str, Value<'a>)> {
         let AttributeType::MemberRef(member) = self.ty();
         let mut sig = member.blob(2);
         let mut values = self.blob(2);
@@ -71,48 +90,35 @@ impl Attribute {
         let reader = self.reader();
 
         for _ in 0..fixed_arg_count {
-            let arg = match reader.type_from_blob(&mut sig, None, &[]) {
-                Type::Bool => Value::Bool(values.read_bool()),
-                Type::I8 => Value::I8(values.read_i8()),
-                Type::U8 => Value::U8(values.read_u8()),
-                Type::I16 => Value::I16(values.read_i16()),
-                Type::U16 => Value::U16(values.read_u16()),
-                Type::I32 => Value::I32(values.read_i32()),
-                Type::U32 => Value::U32(values.read_u32()),
-                Type::I64 => Value::I64(values.read_i64()),
-                Type::U64 => Value::U64(values.read_u64()),
-                Type::String => Value::String(values.read_str().to_string()),
-                Type::Type => Value::TypeName(TypeName::parse(values.read_str())),
-                Type::TypeDef(def, _) => Value::EnumDef(def, Box::new(values.read_integer(def.underlying_type()))),
-                rest => unimplemented!("{rest:?}"),
-            };
+            let ty = reader.type_from_blob(&mut sig, None, &[]);
+            let value = values.read_fixed_arg(&ty);
+            let invalid = matches!(value, Value::Invalid);
+            args.push((ArgKind::Fixed, "", value));
 
-            args.push(("", arg));
+            if invalid {
+                return args;
+            }
         }
 
         let named_arg_count = values.read_u16();
         args.reserve(named_arg_count as usize);
 
         for _ in 0..named_arg_count {
-            let _id = values.read_u8();
-            let arg_type = values.read_u8();
-            let mut name = values.read_str();
-            let arg = match arg_type {
-                ELEMENT_TYPE_BOOLEAN => Value::Bool(values.read_bool()),
-                ELEMENT_TYPE_I2 => Value::I16(values.read_i16()),
-                ELEMENT_TYPE_I4 => Value::I32(values.read_i32()),
-                ELEMENT_TYPE_U4 => Value::U32(values.read_u32()),
-                ELEMENT_TYPE_STRING => Value::String(values.read_str().to_string()),
-                0x50 => Value::TypeName(TypeName::parse(values.read_str())),
-                0x55 => {
-                    let type_name = TypeName::parse(name);
-                    let def = reader.get_type_def(type_name.namespace, type_name.name).next().expect("Type not found");
-                    name = values.read_str();
-                    Value::EnumDef(def, Box::new(values.read_integer(def.underlying_type())))
-                }
-                rest => unimplemented!("{rest:?}"),
+            let kind = if values.read_u8() == SERIALIZATION_TYPE_FIELD { ArgKind::Field } else { ArgKind::Property };
+
+            let Some(ty) = values.read_field_or_prop_type() else {
+                args.push((kind, "", Value::Invalid));
+                return args;
             };
-            args.push((name, arg));
+
+            let name = values.read_str();
+            let value = values.read_fixed_arg(&ty);
+            let invalid = matches!(value, Value::Invalid);
+            args.push((kind, name, value));
+
+            if invalid {
+                return args;
+            }
         }
 
         debug_assert_eq!(sig.slice.len(), 0);
@@ -122,18 +128,22 @@ impl Attribute {
     }
 }
 
-impl ClassLayout {
+impl<'a> ClassLayout<'a> {
     pub fn packing_size(&self) -> usize {
         self.usize(0)
     }
+
+    pub fn class_size(&self) -> usize {
+        self.usize(1)
+    }
 }
 
-impl Constant {
-    pub fn ty(&self) -> Type {
+impl<'a> Constant<'a> {
+    pub fn ty(&self) -> Type<'a> {
         Type::from_code(self.usize(0)).expect("Constant type not found")
     }
 
-    pub fn value(&self) -> Value {
+    pub fn value(&self) -> Value<'a> {
         let mut blob = self.blob(2);
 
         match self.ty() {
@@ -153,21 +163,65 @@ impl Constant {
     }
 }
 
-impl Field {
+impl<'a> Event<'a> {
+    pub fn flags(&self) -> EventAttributes {
+        EventAttributes(self.usize(0) as u16)
+    }
+
+    pub fn name(&self) -> &'a str {
+        self.str(1)
+    }
+
+    pub fn ty(&self, generics: &[Type<'a>]) -> Type<'a> {
+        self.reader().type_from_ref(self.decode(2), None, generics)
+    }
+
+    pub fn methods(&self) -> RowIterator<'a, MethodSemantics<'a>> {
+        self.equal_range(2, HasSemantics::Event(*self).encode())
+    }
+
+    pub fn add_method(&self) -> Option<MethodDef<'a>> {
+        self.methods().find(|semantics| semantics.flags().contains(MethodSemanticsAttributes::AddOn)).map(|semantics| semantics.method())
+    }
+
+    pub fn remove_method(&self) -> Option<MethodDef<'a>> {
+        self.methods().find(|semantics| semantics.flags().contains(MethodSemanticsAttributes::RemoveOn)).map(|semantics| semantics.method())
+    }
+}
+
+impl<'a> EventMap<'a> {
+    pub fn parent(&self) -> TypeDef<'a> {
+        self.row(0)
+    }
+
+    pub fn events(&self) -> RowIterator<'a, Event<'a>> {
+        self.list(1)
+    }
+}
+
+impl<'a> Field<'a> {
     pub fn flags(&self) -> FieldAttributes {
         FieldAttributes(self.usize(0) as u16)
     }
 
-    pub fn name(&self) -> &'static str {
+    pub fn name(&self) -> &'a str {
         self.str(1)
     }
 
-    pub fn constant(&self) -> Option<Constant> {
+    pub fn constant(&self) -> Option<Constant<'a>> {
         self.equal_range(1, HasConstant::Field(*self).encode()).next()
     }
 
+    pub fn parent(&self) -> TypeDef<'a> {
+        self.list_parent(4)
+    }
+
+    pub fn marshal(&self) -> Option<FieldMarshal<'a>> {
+        self.equal_range(0, HasFieldMarshal::Field(*self).encode()).next()
+    }
+
     // TODO: enclosing craziness is only needed for nested structs - get rid of those in riddle and this goes away.
-    pub fn ty(&self, enclosing: Option<TypeDef>) -> Type {
+    pub fn ty(&self, enclosing: Option<TypeDef<'a>>) -> Type<'a> {
         let mut blob = self.blob(2);
         blob.read_usize();
         blob.read_modifiers();
@@ -181,47 +235,71 @@ impl Field {
     }
 }
 
-impl GenericParam {
+impl<'a> FieldMarshal<'a> {
+    pub fn parent(&self) -> HasFieldMarshal<'a> {
+        self.decode(0)
+    }
+
+    pub fn signature(&self) -> Blob<'a> {
+        self.blob(1)
+    }
+}
+
+impl<'a> GenericParam<'a> {
     pub fn number(&self) -> u16 {
         self.usize(0) as u16
     }
 
-    pub fn name(&self) -> &'static str {
+    pub fn name(&self) -> &'a str {
         self.str(3)
     }
+
+    pub fn constraints(&self) -> RowIterator<'a, GenericParamConstraint<'a>> {
+        self.equal_range(0, self.index() + 1)
+    }
+}
+
+impl<'a> GenericParamConstraint<'a> {
+    pub fn owner(&self) -> GenericParam<'a> {
+        self.row(0)
+    }
+
+    pub fn ty(&self, generics: &[Type<'a>]) -> Type<'a> {
+        self.reader().type_from_ref(self.decode(1), None, generics)
+    }
 }
 
-impl ImplMap {
+impl<'a> ImplMap<'a> {
     pub fn flags(&self) -> PInvokeAttributes {
         PInvokeAttributes(self.usize(0))
     }
 
-    pub fn scope(&self) -> ModuleRef {
-        ModuleRef(self.row(3))
+    pub fn scope(&self) -> ModuleRef<'a> {
+        self.row(3)
     }
 
-    pub fn import_name(&self) -> &'static str {
+    pub fn import_name(&self) -> &'a str {
         self.str(2)
     }
 }
 
-impl InterfaceImpl {
-    pub fn ty(&self, generics: &[Type]) -> Type {
+impl<'a> InterfaceImpl<'a> {
+    pub fn ty(&self, generics: &[Type<'a>]) -> Type<'a> {
         self.reader().type_from_ref(self.decode(1), None, generics)
     }
 }
 
-impl MemberRef {
-    pub fn parent(&self) -> MemberRefParent {
+impl<'a> MemberRef<'a> {
+    pub fn parent(&self) -> MemberRefParent<'a> {
         self.decode(0)
     }
 
-    pub fn name(&self) -> &'static str {
+    pub fn name(&self) -> &'a str {
         self.str(1)
     }
 }
 
-impl MethodDef {
+impl<'a> MethodDef<'a> {
     pub fn impl_flags(&self) -> MethodImplAttributes {
         MethodImplAttributes(self.usize(1) as u16)
     }
@@ -230,15 +308,19 @@ impl MethodDef {
         MethodAttributes(self.usize(2) as u16)
     }
 
-    pub fn name(&self) -> &'static str {
+    pub fn name(&self) -> &'a str {
         self.str(3)
     }
 
-    pub fn params(&self) -> RowIterator<Param> {
+    pub fn params(&self) -> RowIterator<'a, Param<'a>> {
         self.list(5)
     }
 
-    pub fn impl_map(&self) -> Option<ImplMap> {
+    pub fn parent(&self) -> TypeDef<'a> {
+        self.list_parent(5)
+    }
+
+    pub fn impl_map(&self) -> Option<ImplMap<'a>> {
         self.equal_range(1, MemberForwarded::MethodDef(*self).encode()).next()
     }
 
@@ -251,7 +333,7 @@ impl MethodDef {
         impl_map.scope().name().to_lowercase()
     }
 
-    pub fn signature(&self, generics: &[Type]) -> MethodDefSig {
+    pub fn signature(&self, generics: &[Type<'a>]) -> MethodDefSig<'a> {
         let reader = self.reader();
         let mut blob = self.blob(4);
         let call_flags = MethodCallAttributes(blob.read_usize() as u8);
@@ -262,23 +344,37 @@ impl MethodDef {
     }
 }
 
-impl ModuleRef {
-    pub fn name(&self) -> &'static str {
+impl<'a> MethodSemantics<'a> {
+    pub fn flags(&self) -> MethodSemanticsAttributes {
+        MethodSemanticsAttributes(self.usize(0) as u16)
+    }
+
+    pub fn method(&self) -> MethodDef<'a> {
+        self.row(1)
+    }
+
+    pub fn association(&self) -> HasSemantics<'a> {
+        self.decode(2)
+    }
+}
+
+impl<'a> ModuleRef<'a> {
+    pub fn name(&self) -> &'a str {
         self.str(0)
     }
 }
 
-impl NestedClass {
-    pub fn inner(&self) -> TypeDef {
-        TypeDef(self.row(0))
+impl<'a> NestedClass<'a> {
+    pub fn inner(&self) -> TypeDef<'a> {
+        self.row(0)
     }
 
-    pub fn outer(&self) -> TypeDef {
-        TypeDef(self.row(1))
+    pub fn outer(&self) -> TypeDef<'a> {
+        self.row(1)
     }
 }
 
-impl Param {
+impl<'a> Param<'a> {
     pub fn flags(&self) -> ParamAttributes {
         ParamAttributes(self.usize(0) as u16)
     }
@@ -287,29 +383,72 @@ impl Param {
         self.usize(1) as u16
     }
 
-    pub fn name(&self) -> &'static str {
+    pub fn name(&self) -> &'a str {
         self.str(2)
     }
+
+    pub fn marshal(&self) -> Option<FieldMarshal<'a>> {
+        self.equal_range(0, HasFieldMarshal::Param(*self).encode()).next()
+    }
 }
 
-impl TypeDef {
+impl<'a> Property<'a> {
+    pub fn flags(&self) -> PropertyAttributes {
+        PropertyAttributes(self.usize(0) as u16)
+    }
+
+    pub fn name(&self) -> &'a str {
+        self.str(1)
+    }
+
+    pub fn ty(&self, generics: &[Type<'a>]) -> Type<'a> {
+        let mut blob = self.blob(2);
+        blob.read_usize(); // PROPERTY | HASTHIS
+        blob.read_usize(); // ParamCount, whose parameters follow the property's type so don't need to be read
+        self.reader().type_from_blob(&mut blob, None, generics)
+    }
+
+    pub fn methods(&self) -> RowIterator<'a, MethodSemantics<'a>> {
+        self.equal_range(2, HasSemantics::Property(*self).encode())
+    }
+
+    pub fn getter(&self) -> Option<MethodDef<'a>> {
+        self.methods().find(|semantics| semantics.flags().contains(MethodSemanticsAttributes::Getter)).map(|semantics| semantics.method())
+    }
+
+    pub fn setter(&self) -> Option<MethodDef<'a>> {
+        self.methods().find(|semantics| semantics.flags().contains(MethodSemanticsAttributes::Setter)).map(|semantics| semantics.method())
+    }
+}
+
+impl<'a> PropertyMap<'a> {
+    pub fn parent(&self) -> TypeDef<'a> {
+        self.row(0)
+    }
+
+    pub fn properties(&self) -> RowIterator<'a, Property<'a>> {
+        self.list(1)
+    }
+}
+
+impl<'a> TypeDef<'a> {
     pub fn flags(&self) -> TypeAttributes {
         TypeAttributes(self.usize(0) as u32)
     }
 
-    pub fn name(&self) -> &'static str {
+    pub fn name(&self) -> &'a str {
         trim_tick(self.str(1))
     }
 
-    pub fn namespace(&self) -> &'static str {
+    pub fn namespace(&self) -> &'a str {
         self.str(2)
     }
 
-    pub fn type_name(&self) -> TypeName {
+    pub fn type_name(&self) -> TypeName<'a> {
         TypeName::new(self.namespace(), self.name())
     }
 
-    pub fn extends(&self) -> Option<TypeName> {
+    pub fn extends(&self) -> Option<TypeName<'a>> {
         let extends = self.usize(3);
 
         if extends == 0 {
@@ -319,31 +458,46 @@ impl TypeDef {
         Some(TypeDefOrRef::decode(self.file(), extends).type_name())
     }
 
-    pub fn methods(&self) -> RowIterator<MethodDef> {
+    pub fn methods(&self) -> RowIterator<'a, MethodDef<'a>> {
         self.list(5)
     }
 
-    pub fn fields(&self) -> RowIterator<Field> {
+    pub fn fields(&self) -> RowIterator<'a, Field<'a>> {
         self.list(4)
     }
 
-    pub fn generics(&self) -> RowIterator<GenericParam> {
+    pub fn properties(&self) -> RowIterator<'a, Property<'a>> {
+        // Unlike most tables, the PropertyMap and EventMap tables aren't required to be sorted by their parent.
+        match self.file().table::<PropertyMap<'_>>().find(|map| map.usize(0) == self.index() + 1) {
+            Some(map) => map.properties(),
+            None => RowIterator::new(self.file(), 0..0),
+        }
+    }
+
+    pub fn events(&self) -> RowIterator<'a, Event<'a>> {
+        match self.file().table::<EventMap<'_>>().find(|map| map.usize(0) == self.index() + 1) {
+            Some(map) => map.events(),
+            None => RowIterator::new(self.file(), 0..0),
+        }
+    }
+
+    pub fn generics(&self) -> RowIterator<'a, GenericParam<'a>> {
         self.equal_range(2, TypeOrMethodDef::TypeDef(*self).encode())
     }
 
-    pub fn interface_impls(&self) -> RowIterator<InterfaceImpl> {
+    pub fn interface_impls(&self) -> RowIterator<'a, InterfaceImpl<'a>> {
         self.equal_range(0, self.index() + 1)
     }
 
-    pub fn enclosing_type(&self) -> Option<TypeDef> {
-        self.equal_range::<NestedClass>(0, self.index() + 1).next().map(|row| TypeDef(row.row(1)))
+    pub fn enclosing_type(&self) -> Option<TypeDef<'a>> {
+        self.equal_range::<NestedClass<'_>>(0, self.index() + 1).next().map(|row| row.row(1))
     }
 
-    pub fn class_layout(&self) -> Option<ClassLayout> {
+    pub fn class_layout(&self) -> Option<ClassLayout<'a>> {
         self.equal_range(2, self.index() + 1).next()
     }
 
-    pub fn underlying_type(&self) -> Type {
+    pub fn underlying_type(&self) -> Type<'a> {
         let field = self.fields().next().expect("Field not found");
         if let Some(constant) = field.constant() {
             constant.ty()
@@ -393,20 +547,20 @@ impl TypeDef {
     }
 }
 
-impl TypeRef {
-    pub fn name(&self) -> &'static str {
+impl<'a> TypeRef<'a> {
+    pub fn name(&self) -> &'a str {
         trim_tick(self.str(1))
     }
 
-    pub fn namespace(&self) -> &'static str {
+    pub fn namespace(&self) -> &'a str {
         self.str(2)
     }
 
-    pub fn type_name(&self) -> TypeName {
+    pub fn type_name(&self) -> TypeName<'a> {
         TypeName::new(self.namespace(), self.name())
     }
 
-    pub fn resolution_scope(&self) -> ResolutionScope {
+    pub fn resolution_scope(&self) -> ResolutionScope<'a> {
         self.decode(0)
     }
 }
@@ -1,7 +1,7 @@
 use super::*;
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq, Ord, PartialOrd)]
-pub enum Type {
+pub enum Type<'a> {
     // Primitives in ECMA-335
     Void,
     Bool,
@@ -26,9 +26,9 @@ pub enum Type {
     Type,         // System.Type is needed since WinRT attribute use this as a parameter type.
 
     // Regular ECMA-335 types that map to metadata
-    TypeRef(TypeName),
-    GenericParam(GenericParam),
-    TypeDef(TypeDef, Vec<Self>),
+    TypeRef(TypeName<'a>),
+    GenericParam(GenericParam<'a>),
+    TypeDef(TypeDef<'a>, Vec<Self>),
 
     // Qualified types
     MutPtr(Box<Self>, usize),
@@ -53,7 +53,7 @@ pub enum Type {
     PROPVARIANT,
 }
 
-impl Type {
+impl Type<'_> {
     /// Creates a `Type` object from an `ELEMENT_TYPE` (see ECMA-335) type constant, typically
     /// used to indicate the type of a constant or primitive type signature.
     pub fn from_code(code: usize) -> Option<Self> {
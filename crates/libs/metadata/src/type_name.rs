@@ -1,12 +1,12 @@
 #![allow(non_upper_case_globals)]
 
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, Ord, PartialOrd)]
-pub struct TypeName {
-    pub namespace: &'static str,
-    pub name: &'static str,
+pub struct TypeName<'a> {
+    pub namespace: &'a str,
+    pub name: &'a str,
 }
 
-impl TypeName {
+impl<'a> TypeName<'a> {
     pub const Enum: Self = Self::from_const("System", "Enum");
     pub const Delegate: Self = Self::from_const("System", "MulticastDelegate");
     pub const Struct: Self = Self::from_const("System", "ValueType");
@@ -50,21 +50,46 @@ impl TypeName {
     pub const VARIANT: Self = Self::from_const("Windows.Win32.System.Variant", "VARIANT");
     pub const PROPVARIANT: Self = Self::from_const("Windows.Win32.System.Com.StructuredStorage", "PROPVARIANT");
 
-    const fn from_const(namespace: &'static str, name: &'static str) -> Self {
+    const fn from_const(namespace: &'a str, name: &'a str) -> Self {
         Self { namespace, name }
     }
 
-    pub fn new(namespace: &'static str, name: &'static str) -> Self {
+    pub fn new(namespace: &'a str, name: &'a str) -> Self {
         Self { namespace, name }
     }
 
-    pub fn parse(full_name: &'static str) -> Self {
+    pub fn parse(full_name: &'a str) -> Self {
         let index = full_name.rfind('.').expect("Expected full name separated with `.`");
         Self::new(&full_name[0..index], &full_name[index + 1..])
     }
+
+    /// Parses a type name as serialized in a custom attribute blob. Such names may be assembly-qualified and may refer
+    /// to nested types, as in `Namespace.Outer+Inner, Assembly`, in which case the name is `Outer+Inner`.
+    pub fn parse_serialized(full_name: &'a str) -> Self {
+        let mut depth = 0;
+        let end = full_name
+            .char_indices()
+            .find(|(_, c)| {
+                match c {
+                    '[' => depth += 1,
+                    ']' => depth -= 1,
+                    _ => {}
+                }
+                *c == ',' && depth == 0
+            })
+            .map_or(full_name.len(), |(index, _)| index);
+
+        let full_name = full_name[..end].trim();
+        let outer = full_name.find('+').unwrap_or(full_name.len());
+
+        match full_name[..outer].rfind('.') {
+            Some(index) => Self::new(&full_name[..index], &full_name[index + 1..]),
+            None => Self::new("", full_name),
+        }
+    }
 }
 
-impl std::fmt::Display for TypeName {
+impl std::fmt::Display for TypeName<'_> {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(fmt, "{}.{}", self.namespace, self.name)
     }
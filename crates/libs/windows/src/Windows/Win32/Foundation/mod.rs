@@ -11538,6 +11538,7 @@ impl BOOL {
         self.0 != 0
     }
     #[inline]
+    #[track_caller]
     pub fn ok(self) -> ::windows_core::Result<()> {
         if self.as_bool() {
             Ok(())
@@ -11611,6 +11612,7 @@ impl BOOLEAN {
         self.0 != 0
     }
     #[inline]
+    #[track_caller]
     pub fn ok(self) -> ::windows_core::Result<()> {
         if self.as_bool() {
             Ok(())
@@ -11692,6 +11694,7 @@ impl NTSTATUS {
         ::windows_core::HRESULT(self.0 | 0x1000_0000)
     }
     #[inline]
+    #[track_caller]
     pub fn ok(self) -> ::windows_core::Result<()> {
         if self.is_ok() {
             Ok(())
@@ -11716,6 +11719,7 @@ impl VARIANT_BOOL {
         self.0 != 0
     }
     #[inline]
+    #[track_caller]
     pub fn ok(self) -> ::windows_core::Result<()> {
         if self.as_bool() {
             Ok(())
@@ -11801,6 +11805,7 @@ impl WIN32_ERROR {
         }
     }
     #[inline]
+    #[track_caller]
     pub fn ok(self) -> ::windows_core::Result<()> {
         if self.is_ok() {
             Ok(())
@@ -11815,6 +11820,7 @@ impl ::core::convert::From<WIN32_ERROR> for ::windows_core::HRESULT {
     }
 }
 impl ::core::convert::From<WIN32_ERROR> for ::windows_core::Error {
+    #[track_caller]
     fn from(value: WIN32_ERROR) -> Self {
         value.to_hresult().into()
     }
@@ -1,8 +1,9 @@
 mod bindings;
 use std::sync::*;
-use windows::{core::*, Foundation::*, Win32::Foundation::*, Win32::System::WinRT::*};
+use windows::{core::*, Foundation::*, Win32::Foundation::*};
 
-#[implement(bindings::Class)]
+#[implement(bindings::Class, factory)]
+#[derive(Default)]
 struct Class(RwLock<i32>);
 
 impl bindings::IClass_Impl for Class {
@@ -59,30 +60,6 @@ impl bindings::IClass_Impl for Class {
     }
 }
 
-#[implement(IActivationFactory)]
-struct ClassFactory;
-
-impl IActivationFactory_Impl for ClassFactory {
-    fn ActivateInstance(&self) -> Result<IInspectable> {
-        Ok(Class(RwLock::new(0)).into())
-    }
-}
-
-#[no_mangle]
-unsafe extern "system" fn DllGetActivationFactory(
-    name: std::mem::ManuallyDrop<HSTRING>,
-    result: *mut *mut std::ffi::c_void,
-) -> HRESULT {
-    let factory: Option<IActivationFactory> = match (*name).to_string().as_str() {
-        "test_component.Class" => Some(ClassFactory.into()),
-        _ => None,
-    };
-
-    if let Some(factory) = factory {
-        *result = std::mem::transmute(factory);
-        S_OK
-    } else {
-        *result = std::ptr::null_mut();
-        CLASS_E_CLASSNOTAVAILABLE
-    }
+windows::core::component! {
+    "test_component.Class" => Class,
 }
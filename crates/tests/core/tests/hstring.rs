@@ -266,3 +266,101 @@ mod sys {
         pub data: isize,
     }
 }
+
+#[test]
+fn hstring_builder() -> Result<()> {
+    let builder = HStringBuilder::new();
+    assert!(builder.is_empty());
+    assert_eq!(builder.capacity(), 0);
+    assert!(HSTRING::from(builder).is_empty());
+
+    let mut builder = HStringBuilder::with_capacity(5)?;
+    assert!(builder.capacity() >= 5);
+    builder.push_str("Hello")?;
+    builder.push(' ')?;
+    builder.push_wide(&[0x0057, 0x006f])?;
+    builder.extend("rld".chars());
+    assert_eq!(builder.len(), 11);
+    assert_eq!(builder.as_wide()[6], 0x0057);
+
+    let value = HSTRING::from(builder);
+    assert_eq!(value, "Hello World");
+    assert_eq!(value.len(), 11);
+    assert_eq!(unsafe { *value.as_ptr().add(11) }, 0);
+
+    let mut builder = HStringBuilder::new();
+    use std::fmt::Write;
+    write!(builder, "{} + {} = {}", 1, 2, 1 + 2).unwrap();
+    builder.as_wide_mut()[0] = b'3' as u16;
+    assert_eq!(HSTRING::from(builder), "3 + 2 = 3");
+
+    // Capacity that can't be represented is reported rather than panicking.
+    let mut builder = HStringBuilder::new();
+    builder.push('a')?;
+    assert_eq!(
+        builder.reserve(usize::MAX).unwrap_err().code(),
+        windows::Win32::Foundation::E_OUTOFMEMORY
+    );
+    assert_eq!(HSTRING::from(builder), "a");
+
+    Ok(())
+}
+
+#[test]
+fn hstring_iter() {
+    let value: HSTRING = "Hello".encode_utf16().collect();
+    assert_eq!(value, "Hello");
+
+    let value: HSTRING = "α & ω".chars().rev().collect();
+    assert_eq!(value, "ω & α");
+
+    let value: HSTRING = std::iter::empty::<char>().collect();
+    assert!(value.is_empty());
+}
+
+#[test]
+fn hstring_concat() -> Result<()> {
+    let hello = HSTRING::from("Hello");
+    let world = HSTRING::from("World");
+
+    assert_eq!(&hello + &world, "HelloWorld");
+    assert_eq!(hello.clone() + " " + &world, "Hello World");
+    assert_eq!(HSTRING::concat(&[&hello, h!(" "), &world])?, "Hello World");
+    assert!(HSTRING::concat(&[])?.is_empty());
+    assert_eq!(HSTRING::new() + "", "");
+
+    Ok(())
+}
+
+#[test]
+fn hstring_ref() -> Result<()> {
+    let wide: Vec<u16> = "Hello\0".encode_utf16().collect();
+    let value = HStringRef::from_wide(&wide)?;
+    assert_eq!(value.len(), 5);
+    assert_eq!(*value, "Hello");
+    assert_eq!(value.as_ptr(), wide.as_ptr());
+    assert_eq!(format!("{value:?}"), r#""Hello""#);
+
+    // Cloning a reference string creates a copy that owns its own buffer.
+    let owned: HSTRING = value.clone();
+    assert_ne!(owned.as_ptr(), wide.as_ptr());
+    assert_eq!(owned, "Hello");
+    assert_eq!(owned, *value);
+
+    unsafe {
+        let param = (&value).into_param();
+        let abi = param.abi();
+        let borrowed: &HSTRING = &*abi.as_ptr();
+        assert_eq!(borrowed.as_ptr(), wide.as_ptr());
+        assert_eq!(borrowed, "Hello");
+    }
+
+    assert!(HStringRef::from_wide(&[0])?.is_empty());
+    assert_eq!(
+        HStringRef::from_wide(&[72, 105]).unwrap_err().code(),
+        windows::Win32::Foundation::E_INVALIDARG
+    );
+    assert!(HStringRef::from_wide(&[]).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+#![allow(non_snake_case)]
+
+use wincore::*;
+use windows_implement::implement;
+use windows_interface::interface;
+
+#[interface("8b0a7e4c-3f1d-4a52-9e6b-0c2d5f7a1b93")]
+unsafe trait IValue: IUnknown {
+    unsafe fn GetValue(&self) -> i32;
+}
+
+#[interface("2c9f4d61-7a3e-4b08-b5d2-e1f0a6c83d47")]
+unsafe trait IScaled: IUnknown {
+    unsafe fn GetScaled(&self, factor: i32) -> i32;
+}
+
+#[implement(IValue, IScaled)]
+struct Value(i32);
+
+impl IValue_Impl for Value {
+    unsafe fn GetValue(&self) -> i32 {
+        self.0
+    }
+}
+
+impl IScaled_Impl for Value {
+    unsafe fn GetScaled(&self, factor: i32) -> i32 {
+        self.0 * factor
+    }
+}
+
+#[test]
+fn test() -> Result<()> {
+    unsafe {
+        let scaled: IScaled = Value(123).into();
+        assert_eq!(scaled.GetScaled(2), 246);
+
+        let value: IValue = scaled.cast()?;
+        assert_eq!(value.GetValue(), 123);
+        assert_eq!(value.cast::<IUnknown>()?, scaled.cast::<IUnknown>()?);
+
+        Ok(())
+    }
+}
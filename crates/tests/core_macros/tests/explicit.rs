@@ -0,0 +1,36 @@
+#![allow(non_snake_case)]
+
+use windows_implement::implement;
+use windows_interface::interface;
+
+// Simulates a crate that re-exports `windows-core` and does not want its users to depend on it directly.
+mod reexport {
+    pub use wincore as core;
+}
+
+use reexport::core::{IUnknown, IUnknown_Vtbl, Interface};
+
+#[interface("5e3b1a97-c8d4-4f26-a07b-93d1e2f4c6a5", crate = reexport::core)]
+unsafe trait IValue: IUnknown {
+    unsafe fn GetValue(&self) -> i32;
+}
+
+#[implement(IValue, crate = reexport::core)]
+struct Value(i32);
+
+impl IValue_Impl for Value {
+    unsafe fn GetValue(&self) -> i32 {
+        self.0
+    }
+}
+
+#[test]
+fn test() -> reexport::core::Result<()> {
+    unsafe {
+        let value: IValue = Value(123).into();
+        assert_eq!(value.GetValue(), 123);
+        assert_eq!(value.cast::<IValue>()?.GetValue(), 123);
+
+        Ok(())
+    }
+}
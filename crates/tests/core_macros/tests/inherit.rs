@@ -0,0 +1,120 @@
+#![allow(non_snake_case)]
+
+use wincore::*;
+use windows_implement::implement;
+use windows_interface::interface;
+
+mod base {
+    use wincore::*;
+    use windows_interface::interface;
+
+    #[interface("5f3e2a1b-8c4d-4e6f-9a0b-1c2d3e4f5a6b")]
+    pub unsafe trait IShape: IUnknown {
+        pub unsafe fn Sides(&self) -> u32;
+    }
+}
+
+// The parent interface is defined in another module.
+#[interface("7a8b9c0d-1e2f-4a3b-8c4d-5e6f7a8b9c0d")]
+unsafe trait IPolygon: base::IShape {
+    unsafe fn Area(&self, #[retval] area: *mut f64) -> HRESULT;
+}
+
+#[interface("0d1e2f3a-4b5c-4d6e-9f0a-1b2c3d4e5f6a")]
+unsafe trait ISquare: IPolygon {
+    unsafe fn Compare(&self, other: Option<&IPolygon>, #[retval] larger: *mut bool) -> HRESULT;
+}
+
+#[interface("3c4d5e6f-7a8b-4c9d-8e0f-1a2b3c4d5e6f")]
+unsafe trait INamed: IInspectable {
+    unsafe fn Name(&self, #[retval] name: *mut u32) -> HRESULT;
+}
+
+#[implement(ISquare, INamed)]
+struct Square(f64);
+
+impl base::IShape_Impl for Square {
+    unsafe fn Sides(&self) -> u32 {
+        4
+    }
+}
+
+impl IPolygon_Impl for Square {
+    unsafe fn Area(&self, area: *mut f64) -> HRESULT {
+        *area = self.0 * self.0;
+        HRESULT(0)
+    }
+}
+
+impl ISquare_Impl for Square {
+    unsafe fn Compare(&self, other: Option<&IPolygon>, larger: *mut bool) -> HRESULT {
+        match other {
+            Some(other) => other
+                .Area()
+                .map(|area| *larger = self.0 * self.0 > area)
+                .into(),
+            None => HRESULT(-2147467261), // E_POINTER
+        }
+    }
+}
+
+impl INamed_Impl for Square {
+    unsafe fn Name(&self, name: *mut u32) -> HRESULT {
+        *name = 42;
+        HRESULT(0)
+    }
+}
+
+#[test]
+fn inherited() -> Result<()> {
+    unsafe {
+        let square: ISquare = Square(3.0).into();
+
+        // Methods of the parent and grandparent interfaces are callable on the derived interface.
+        assert_eq!(square.Sides(), 4);
+        assert_eq!(square.Area()?, 9.0);
+
+        // The derived interface converts to its parent without a query, and the object answers queries for its ancestors.
+        let polygon: IPolygon = square.clone().into();
+        assert_eq!(polygon.Area()?, 9.0);
+        assert_eq!(square.cast::<IPolygon>()?.Sides(), 4);
+        assert_eq!(square.cast::<base::IShape>()?.Sides(), 4);
+        assert_eq!(polygon.cast::<ISquare>()?, square);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn params() -> Result<()> {
+    unsafe {
+        let small: ISquare = Square(2.0).into();
+        let large: ISquare = Square(5.0).into();
+
+        // Interface parameters accept derived interfaces as well as `None`.
+        assert!(large.Compare(&small)?);
+        assert!(!small.Compare(&large)?);
+        assert_eq!(
+            small.Compare(None).unwrap_err().code(),
+            HRESULT(-2147467261)
+        );
+
+        Ok(())
+    }
+}
+
+#[test]
+fn inspectable() -> Result<()> {
+    unsafe {
+        let square: ISquare = Square(1.0).into();
+        let named: INamed = square.cast()?;
+        assert_eq!(named.Name()?, 42);
+
+        // The methods of `IInspectable` are available on interfaces derived from it.
+        let inspectable: IInspectable = named.clone().into();
+        assert_eq!(inspectable.GetRuntimeClassName()?, "");
+        assert_eq!(named.cast::<ISquare>()?, square);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use windows::{core::*, Win32::Foundation::*};
+
+#[test]
+fn location() {
+    let error = Error::from(E_INVALIDARG);
+    let location = error.location().unwrap();
+    assert_eq!(location.file(), file!());
+    assert_eq!(location.line(), line!() - 3);
+
+    let error = E_INVALIDARG.ok().unwrap_err();
+    assert_eq!(error.location().unwrap().line(), line!() - 1);
+
+    let error = ERROR_BAD_ARGUMENTS.ok().unwrap_err();
+    assert_eq!(error.location().unwrap().line(), line!() - 1);
+
+    assert!(format!("{error:?}").contains("location"));
+    assert_eq!(Error::OK.location(), None);
+}
+
+#[test]
+fn propagation() {
+    fn fail() -> HRESULT {
+        E_FAIL
+    }
+
+    fn call() -> Result<()> {
+        fail().ok()?;
+        Ok(())
+    }
+
+    let error = call().unwrap_err();
+    assert_eq!(error.location().unwrap().line(), line!() - 6);
+
+    // Equality only considers the error code and information.
+    assert_eq!(error, Error::from(E_FAIL));
+}
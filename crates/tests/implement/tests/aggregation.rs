@@ -0,0 +1,122 @@
+#![allow(non_snake_case)]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use windows::{
+    core::*, Foundation::*, Win32::Foundation::*, Win32::System::Com::*, Win32::System::WinRT::*,
+};
+
+static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+#[implement(IClosable, outer)]
+struct Inner;
+
+impl IClosable_Impl for Inner {
+    fn Close(&self) -> Result<()> {
+        Err(Error::new(E_ACCESSDENIED, "inner".into()))
+    }
+}
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        DROPPED.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[implement(IStringable, composable)]
+struct Outer;
+
+impl IStringable_Impl for Outer {
+    fn ToString(&self) -> Result<HSTRING> {
+        // The base object may be reached from within the derived object.
+        let closable: IClosable = unsafe { self.base()? };
+        Ok(closable.Close().unwrap_err().message())
+    }
+}
+
+impl Drop for Outer {
+    fn drop(&mut self) {
+        DROPPED.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+// An apartment-bound outer object, which forwards queries for `IAgileObject` to its base object along with the other
+// interfaces it doesn't implement.
+#[implement(IStringable, composable, Agile = false)]
+struct Forwarding;
+
+impl IStringable_Impl for Forwarding {
+    fn ToString(&self) -> Result<HSTRING> {
+        Ok("forwarding".into())
+    }
+}
+
+#[implement(IClosable, outer)]
+struct Forwarded;
+
+impl IClosable_Impl for Forwarded {
+    fn Close(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test() -> Result<()> {
+    let outer = Outer.compose(|outer| Ok(Inner.aggregate(&outer.cast()?)))?;
+
+    let stringable: IStringable = outer.cast()?;
+    assert_eq!(stringable.ToString()?, "inner");
+
+    // Interfaces of the base object share the identity of the derived object.
+    let closable: IClosable = outer.cast()?;
+    assert_eq!(closable.Close().unwrap_err().code(), E_ACCESSDENIED);
+    assert_eq!(closable.cast::<IUnknown>()?, outer.cast::<IUnknown>()?);
+    assert_eq!(closable.cast::<IStringable>()?, stringable);
+    assert!(closable.cast::<IMemoryBuffer>().is_err());
+
+    drop(outer);
+    drop(stringable);
+    assert_eq!(DROPPED.load(Ordering::Relaxed), 0);
+
+    // The base object holds the derived object alive through its delegating interfaces.
+    drop(closable);
+    assert_eq!(DROPPED.load(Ordering::Relaxed), 2);
+
+    Ok(())
+}
+
+#[test]
+fn forwarding() -> Result<()> {
+    let mut inner = None;
+
+    let outer = Forwarding.compose(|outer| {
+        let object = Forwarded.aggregate(&outer.cast()?);
+        inner = Some(object.clone());
+        Ok(object)
+    })?;
+
+    let inner = inner.unwrap();
+    let identity: IUnknown = outer.cast()?;
+
+    // Interfaces forwarded to the base object, other than those it implements itself, share the identity of the
+    // derived object.
+    let agile: IAgileObject = outer.cast()?;
+    assert_eq!(agile.cast::<IUnknown>()?, identity);
+    assert_eq!(agile.cast::<IStringable>()?.ToString()?, "forwarding");
+
+    // Only `IUnknown` is answered by the non-delegating identity of the base object itself.
+    let inspectable: IInspectable = inner.cast()?;
+    assert_ne!(inspectable, inner);
+    assert_eq!(inspectable.cast::<IUnknown>()?, identity);
+    assert_eq!(inner.cast::<IUnknown>()?.as_raw(), inner.as_raw());
+
+    // Weak references to the aggregate are provided by the derived object.
+    assert_eq!(
+        inner.cast::<IWeakReferenceSource>().unwrap_err().code(),
+        E_NOINTERFACE
+    );
+    let source: IWeakReferenceSource = agile.cast()?;
+    assert_eq!(source.cast::<IUnknown>()?, identity);
+
+    drop(inner);
+    Ok(())
+}
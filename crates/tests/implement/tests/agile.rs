@@ -0,0 +1,66 @@
+#![allow(non_snake_case)]
+
+use windows::{core::*, Foundation::*, Win32::System::Com::Marshal::*, Win32::System::Com::*};
+
+#[implement(IStringable)]
+struct Agile;
+
+impl IStringable_Impl for Agile {
+    fn ToString(&self) -> Result<HSTRING> {
+        Ok("Agile".into())
+    }
+}
+
+#[implement(IStringable, Agile = false)]
+struct Apartment;
+
+impl IStringable_Impl for Apartment {
+    fn ToString(&self) -> Result<HSTRING> {
+        Ok("Apartment".into())
+    }
+}
+
+// The free-threaded marshaler is provided by COM, which is only available on Windows.
+#[cfg(windows)]
+#[implement(IStringable, Agile = FreeThreaded)]
+struct FreeThreaded;
+
+#[cfg(windows)]
+impl IStringable_Impl for FreeThreaded {
+    fn ToString(&self) -> Result<HSTRING> {
+        Ok("FreeThreaded".into())
+    }
+}
+
+#[test]
+fn agile() -> Result<()> {
+    let object: IStringable = Agile.into();
+    object.cast::<IAgileObject>()?;
+    assert!(object.cast::<IMarshal>().is_err());
+
+    let object: IStringable = Apartment.into();
+    assert!(object.cast::<IAgileObject>().is_err());
+    assert!(object.cast::<IMarshal>().is_err());
+    assert_eq!(object.ToString()?, "Apartment");
+
+    Ok(())
+}
+
+#[cfg(windows)]
+#[test]
+fn free_threaded() -> Result<()> {
+    let object: IStringable = FreeThreaded.into();
+    object.cast::<IAgileObject>()?;
+
+    // The marshaler shares the object's identity and keeps it alive.
+    let marshal: IMarshal = object.cast()?;
+    assert_eq!(marshal.cast::<IUnknown>()?, object.cast::<IUnknown>()?);
+    assert_eq!(marshal.cast::<IStringable>()?, object);
+    assert_eq!(marshal.cast::<IMarshal>()?, marshal);
+    drop(object);
+
+    let object: IStringable = marshal.cast()?;
+    assert_eq!(object.ToString()?, "FreeThreaded");
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+#![allow(non_snake_case)]
+
+use windows::{
+    core::*, Foundation::*, Win32::Foundation::*, Win32::System::Com::*, Win32::System::WinRT::*,
+};
+
+#[implement(IStringable, factory)]
+#[derive(Default)]
+struct Class;
+
+impl IStringable_Impl for Class {
+    fn ToString(&self) -> Result<HSTRING> {
+        Ok("Class".into())
+    }
+}
+
+#[implement(IClosable, factory)]
+#[derive(Default)]
+struct Object;
+
+impl IClosable_Impl for Object {
+    fn Close(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+const CLSID_OBJECT: GUID = GUID::from_u128(0x1b4d7d14_b3c4_4c8b_9e6b_3d4f2a7c8e91);
+
+windows::core::component! {
+    "test_implement.Class" => Class,
+    CLSID_OBJECT => Object,
+}
+
+// The module lock count is shared by the tests, so they run one at a time.
+static SERIAL: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+#[test]
+fn activation_factory() -> Result<()> {
+    let _serial = SERIAL.lock().unwrap();
+
+    unsafe {
+        let mut factory = std::ptr::null_mut();
+        DllGetActivationFactory(
+            std::mem::ManuallyDrop::new(h!("test_implement.Class").clone()),
+            &mut factory,
+        )
+        .ok()?;
+        let factory = IActivationFactory::from_raw(factory);
+
+        let stringable: IStringable = factory.ActivateInstance()?.cast()?;
+        assert_eq!(stringable.ToString()?, "Class");
+        assert_eq!(
+            &factory.cast::<IInspectable>()?.GetIids()?[..],
+            [IActivationFactory::IID]
+        );
+
+        let mut factory = std::ptr::null_mut();
+        assert_eq!(
+            DllGetActivationFactory(
+                std::mem::ManuallyDrop::new(h!("test_implement.Object").clone()),
+                &mut factory
+            ),
+            CLASS_E_CLASSNOTAVAILABLE
+        );
+        assert!(factory.is_null());
+
+        Ok(())
+    }
+}
+
+#[test]
+fn class_object() -> Result<()> {
+    let _serial = SERIAL.lock().unwrap();
+
+    unsafe {
+        let mut factory = std::ptr::null_mut();
+        DllGetClassObject(&CLSID_OBJECT, &IClassFactory::IID, &mut factory).ok()?;
+        let factory = IClassFactory::from_raw(factory);
+        factory.LockServer(true)?;
+
+        let closable: IClosable = factory.CreateInstance(None)?;
+        closable.Close()?;
+        assert!(factory.CreateInstance::<_, IStringable>(None).is_err());
+        factory.LockServer(false)?;
+
+        let outer: IUnknown = Object.into();
+        assert_eq!(
+            factory
+                .CreateInstance::<_, IClosable>(&outer)
+                .unwrap_err()
+                .code(),
+            CLASS_E_NOAGGREGATION
+        );
+
+        let mut factory = std::ptr::null_mut();
+        assert_eq!(
+            DllGetClassObject(&IClassFactory::IID, &IClassFactory::IID, &mut factory),
+            CLASS_E_CLASSNOTAVAILABLE
+        );
+
+        Ok(())
+    }
+}
+
+#[test]
+fn can_unload_now() -> Result<()> {
+    let _serial = SERIAL.lock().unwrap();
+
+    unsafe {
+        assert_eq!(DllCanUnloadNow(), S_OK);
+
+        let mut factory = std::ptr::null_mut();
+        DllGetClassObject(&CLSID_OBJECT, &IClassFactory::IID, &mut factory).ok()?;
+        let factory = IClassFactory::from_raw(factory);
+        assert_eq!(DllCanUnloadNow(), S_FALSE);
+
+        // Objects keep the module loaded after their factory is released.
+        let closable: IClosable = factory.CreateInstance(None)?;
+        factory.LockServer(true)?;
+        drop(factory);
+        assert_eq!(DllCanUnloadNow(), S_FALSE);
+        drop(closable);
+        assert_eq!(DllCanUnloadNow(), S_FALSE);
+
+        let mut factory = std::ptr::null_mut();
+        DllGetClassObject(&CLSID_OBJECT, &IClassFactory::IID, &mut factory).ok()?;
+        let factory = IClassFactory::from_raw(factory);
+        factory.LockServer(false)?;
+        assert_eq!(factory.LockServer(false).unwrap_err().code(), E_UNEXPECTED);
+        drop(factory);
+        assert_eq!(DllCanUnloadNow(), S_OK);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+#![allow(non_snake_case)]
+
+use windows::{core::*, Foundation::*};
+
+#[implement(IStringable, TearOff(IClosable))]
+struct Object(i32);
+
+impl IStringable_Impl for Object {
+    fn ToString(&self) -> Result<HSTRING> {
+        Ok(self.0.to_string().into())
+    }
+}
+
+impl IClosable_Impl for Object {
+    fn Close(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[implement(IStringable)]
+struct Other;
+
+impl IStringable_Impl for Other {
+    fn ToString(&self) -> Result<HSTRING> {
+        Ok("other".into())
+    }
+}
+
+static STATIC: StaticComObject<Other> = Other.into_static();
+
+#[test]
+fn test() -> Result<()> {
+    let object: IUnknown = Object(123).into();
+    assert_eq!(ComObject::downcast::<Object>(&object).unwrap().0, 123);
+
+    // The type of the implementation is checked.
+    assert!(ComObject::downcast::<Other>(&object).is_none());
+    assert!(ComObject::downcast::<i32>(&object).is_none());
+
+    let other: IUnknown = Other.into();
+    assert!(ComObject::downcast::<Object>(&other).is_none());
+    assert!(ComObject::downcast::<Other>(&other).is_some());
+
+    // Any interface of the object may be used, including tear-offs.
+    let closable: IClosable = object.cast()?;
+    assert_eq!(
+        ComObject::downcast::<Object>(&closable.cast()?).unwrap().0,
+        123
+    );
+
+    // Static objects may also be downcast.
+    let unknown: IUnknown = STATIC.cast()?;
+    assert!(std::ptr::eq(
+        ComObject::downcast::<Other>(&unknown).unwrap(),
+        STATIC.get()
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+#![allow(non_snake_case)]
+
+use windows::core::*;
+use windows::Foundation::Collections::*;
+use windows::Foundation::*;
+
+#[implement(IStringable, IClosable, IIterable<i32>)]
+struct Object;
+
+impl IStringable_Impl for Object {
+    fn ToString(&self) -> Result<HSTRING> {
+        Ok("Object".into())
+    }
+}
+
+impl IClosable_Impl for Object {
+    fn Close(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl IIterable_Impl<i32> for Object {
+    fn First(&self) -> Result<IIterator<i32>> {
+        Err(Error::OK)
+    }
+}
+
+#[implement]
+struct Empty;
+
+#[test]
+fn test() -> Result<()> {
+    let object: IStringable = Object.into();
+
+    // Every interface returns the same list, regardless of the one used to call `GetIids`.
+    for inspectable in [
+        object.cast::<IInspectable>()?,
+        object.cast::<IClosable>()?.into(),
+        object.cast::<IIterable<i32>>()?.cast()?,
+    ] {
+        assert_eq!(
+            &inspectable.GetIids()?[..],
+            [IStringable::IID, IClosable::IID, IIterable::<i32>::IID]
+        );
+    }
+
+    let empty: IInspectable = Empty.into();
+    assert!(empty.GetIids()?.is_empty());
+
+    Ok(())
+}
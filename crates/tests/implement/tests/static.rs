@@ -0,0 +1,56 @@
+#![allow(non_snake_case)]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use windows::{core::*, Foundation::*, Win32::Foundation::*, Win32::System::WinRT::*};
+
+#[implement(IStringable, IClosable)]
+struct Object {
+    closed: AtomicUsize,
+}
+
+impl IStringable_Impl for Object {
+    fn ToString(&self) -> Result<HSTRING> {
+        Ok("static".into())
+    }
+}
+
+impl IClosable_Impl for Object {
+    fn Close(&self) -> Result<()> {
+        self.closed.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+static OBJECT: StaticComObject<Object> = Object {
+    closed: AtomicUsize::new(0),
+}
+.into_static();
+
+#[test]
+fn test() -> Result<()> {
+    let stringable: IStringable = OBJECT.cast()?;
+    assert_eq!(stringable.ToString()?, "static");
+
+    let closable: IClosable = stringable.cast()?;
+    closable.Close()?;
+    assert_eq!(OBJECT.closed.load(Ordering::Relaxed), 1);
+
+    // The object has a single identity however it is reached.
+    assert_eq!(closable.cast::<IUnknown>()?, OBJECT.cast::<IUnknown>()?);
+    assert_eq!(OBJECT.cast::<IStringable>()?, stringable);
+
+    // Reference counting does nothing, so releasing every reference leaves the object intact.
+    drop(stringable);
+    drop(closable);
+    let closable: IClosable = OBJECT.cast()?;
+    closable.Close()?;
+    assert_eq!(OBJECT.get().closed.load(Ordering::Relaxed), 2);
+
+    // Static objects can't go away, so they don't provide weak references.
+    assert_eq!(
+        closable.cast::<IWeakReferenceSource>().unwrap_err().code(),
+        E_NOINTERFACE
+    );
+
+    Ok(())
+}
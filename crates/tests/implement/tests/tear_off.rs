@@ -0,0 +1,71 @@
+#![allow(non_snake_case)]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use windows::{core::*, Foundation::*, Win32::Foundation::*};
+
+static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+#[implement(IStringable, TearOff(IClosable, IMemoryBuffer))]
+struct Object;
+
+impl IStringable_Impl for Object {
+    fn ToString(&self) -> Result<HSTRING> {
+        Ok("object".into())
+    }
+}
+
+impl IClosable_Impl for Object {
+    fn Close(&self) -> Result<()> {
+        Err(E_ACCESSDENIED.into())
+    }
+}
+
+impl IMemoryBuffer_Impl for Object {
+    fn CreateReference(&self) -> Result<IMemoryBufferReference> {
+        Err(E_NOTIMPL.into())
+    }
+}
+
+impl Drop for Object {
+    fn drop(&mut self) {
+        DROPPED.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn test() -> Result<()> {
+    let stringable: IStringable = Object.into();
+
+    // Tear-offs are allocated when queried for and call the object's implementation.
+    let closable: IClosable = stringable.cast()?;
+    assert_eq!(closable.Close().unwrap_err().code(), E_ACCESSDENIED);
+    assert_eq!(
+        closable
+            .cast::<IMemoryBuffer>()?
+            .CreateReference()
+            .unwrap_err()
+            .code(),
+        E_NOTIMPL
+    );
+
+    // Tear-offs share the identity of the object, which provides its other interfaces.
+    assert_eq!(closable.cast::<IUnknown>()?, stringable.cast::<IUnknown>()?);
+    assert_eq!(closable.cast::<IStringable>()?, stringable);
+    assert_eq!(closable.cast::<IStringable>()?.ToString()?, "object");
+    assert!(closable.cast::<IAsyncAction>().is_err());
+
+    // A tear-off answers queries for its own interface itself.
+    assert_eq!(closable.cast::<IClosable>()?, closable);
+
+    let iids = closable.cast::<IInspectable>()?.GetIids()?;
+    assert_eq!(iids.len(), 3);
+    assert_eq!(iids[1], IClosable::IID);
+
+    // The object lives as long as its tear-offs.
+    drop(stringable);
+    assert_eq!(DROPPED.load(Ordering::Relaxed), 0);
+    drop(closable);
+    assert_eq!(DROPPED.load(Ordering::Relaxed), 1);
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use metadata::*;
+
+const PATH: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../libs/bindgen/default/Windows.Wdk.winmd"
+);
+
+#[test]
+fn attribute_args() {
+    let reader = Reader::new(vec![File::new(std::fs::read(PATH).unwrap()).unwrap()]);
+
+    let def = reader
+        .get_type_def("Windows.Wdk.System.OfflineRegistry", "ORHKEY")
+        .next()
+        .unwrap();
+
+    let args = def.find_attribute("RAIIFreeAttribute").unwrap().args();
+    assert!(matches!(args.as_slice(), [("", Value::String(value))] if value == "ORCloseKey"));
+
+    // The `CallingConvention` enum isn't defined by any of the files so it is decoded as a reference.
+    let def = reader
+        .get_type_def(
+            "Windows.Wdk.Devices.HumanInterfaceDevice",
+            "EVT_VHF_CLEANUP",
+        )
+        .next()
+        .unwrap();
+
+    let args = def
+        .find_attribute("UnmanagedFunctionPointerAttribute")
+        .unwrap()
+        .args();
+
+    let [("", Value::EnumRef(type_name, value))] = args.as_slice() else {
+        panic!("Value not found");
+    };
+
+    assert_eq!(
+        *type_name,
+        TypeName::new("System.Runtime.InteropServices", "CallingConvention")
+    );
+    assert!(matches!(**value, Value::I32(1)));
+}
+
+#[test]
+fn parse_serialized() {
+    assert_eq!(
+        TypeName::parse_serialized("Windows.Foundation.Metadata.GCPressureAmount"),
+        TypeName::new("Windows.Foundation.Metadata", "GCPressureAmount")
+    );
+    assert_eq!(
+        TypeName::parse_serialized(
+            "System.Runtime.InteropServices.CallingConvention, mscorlib, Version=4.0.0.0"
+        ),
+        TypeName::new("System.Runtime.InteropServices", "CallingConvention")
+    );
+    assert_eq!(
+        TypeName::parse_serialized("Test.Outer+Inner"),
+        TypeName::new("Test", "Outer+Inner")
+    );
+    assert_eq!(
+        TypeName::parse_serialized("Global"),
+        TypeName::new("", "Global")
+    );
+}
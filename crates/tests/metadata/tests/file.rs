@@ -0,0 +1,109 @@
+use metadata::*;
+
+const WINMD: &[u8] = std::include_bytes!("../../../libs/bindgen/default/Windows.Wdk.winmd");
+
+#[test]
+fn valid() {
+    assert!(File::new(WINMD.to_vec()).is_ok());
+}
+
+#[test]
+fn signatures() {
+    assert_eq!(
+        File::new(vec![]).unwrap_err(),
+        Error::OutOfBounds { offset: 0, len: 64 }
+    );
+
+    let mut bytes = WINMD.to_vec();
+    bytes[0] = 0;
+    assert_eq!(File::new(bytes).unwrap_err(), Error::InvalidDosSignature);
+
+    let mut bytes = WINMD.to_vec();
+    let pe_offset = u32::from_le_bytes(bytes[0x3c..0x40].try_into().unwrap()) as usize;
+    bytes[pe_offset] = 0;
+    assert_eq!(File::new(bytes).unwrap_err(), Error::InvalidPeSignature);
+
+    // Moving the PE header to an odd offset leaves the file header that follows the signature misaligned.
+    let mut bytes = WINMD.to_vec();
+    bytes.copy_within(pe_offset..pe_offset + 24, pe_offset + 1);
+    bytes[0x3c..0x40].copy_from_slice(&(pe_offset as u32 + 1).to_le_bytes());
+    assert_eq!(
+        File::new(bytes).unwrap_err(),
+        Error::Misaligned {
+            offset: pe_offset + 5,
+            align: 4
+        }
+    );
+
+    let mut bytes = WINMD.to_vec();
+    bytes[pe_offset + 24] = 0;
+    assert!(matches!(
+        File::new(bytes).unwrap_err(),
+        Error::InvalidOptionalHeader(_)
+    ));
+
+    let metadata_offset = find(WINMD, b"BSJB");
+    let mut bytes = WINMD.to_vec();
+    bytes[metadata_offset] = 0;
+    assert_eq!(
+        File::new(bytes).unwrap_err(),
+        Error::InvalidMetadataSignature
+    );
+
+    let mut bytes = WINMD.to_vec();
+    let stream = find(WINMD, b"#GUID\0");
+    bytes[stream + 1] = b'X';
+    assert_eq!(
+        File::new(bytes).unwrap_err(),
+        Error::UnsupportedStream("#XUID".to_string())
+    );
+}
+
+#[test]
+fn tables() {
+    let tables = find(WINMD, b"#~\0") - 8;
+    let tables = find(WINMD, b"BSJB")
+        + u32::from_le_bytes(WINMD[tables..tables + 4].try_into().unwrap()) as usize;
+
+    // Set the valid bit of the `FieldPtr` table, which only appears in unoptimized metadata.
+    let mut bytes = WINMD.to_vec();
+    bytes[tables + 8] |= 1 << 3;
+    assert_eq!(File::new(bytes).unwrap_err(), Error::UnknownTable(3));
+
+    // Inflate the length of the `Module` table so that it runs past the end of the stream.
+    let mut bytes = WINMD.to_vec();
+    bytes[tables + 24 + 3] = 0x7f;
+    assert_eq!(
+        File::new(bytes).unwrap_err(),
+        Error::TableOutOfBounds {
+            table: 0,
+            offset: tables + 24 + 4 * count_tables(WINMD, tables),
+        }
+    );
+}
+
+#[test]
+fn truncated() {
+    let tables = find(WINMD, b"BSJB");
+
+    // Truncating the file anywhere before the metadata is an error rather than a panic.
+    for len in (0..tables).step_by(97) {
+        assert!(File::new(WINMD[..len].to_vec()).is_err());
+    }
+
+    // Beyond that, parsing may or may not succeed depending on which tables are intact.
+    for len in (tables..WINMD.len()).step_by(997) {
+        _ = File::new(WINMD[..len].to_vec());
+    }
+}
+
+fn find(bytes: &[u8], pattern: &[u8]) -> usize {
+    bytes
+        .windows(pattern.len())
+        .position(|window| window == pattern)
+        .unwrap()
+}
+
+fn count_tables(bytes: &[u8], tables: usize) -> usize {
+    u64::from_le_bytes(bytes[tables + 8..tables + 16].try_into().unwrap()).count_ones() as usize
+}
@@ -0,0 +1,103 @@
+use metadata::*;
+
+const PATH: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../libs/bindgen/default/Windows.Wdk.winmd"
+);
+
+fn type_names(reader: &Reader) -> Vec<String> {
+    reader
+        .items()
+        .filter_map(|item| match item {
+            Item::Type(def) => Some(def.type_name().to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn map() {
+    let mapped = unsafe { File::map(PATH).unwrap() };
+    let read = File::new(std::fs::read(PATH).unwrap()).unwrap();
+    assert_eq!(&*mapped.bytes, &*read.bytes);
+    assert_eq!(Index::new(&mapped), Index::new(&read));
+
+    let mapped = Reader::new_owned(vec![mapped]);
+    let read = Reader::new_owned(vec![read]);
+    assert!(!type_names(&mapped).is_empty());
+    assert_eq!(type_names(&mapped), type_names(&read));
+
+    assert_eq!(
+        unsafe { File::map("missing.winmd") }.unwrap_err(),
+        Error::Io(std::io::ErrorKind::NotFound)
+    );
+}
+
+#[test]
+fn index() {
+    let file = File::new(std::fs::read(PATH).unwrap()).unwrap();
+    let index = Index::new(&file);
+    let namespaces: Vec<&str> = index.namespaces().map(|(namespace, _)| namespace).collect();
+    assert!(namespaces.contains(&"Windows.Wdk.Foundation"));
+    assert!(!namespaces.contains(&""));
+
+    let reader = Reader::new_owned(vec![file]);
+    let namespaces: Vec<&str> = reader.namespaces().collect();
+    assert!(namespaces.contains(&"Windows.Wdk.Foundation"));
+}
+
+#[test]
+fn cache() {
+    let path = std::env::temp_dir().join(format!("test_metadata_{}.idx", std::process::id()));
+    _ = std::fs::remove_file(&path);
+
+    let first = File::new(std::fs::read(PATH).unwrap()).unwrap();
+    let index = Index::cached(&first, &path).unwrap();
+    assert_eq!(index, Index::new(&first));
+    let bytes = std::fs::read(&path).unwrap();
+    assert_eq!(bytes, index.to_bytes());
+
+    // The cached index is loaded rather than rebuilt.
+    let second = File::new(std::fs::read(PATH).unwrap()).unwrap();
+    assert_eq!(Index::from_bytes(&second, &bytes).as_ref(), Some(&index));
+    assert_eq!(Index::cached(&second, &path).unwrap(), index);
+
+    // A reader may be created from the loaded index.
+    let reader = Reader::filter_indexed_owned(
+        vec![(second, index)],
+        &["Windows.Wdk.Foundation"],
+        &[],
+        &Default::default(),
+    );
+    assert!(reader
+        .get_type_def("Windows.Wdk.Foundation", "OBJECT_ATTRIBUTES")
+        .next()
+        .is_some());
+
+    // A truncated cache is rejected.
+    let third = File::new(std::fs::read(PATH).unwrap()).unwrap();
+    assert_eq!(Index::from_bytes(&third, &bytes[..bytes.len() - 1]), None);
+
+    // A cache for a different file is rejected and replaced.
+    let mut other = std::fs::read(PATH).unwrap();
+    other.push(0);
+    let other = File::new(other).unwrap();
+    assert_eq!(Index::from_bytes(&other, &bytes), None);
+    let index = Index::cached(&other, &path).unwrap();
+    assert_eq!(std::fs::read(&path).unwrap(), index.to_bytes());
+
+    _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn filter() {
+    let reader = Reader::filter_owned(
+        vec![File::new(std::fs::read(PATH).unwrap()).unwrap()],
+        &["Windows.Wdk.Foundation.NtClose"],
+        &[],
+        &Default::default(),
+    );
+
+    assert_eq!(reader.unused().count(), 0);
+    assert_eq!(reader.items().count(), 1);
+}
@@ -0,0 +1,61 @@
+use metadata::*;
+
+const WINMD: &[u8] = std::include_bytes!("../../../libs/bindgen/default/Windows.Wdk.winmd");
+
+fn read(reader: &Reader) -> Vec<String> {
+    reader
+        .namespace_items("Windows.Wdk.Foundation")
+        .filter_map(|item| match item {
+            Item::Type(def) => Some(def.type_name().to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn owned() {
+    let mut expected = None;
+
+    // Each reader, along with its file, is freed at the end of the iteration.
+    for _ in 0..3 {
+        let reader = Reader::new_owned(vec![File::new(WINMD.to_vec()).unwrap()]);
+        let names = read(&reader);
+        assert!(!names.is_empty());
+
+        let (method, namespace) = reader
+            .get_method_def("Windows.Wdk.Foundation", "NtClose")
+            .next()
+            .unwrap();
+        assert_eq!(method.name(), "NtClose");
+        assert_eq!(namespace, "Windows.Wdk.Foundation");
+        assert_eq!(method.signature(&[]).params.len(), 1);
+
+        if let Some(expected) = &expected {
+            assert_eq!(expected, &names);
+        } else {
+            expected = Some(names);
+        }
+    }
+}
+
+#[test]
+fn leak() {
+    let owned = Reader::filter_owned(
+        vec![File::new(WINMD.to_vec()).unwrap()],
+        &["Windows.Wdk.Foundation"],
+        &[],
+        &Default::default(),
+    );
+
+    let names = read(&owned);
+    let reader: &'static Reader = owned.leak();
+    assert_eq!(read(reader), names);
+}
+
+#[test]
+fn send() {
+    let reader = Reader::new_owned(vec![File::new(WINMD.to_vec()).unwrap()]);
+    let names = read(&reader);
+    let thread = std::thread::spawn(move || read(&reader));
+    assert_eq!(thread.join().unwrap(), names);
+}
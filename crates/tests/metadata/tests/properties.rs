@@ -0,0 +1,54 @@
+use metadata::*;
+
+#[test]
+fn properties() {
+    let files = tool_lib::default_metadata();
+    let reader = Reader::new(files);
+
+    let def = reader
+        .get_type_def("Windows.Foundation", "IPropertyValue")
+        .next()
+        .unwrap();
+
+    let property = def
+        .properties()
+        .find(|property| property.name() == "Type")
+        .unwrap();
+    assert_eq!(property.getter().unwrap().name(), "get_Type");
+    assert!(property.setter().is_none());
+    let Type::TypeDef(ty, _) = property.ty(&[]) else {
+        panic!("Type not found");
+    };
+    assert_eq!(
+        ty.type_name().to_string(),
+        "Windows.Foundation.PropertyType"
+    );
+
+    let def = reader
+        .get_type_def("Windows.Foundation", "IUriRuntimeClass")
+        .next()
+        .unwrap();
+
+    assert!(def
+        .properties()
+        .any(|property| property.name() == "AbsoluteUri"));
+    assert!(def.events().next().is_none());
+}
+
+#[test]
+fn events() {
+    let files = tool_lib::default_metadata();
+    let reader = Reader::new(files);
+
+    let def = reader
+        .get_type_def("Windows.Foundation", "IMemoryBufferReference")
+        .next()
+        .unwrap();
+
+    let event = def.events().find(|event| event.name() == "Closed").unwrap();
+    assert_eq!(event.add_method().unwrap().name(), "add_Closed");
+    assert_eq!(event.remove_method().unwrap().name(), "remove_Closed");
+    assert!(event
+        .methods()
+        .all(|semantics| semantics.association() == HasSemantics::Event(event)));
+}
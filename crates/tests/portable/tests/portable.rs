@@ -0,0 +1,141 @@
+// These tests only rely on objects implemented within the process, so they pass on any platform.
+
+use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
+use std::sync::Arc;
+use windows::{core::*, Foundation::*, Win32::Foundation::*};
+
+#[implement(IStringable, IClosable)]
+struct Stringable {
+    value: String,
+    dropped: Arc<AtomicBool>,
+}
+
+impl Drop for Stringable {
+    fn drop(&mut self) {
+        self.dropped.store(true, Ordering::Relaxed);
+    }
+}
+
+impl IStringable_Impl for Stringable {
+    fn ToString(&self) -> Result<HSTRING> {
+        Ok(self.value.as_str().into())
+    }
+}
+
+impl IClosable_Impl for Stringable {
+    fn Close(&self) -> Result<()> {
+        Err(Error::new(E_ACCESSDENIED, "closed".into()))
+    }
+}
+
+fn stringable(value: &str) -> (IStringable, Arc<AtomicBool>) {
+    let dropped = Arc::new(AtomicBool::new(false));
+    let object = Stringable {
+        value: value.to_string(),
+        dropped: dropped.clone(),
+    };
+    (object.into(), dropped)
+}
+
+#[test]
+fn query_interface() -> Result<()> {
+    let (stringable, dropped) = stringable("hello");
+    assert_eq!(stringable.ToString()?, "hello");
+
+    let closable: IClosable = stringable.cast()?;
+    let unknown: IUnknown = closable.cast()?;
+    assert_eq!(unknown, stringable.cast()?);
+    assert!(unknown.cast::<IMemoryBuffer>().is_err());
+
+    drop(stringable);
+    drop(closable);
+    assert!(!dropped.load(Ordering::Relaxed));
+    drop(unknown);
+    assert!(dropped.load(Ordering::Relaxed));
+
+    Ok(())
+}
+
+#[test]
+fn weak() -> Result<()> {
+    let (stringable, dropped) = stringable("hello");
+    let weak = stringable.downgrade()?;
+    assert_eq!(weak.upgrade().unwrap().ToString()?, "hello");
+
+    drop(stringable);
+    assert!(dropped.load(Ordering::Relaxed));
+    assert!(weak.upgrade().is_none());
+
+    Ok(())
+}
+
+#[test]
+fn error() {
+    let (stringable, _) = stringable("hello");
+    let error = stringable.cast::<IClosable>().unwrap().Close().unwrap_err();
+    assert_eq!(error.code(), E_ACCESSDENIED);
+    assert_eq!(error.message(), "closed");
+
+    let error = Error::from(E_FAIL);
+    assert_eq!(error.message(), "");
+}
+
+#[test]
+fn event() -> Result<()> {
+    let mut event = Event::<EventHandler<i32>>::new();
+    let check = Arc::new(AtomicI32::new(0));
+    let check_sender = check.clone();
+
+    let token = event.add(&EventHandler::<i32>::new(move |_, args| {
+        check_sender.fetch_add(*args, Ordering::Relaxed);
+        Ok(())
+    }))?;
+
+    event.call(|delegate| delegate.Invoke(None, 123))?;
+    assert_eq!(check.load(Ordering::Relaxed), 123);
+
+    event.remove(token)?;
+    event.call(|delegate| delegate.Invoke(None, 123))?;
+    assert_eq!(check.load(Ordering::Relaxed), 123);
+
+    Ok(())
+}
+
+#[test]
+fn agile_reference() -> Result<()> {
+    let (stringable, _) = stringable("hello");
+    let reference = AgileReference::new(&stringable)?;
+
+    let resolved = std::thread::spawn(move || reference.resolve().unwrap().ToString().unwrap())
+        .join()
+        .unwrap();
+    assert_eq!(resolved, "hello");
+
+    Ok(())
+}
+
+#[test]
+fn strings() -> Result<()> {
+    let hello = HSTRING::from("hello");
+    let copy = hello.clone();
+    assert_eq!(copy, "hello");
+    assert_eq!((&hello + " world").to_string(), "hello world");
+
+    let bstr = BSTR::from("world");
+    assert_eq!(bstr.len(), 5);
+    assert_eq!(bstr.clone(), "world");
+
+    Ok(())
+}
+
+#[test]
+fn array() {
+    let mut array = Array::<i32>::with_len(3);
+    array[0] = 1;
+    array[2] = 3;
+    assert_eq!(&array[..], [1, 0, 3]);
+
+    let array = Array::<HSTRING>::from_slice(&["a".into(), "b".into()]);
+    assert_eq!(array.len(), 2);
+    assert_eq!(array[1], "b");
+}
@@ -0,0 +1,93 @@
+// Bindings generated by `windows-bindgen` 0.52.0
+
+#![allow(
+    non_snake_case,
+    non_upper_case_globals,
+    non_camel_case_types,
+    dead_code,
+    clippy::all
+)]
+#[repr(transparent)]
+#[derive(
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+    ::core::marker::Copy,
+    ::core::clone::Clone,
+    ::core::default::Default,
+)]
+pub struct Signed(pub i32);
+impl Signed {
+    pub const Negative: Self = Self(-1i32);
+    pub const Zero: Self = Self(0i32);
+    pub const One: Self = Self(1i32);
+}
+impl ::windows_core::TypeKind for Signed {
+    type TypeKind = ::windows_core::CopyType;
+}
+impl ::core::fmt::Debug for Signed {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("Signed").field(&self.0).finish()
+    }
+}
+impl ::windows_core::RuntimeType for Signed {
+    const SIGNATURE: ::windows_core::imp::ConstBuffer =
+        ::windows_core::imp::ConstBuffer::from_slice(b"enum(Test.Signed;i4)");
+}
+#[repr(transparent)]
+#[derive(
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+    ::core::marker::Copy,
+    ::core::clone::Clone,
+    ::core::default::Default,
+)]
+pub struct Unsigned(pub u32);
+impl Unsigned {
+    pub const Low: Self = Self(1u32);
+    pub const High: Self = Self(4294967295u32);
+}
+impl ::windows_core::TypeKind for Unsigned {
+    type TypeKind = ::windows_core::CopyType;
+}
+impl ::core::fmt::Debug for Unsigned {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_tuple("Unsigned").field(&self.0).finish()
+    }
+}
+impl Unsigned {
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+impl ::core::ops::BitOr for Unsigned {
+    type Output = Self;
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+impl ::core::ops::BitAnd for Unsigned {
+    type Output = Self;
+    fn bitand(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+}
+impl ::core::ops::BitOrAssign for Unsigned {
+    fn bitor_assign(&mut self, other: Self) {
+        self.0.bitor_assign(other.0)
+    }
+}
+impl ::core::ops::BitAndAssign for Unsigned {
+    fn bitand_assign(&mut self, other: Self) {
+        self.0.bitand_assign(other.0)
+    }
+}
+impl ::core::ops::Not for Unsigned {
+    type Output = Self;
+    fn not(self) -> Self {
+        Self(self.0.not())
+    }
+}
+impl ::windows_core::RuntimeType for Unsigned {
+    const SIGNATURE: ::windows_core::imp::ConstBuffer =
+        ::windows_core::imp::ConstBuffer::from_slice(b"enum(Test.Unsigned;u4)");
+}
@@ -75,22 +75,22 @@ impl<T: ::windows_core::RuntimeType + 'static> ::windows_core::CanInto<::windows
 {
 }
 impl<T: ::windows_core::RuntimeType + 'static> IIterator<T> {
-    pub fn get_Current(&self) -> ::windows_core::Result<T> {
+    pub fn Current(&self) -> ::windows_core::Result<T> {
         let this = self;
         unsafe {
             let mut result__ = ::std::mem::zeroed();
-            (::windows_core::Interface::vtable(this).get_Current)(
+            (::windows_core::Interface::vtable(this).Current)(
                 ::windows_core::Interface::as_raw(this),
                 &mut result__,
             )
             .from_abi(result__)
         }
     }
-    pub fn get_HasCurrent(&self) -> ::windows_core::Result<bool> {
+    pub fn HasCurrent(&self) -> ::windows_core::Result<bool> {
         let this = self;
         unsafe {
             let mut result__ = ::std::mem::zeroed();
-            (::windows_core::Interface::vtable(this).get_HasCurrent)(
+            (::windows_core::Interface::vtable(this).HasCurrent)(
                 ::windows_core::Interface::as_raw(this),
                 &mut result__,
             )
@@ -131,11 +131,11 @@ where
     T: ::windows_core::RuntimeType + 'static,
 {
     pub base__: ::windows_core::IInspectable_Vtbl,
-    pub get_Current: unsafe extern "system" fn(
+    pub Current: unsafe extern "system" fn(
         *mut ::core::ffi::c_void,
         *mut ::windows_core::AbiType<T>,
     ) -> ::windows_core::HRESULT,
-    pub get_HasCurrent:
+    pub HasCurrent:
         unsafe extern "system" fn(*mut ::core::ffi::c_void, *mut bool) -> ::windows_core::HRESULT,
     pub MoveNext:
         unsafe extern "system" fn(*mut ::core::ffi::c_void, *mut bool) -> ::windows_core::HRESULT,
@@ -162,22 +162,22 @@ impl<K: ::windows_core::RuntimeType + 'static, V: ::windows_core::RuntimeType +
 impl<K: ::windows_core::RuntimeType + 'static, V: ::windows_core::RuntimeType + 'static>
     IKeyValuePair<K, V>
 {
-    pub fn get_Key(&self) -> ::windows_core::Result<K> {
+    pub fn Key(&self) -> ::windows_core::Result<K> {
         let this = self;
         unsafe {
             let mut result__ = ::std::mem::zeroed();
-            (::windows_core::Interface::vtable(this).get_Key)(
+            (::windows_core::Interface::vtable(this).Key)(
                 ::windows_core::Interface::as_raw(this),
                 &mut result__,
             )
             .from_abi(result__)
         }
     }
-    pub fn get_Value(&self) -> ::windows_core::Result<V> {
+    pub fn Value(&self) -> ::windows_core::Result<V> {
         let this = self;
         unsafe {
             let mut result__ = ::std::mem::zeroed();
-            (::windows_core::Interface::vtable(this).get_Value)(
+            (::windows_core::Interface::vtable(this).Value)(
                 ::windows_core::Interface::as_raw(this),
                 &mut result__,
             )
@@ -214,11 +214,11 @@ where
     V: ::windows_core::RuntimeType + 'static,
 {
     pub base__: ::windows_core::IInspectable_Vtbl,
-    pub get_Key: unsafe extern "system" fn(
+    pub Key: unsafe extern "system" fn(
         *mut ::core::ffi::c_void,
         *mut ::windows_core::AbiType<K>,
     ) -> ::windows_core::HRESULT,
-    pub get_Value: unsafe extern "system" fn(
+    pub Value: unsafe extern "system" fn(
         *mut ::core::ffi::c_void,
         *mut ::windows_core::AbiType<V>,
     ) -> ::windows_core::HRESULT,
@@ -269,11 +269,11 @@ impl<K: ::windows_core::RuntimeType + 'static, V: ::windows_core::RuntimeType +
             .from_abi(result__)
         }
     }
-    pub fn get_Size(&self) -> ::windows_core::Result<u32> {
+    pub fn Size(&self) -> ::windows_core::Result<u32> {
         let this = self;
         unsafe {
             let mut result__ = ::std::mem::zeroed();
-            (::windows_core::Interface::vtable(this).get_Size)(
+            (::windows_core::Interface::vtable(this).Size)(
                 ::windows_core::Interface::as_raw(this),
                 &mut result__,
             )
@@ -344,7 +344,7 @@ where
         *mut ::windows_core::AbiType<K>,
         *mut ::windows_core::AbiType<V>,
     ) -> ::windows_core::HRESULT,
-    pub get_Size:
+    pub Size:
         unsafe extern "system" fn(*mut ::core::ffi::c_void, *mut u32) -> ::windows_core::HRESULT,
     pub HasKey: unsafe extern "system" fn(
         *mut ::core::ffi::c_void,
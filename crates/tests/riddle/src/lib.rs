@@ -1,16 +1,46 @@
 mod composition;
+mod r#enum;
 mod generic_interfaces;
 mod module_attributes;
 mod nested_module;
 mod nested_struct;
 mod params;
+mod properties;
 mod r#struct;
 mod win32_struct;
 mod winrt_struct;
 
-use std::process::Command;
+use std::process::{Command, Output};
+use windows_metadata::*;
 
-pub fn run_riddle(name: &str, dialect: &str, etc: &[&str]) -> Vec<windows_metadata::File> {
+/// Runs riddle on `inputs` with the given filter and `--config` values, replacing `output`, and returns the result.
+pub fn riddle(inputs: &[&str], output: &str, filter: &[&str], config: &[&str]) -> Output {
+    _ = std::fs::remove_file(output);
+    let mut command = Command::new("cargo");
+    command.args(["run", "-p", "riddle", "--", "--in"]);
+    command.args(inputs);
+    command.args(["--out", output, "--filter"]);
+    command.args(filter);
+
+    if !config.is_empty() {
+        command.arg("--config");
+        command.args(config);
+    }
+
+    command.output().unwrap()
+}
+
+/// Returns the names of the types defined by `file`, in the order they're defined.
+pub fn type_defs(file: &File) -> Vec<String> {
+    file.table::<TypeDef>()
+        .skip(1) // <Module>
+        .map(|def| def.type_name().to_string())
+        .collect()
+}
+
+/// Converts `tests/{name}.rdl` to .winmd and back, checking that it is unchanged, and then generates `src/{name}.rs`
+/// with `etc` as its `--config` values.
+pub fn run_riddle(name: &str, dialect: &str, etc: &[&str]) -> Vec<File> {
     let rdl = format!("tests/{name}.rdl");
     let winmd = format!("tests/{name}.winmd");
     let rs = format!("src/{name}.rs");
@@ -18,39 +48,25 @@ pub fn run_riddle(name: &str, dialect: &str, etc: &[&str]) -> Vec<windows_metada
     let before = std::fs::read_to_string(&rdl).expect("Failed to read input");
 
     // Convert .rdl to .winmd
-    _ = std::fs::remove_file(&winmd);
-    let mut command = Command::new("cargo");
-    command.args([
-        "run", "-p", "riddle", "--", "--in", &rdl, "--out", &winmd, "--filter", "Test",
-    ]);
-    assert!(command.status().unwrap().success());
+    assert!(riddle(&[&rdl], &winmd, &["Test"], &[]).status.success());
 
     // Convert .winmd back to .rdl
-    std::fs::remove_file(&rdl).expect("Failed to delete output");
-    let mut command = Command::new("cargo");
-    command.args([
-        "run", "-p", "riddle", "--", "--in", &winmd, "--out", &rdl, "--filter", "Test", "--config",
-    ]);
-    command.arg(format!("type={dialect}"));
-    assert!(command.status().unwrap().success());
+    let dialect = format!("type={dialect}");
+    assert!(riddle(&[&winmd], &rdl, &["Test"], &[&dialect])
+        .status
+        .success());
 
     // Check that .rdl is unchanged
     let after = std::fs::read_to_string(&rdl).expect("Failed to read output");
     assert_eq!(before, after, "no equal {}", rdl);
 
     // Convert .rdl to .rs
-    std::fs::remove_file(&rs).expect("Failed to delete output");
-    let mut command = Command::new("cargo");
-    command.args([
-        "run", "-p", "riddle", "--", "--in", &rdl, "--out", &rs, "--filter", "Test",
-    ]);
-    command.args(etc);
-    assert!(command.status().unwrap().success());
+    assert!(riddle(&[&rdl], &rs, &["Test"], etc).status.success());
 
     // Return winmd file for validation
     let mut files = tool_lib::default_metadata();
     files.push(
-        windows_metadata::File::new(std::fs::read(&winmd).expect("failed to read winmd"))
+        File::new(std::fs::read(&winmd).expect("failed to read winmd"))
             .expect("failed to parse winmd"),
     );
     files
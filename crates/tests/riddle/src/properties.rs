@@ -0,0 +1,170 @@
+// Bindings generated by `windows-bindgen` 0.52.0
+
+#![allow(
+    non_snake_case,
+    non_upper_case_globals,
+    non_camel_case_types,
+    dead_code,
+    clippy::all
+)]
+::windows_core::imp::com_interface!(IHandler, IHandler_Vtbl, 0);
+::windows_core::imp::interface_hierarchy!(
+    IHandler,
+    ::windows_core::IUnknown,
+    ::windows_core::IInspectable
+);
+impl IHandler {
+    pub fn Invoke(&self, value: &mut i32) -> ::windows_core::Result<()> {
+        let this = self;
+        unsafe {
+            (::windows_core::Interface::vtable(this).Invoke)(
+                ::windows_core::Interface::as_raw(this),
+                value,
+            )
+            .ok()
+        }
+    }
+}
+impl ::windows_core::RuntimeType for IHandler {
+    const SIGNATURE: ::windows_core::imp::ConstBuffer =
+        ::windows_core::imp::ConstBuffer::for_interface::<Self>();
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IHandler_Vtbl {
+    pub base__: ::windows_core::IInspectable_Vtbl,
+    pub Invoke:
+        unsafe extern "system" fn(*mut ::core::ffi::c_void, *mut i32) -> ::windows_core::HRESULT,
+}
+::windows_core::imp::com_interface!(IWidget, IWidget_Vtbl, 0);
+::windows_core::imp::interface_hierarchy!(
+    IWidget,
+    ::windows_core::IUnknown,
+    ::windows_core::IInspectable
+);
+impl IWidget {
+    pub fn Name(&self) -> ::windows_core::Result<::windows_core::HSTRING> {
+        let this = self;
+        unsafe {
+            let mut result__ = ::std::mem::zeroed();
+            (::windows_core::Interface::vtable(this).Name)(
+                ::windows_core::Interface::as_raw(this),
+                &mut result__,
+            )
+            .from_abi(result__)
+        }
+    }
+    pub fn SetName(&self, value: &mut ::windows_core::HSTRING) -> ::windows_core::Result<()> {
+        let this = self;
+        unsafe {
+            (::windows_core::Interface::vtable(this).SetName)(
+                ::windows_core::Interface::as_raw(this),
+                value as *mut _ as _,
+            )
+            .ok()
+        }
+    }
+    pub fn Size(&self) -> ::windows_core::Result<u32> {
+        let this = self;
+        unsafe {
+            let mut result__ = ::std::mem::zeroed();
+            (::windows_core::Interface::vtable(this).Size)(
+                ::windows_core::Interface::as_raw(this),
+                &mut result__,
+            )
+            .from_abi(result__)
+        }
+    }
+    pub fn Changed<P0>(
+        &self,
+        handler: &mut ::core::option::Option<IHandler>,
+    ) -> ::windows_core::Result<i64>
+    where
+        P0: ::windows_core::IntoParam<IHandler>,
+    {
+        let this = self;
+        unsafe {
+            let mut result__ = ::std::mem::zeroed();
+            (::windows_core::Interface::vtable(this).Changed)(
+                ::windows_core::Interface::as_raw(this),
+                handler as *mut _ as _,
+                &mut result__,
+            )
+            .from_abi(result__)
+        }
+    }
+    pub fn RemoveChanged(&self, token: &mut i64) -> ::windows_core::Result<()> {
+        let this = self;
+        unsafe {
+            (::windows_core::Interface::vtable(this).RemoveChanged)(
+                ::windows_core::Interface::as_raw(this),
+                token,
+            )
+            .ok()
+        }
+    }
+    pub fn get_At(&self, index: &mut u32) -> ::windows_core::Result<i32> {
+        let this = self;
+        unsafe {
+            let mut result__ = ::std::mem::zeroed();
+            (::windows_core::Interface::vtable(this).get_At)(
+                ::windows_core::Interface::as_raw(this),
+                index,
+                &mut result__,
+            )
+            .from_abi(result__)
+        }
+    }
+    pub fn add_Ready(&self) -> ::windows_core::Result<i64> {
+        let this = self;
+        unsafe {
+            let mut result__ = ::std::mem::zeroed();
+            (::windows_core::Interface::vtable(this).add_Ready)(
+                ::windows_core::Interface::as_raw(this),
+                &mut result__,
+            )
+            .from_abi(result__)
+        }
+    }
+    pub fn Close(&self) -> ::windows_core::Result<()> {
+        let this = self;
+        unsafe {
+            (::windows_core::Interface::vtable(this).Close)(::windows_core::Interface::as_raw(this))
+                .ok()
+        }
+    }
+}
+impl ::windows_core::RuntimeType for IWidget {
+    const SIGNATURE: ::windows_core::imp::ConstBuffer =
+        ::windows_core::imp::ConstBuffer::for_interface::<Self>();
+}
+#[repr(C)]
+#[doc(hidden)]
+pub struct IWidget_Vtbl {
+    pub base__: ::windows_core::IInspectable_Vtbl,
+    pub Name: unsafe extern "system" fn(
+        *mut ::core::ffi::c_void,
+        *mut ::std::mem::MaybeUninit<::windows_core::HSTRING>,
+    ) -> ::windows_core::HRESULT,
+    pub SetName: unsafe extern "system" fn(
+        *mut ::core::ffi::c_void,
+        *mut ::std::mem::MaybeUninit<::windows_core::HSTRING>,
+    ) -> ::windows_core::HRESULT,
+    pub Size:
+        unsafe extern "system" fn(*mut ::core::ffi::c_void, *mut u32) -> ::windows_core::HRESULT,
+    pub Changed: unsafe extern "system" fn(
+        *mut ::core::ffi::c_void,
+        *mut *mut ::core::ffi::c_void,
+        *mut i64,
+    ) -> ::windows_core::HRESULT,
+    pub RemoveChanged:
+        unsafe extern "system" fn(*mut ::core::ffi::c_void, *mut i64) -> ::windows_core::HRESULT,
+    pub get_At: unsafe extern "system" fn(
+        *mut ::core::ffi::c_void,
+        *mut u32,
+        *mut i32,
+    ) -> ::windows_core::HRESULT,
+    pub add_Ready:
+        unsafe extern "system" fn(*mut ::core::ffi::c_void, *mut i64) -> ::windows_core::HRESULT,
+    pub Close: unsafe extern "system" fn(*mut ::core::ffi::c_void) -> ::windows_core::HRESULT,
+}
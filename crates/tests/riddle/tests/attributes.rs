@@ -0,0 +1,141 @@
+use test_riddle::riddle;
+use windows_metadata::*;
+
+const TYPES: [(&str, &str); 3] = [
+    ("Windows.Wdk.System.OfflineRegistry", "ORHKEY"),
+    (
+        "Windows.Wdk.Storage.FileSystem",
+        "NTCREATEFILE_CREATE_OPTIONS",
+    ),
+    ("Windows.Wdk.Graphics.Direct3D", "D3DKMDT_DISPLAYMODE_FLAGS"),
+];
+
+#[test]
+fn test() {
+    let winmd = "tests/attributes.winmd";
+    let names: Vec<String> = TYPES
+        .iter()
+        .map(|(namespace, name)| format!("{namespace}.{name}"))
+        .collect();
+
+    let filter: Vec<&str> = names.iter().map(String::as_str).collect();
+    assert!(riddle(
+        &["../../libs/bindgen/default/Windows.Wdk.winmd"],
+        winmd,
+        &filter,
+        &[],
+    )
+    .status
+    .success());
+
+    let before = Reader::new(tool_lib::default_metadata());
+    let after = Reader::new(vec![File::new(std::fs::read(winmd).unwrap()).unwrap()]);
+
+    for (namespace, name) in TYPES {
+        let expected = attributes(before, namespace, name);
+        assert!(!expected.is_empty());
+        assert_eq!(expected, attributes(after, namespace, name));
+    }
+
+    // String and integer arguments keep their types through the round trip.
+    let def = after
+        .get_type_def("Windows.Wdk.System.OfflineRegistry", "ORHKEY")
+        .next()
+        .unwrap();
+
+    let args = def.find_attribute("RAIIFreeAttribute").unwrap().args();
+    assert!(matches!(args.as_slice(), [("", Value::String(value))] if value == "ORCloseKey"));
+
+    let args = def
+        .find_attribute("InvalidHandleValueAttribute")
+        .unwrap()
+        .args();
+    assert!(matches!(args.as_slice(), [("", Value::I64(-1))]));
+}
+
+#[test]
+fn kinds_and_nulls() {
+    // ORHKEY's `RAIIFreeAttribute` blob is rewritten in place to hold a null string rather than "ORCloseKey".
+    let mut bytes = std::fs::read("../../libs/bindgen/default/Windows.Wdk.winmd").unwrap();
+    let blob = b"\x0F\x01\x00\x0AORCloseKey\x00\x00";
+    let offset = bytes
+        .windows(blob.len())
+        .position(|window| window == blob)
+        .unwrap();
+    bytes[offset..offset + 6].copy_from_slice(b"\x05\x01\x00\xFF\x00\x00");
+    std::fs::write("tests/attributes_null.winmd", bytes).unwrap();
+
+    let winmd = "tests/attributes_kinds.winmd";
+    assert!(riddle(
+        &["tests/attributes_null.winmd", "tests/attributes.rdl"],
+        winmd,
+        &[
+            "Windows.Wdk.System.OfflineRegistry.ORHKEY",
+            "Windows.Wdk.Storage.FileSystem.Minifilters.PFLT_MESSAGE_NOTIFY",
+            "Windows.Win32.Foundation.NTSTATUS",
+        ],
+        &[],
+    )
+    .status
+    .success());
+
+    let reader = Reader::new(vec![File::new(std::fs::read(winmd).unwrap()).unwrap()]);
+
+    // A null string is written back as null rather than empty.
+    let def = reader
+        .get_type_def("Windows.Wdk.System.OfflineRegistry", "ORHKEY")
+        .next()
+        .unwrap();
+
+    let args = def.find_attribute("RAIIFreeAttribute").unwrap().args();
+    assert!(matches!(args.as_slice(), [("", Value::Null(Type::String))]));
+
+    // Named arguments keep whether they set a field or a property.
+    let def = reader
+        .get_type_def(
+            "Windows.Wdk.Storage.FileSystem.Minifilters",
+            "PFLT_MESSAGE_NOTIFY",
+        )
+        .next()
+        .unwrap();
+
+    let method = def
+        .methods()
+        .find(|method| method.name() == "Invoke")
+        .unwrap();
+    let param = method
+        .params()
+        .find(|param| param.name() == "InputBuffer")
+        .unwrap();
+
+    let args = param
+        .find_attribute("MemorySizeAttribute")
+        .unwrap()
+        .kinded_args();
+
+    assert!(matches!(
+        args.as_slice(),
+        [(ArgKind::Field, "BytesParamIndex", Value::I16(2))]
+    ));
+}
+
+fn attributes(reader: &Reader, namespace: &str, name: &str) -> Vec<String> {
+    let def = reader.get_type_def(namespace, name).next().unwrap();
+    let mut attributes: Vec<String> = def
+        .attributes()
+        .map(|attribute| format!("{} {:?}", attribute.type_name(), attribute.args()))
+        .collect();
+
+    for field in def.fields() {
+        for attribute in field.attributes() {
+            attributes.push(format!(
+                "{}: {} {:?}",
+                field.name(),
+                attribute.type_name(),
+                attribute.args()
+            ));
+        }
+    }
+
+    attributes
+}
@@ -0,0 +1,59 @@
+use test_riddle::run_riddle;
+use windows_metadata::*;
+
+#[test]
+fn test() {
+    let files = run_riddle("enum", "winrt", &[]);
+    let reader = Reader::new(files);
+
+    let def = reader
+        .get_type_def("Test", "Signed")
+        .next()
+        .expect("Type missing");
+
+    assert_eq!(def.kind(), TypeKind::Enum);
+    assert!(matches!(def.underlying_type(), Type::I32));
+
+    let fields: Vec<Field> = def.fields().collect();
+    assert_eq!(fields.len(), 4);
+
+    assert_eq!(fields[1].name(), "Negative");
+    assert_eq!(fields[2].name(), "Zero");
+    assert_eq!(fields[3].name(), "One");
+
+    assert!(matches!(
+        fields[1].constant().unwrap().value(),
+        Value::I32(-1)
+    ));
+    assert!(matches!(
+        fields[2].constant().unwrap().value(),
+        Value::I32(0)
+    ));
+    assert!(matches!(
+        fields[3].constant().unwrap().value(),
+        Value::I32(1)
+    ));
+
+    let def = reader
+        .get_type_def("Test", "Unsigned")
+        .next()
+        .expect("Type missing");
+
+    assert_eq!(def.kind(), TypeKind::Enum);
+    assert!(matches!(def.underlying_type(), Type::U32));
+
+    let fields: Vec<Field> = def.fields().collect();
+    assert_eq!(fields.len(), 3);
+
+    assert_eq!(fields[1].name(), "Low");
+    assert_eq!(fields[2].name(), "High");
+
+    assert!(matches!(
+        fields[1].constant().unwrap().value(),
+        Value::U32(1)
+    ));
+    assert!(matches!(
+        fields[2].constant().unwrap().value(),
+        Value::U32(u32::MAX)
+    ));
+}
@@ -0,0 +1,173 @@
+use test_riddle::{riddle, type_defs};
+use windows_metadata::*;
+
+fn run_merge(inputs: &[&str], output: &str, config: &[&str]) -> bool {
+    riddle(inputs, output, &["Test"], config).status.success()
+}
+
+fn read(path: &str) -> File {
+    File::new(std::fs::read(path).unwrap()).unwrap()
+}
+
+#[test]
+fn duplicates() {
+    let inputs = ["tests/merge_a.rdl", "tests/merge_b.rdl"];
+
+    // Without merging, each input's definition is written.
+    assert!(run_merge(&inputs, "tests/merge_duplicates.winmd", &[]));
+    assert_eq!(
+        type_defs(&read("tests/merge_duplicates.winmd")),
+        ["Test.Point", "Test.Point", "Test.Rect", "Test.Size"]
+    );
+
+    // Identical definitions are written once when merged.
+    assert!(run_merge(&inputs, "tests/merge.winmd", &["merge"]));
+    assert_eq!(
+        type_defs(&read("tests/merge.winmd")),
+        ["Test.Point", "Test.Rect", "Test.Size"]
+    );
+
+    let reader = Reader::new(vec![read("tests/merge.winmd")]);
+    let def = reader.get_type_def("Test", "Rect").next().unwrap();
+    let field = def.fields().next().unwrap();
+    let Type::TypeDef(point, _) = field.ty(Some(def)) else {
+        panic!("TypeDef expected");
+    };
+    assert_eq!(point.fields().count(), 2);
+}
+
+#[test]
+fn conflict() {
+    assert!(!run_merge(
+        &["tests/merge_a.rdl", "tests/merge_conflict.rdl"],
+        "tests/merge_conflict.winmd",
+        &["merge"],
+    ));
+
+    assert!(!std::path::Path::new("tests/merge_conflict.winmd").exists());
+}
+
+fn architectures(def: TypeDef) -> i32 {
+    let args = def
+        .find_attribute("SupportedArchitectureAttribute")
+        .unwrap()
+        .args();
+
+    let [("", Value::EnumRef(type_name, value))] = args.as_slice() else {
+        panic!("Value not found");
+    };
+
+    assert_eq!(
+        *type_name,
+        TypeName::new("Windows.Win32.Foundation.Metadata", "Architecture")
+    );
+
+    let Value::I32(value) = **value else {
+        panic!("I32 expected");
+    };
+
+    value
+}
+
+#[test]
+fn architectures_merged() {
+    let inputs = ["tests/merge_x86.rdl", "tests/merge_x64.rdl"];
+    assert!(run_merge(
+        &inputs,
+        "tests/merge_architectures.winmd",
+        &["merge"]
+    ));
+
+    // Definitions that differ are kept side by side since they support disjoint architectures while identical
+    // definitions are written once.
+    assert_eq!(
+        type_defs(&read("tests/merge_architectures.winmd")),
+        ["Test.Handle", "Test.Handle", "Test.Shared"]
+    );
+
+    let reader = Reader::new(vec![read("tests/merge_architectures.winmd")]);
+
+    let handles: Vec<(i32, Type)> = reader
+        .get_type_def("Test", "Handle")
+        .map(|def| {
+            let field = def.fields().next().unwrap();
+            (architectures(def), field.ty(Some(def)))
+        })
+        .collect();
+
+    assert_eq!(handles, [(1, Type::I32), (2, Type::I64)]);
+
+    // The identical definitions support the architectures of both.
+    let shared = reader.get_type_def("Test", "Shared").next().unwrap();
+    assert_eq!(architectures(shared), 1 | 2);
+
+    // The architectures are written back to .rdl as well.
+    assert!(riddle(
+        &["tests/merge_architectures.winmd"],
+        "tests/merge_architectures.rdl",
+        &["Test"],
+        &["type=win32"],
+    )
+    .status
+    .success());
+
+    let rdl = std::fs::read_to_string("tests/merge_architectures.rdl").unwrap();
+    assert!(rdl.contains("#[arch(X86 | X64)]\n    struct Shared {"));
+}
+
+#[test]
+fn architectures_overlapping() {
+    // Definitions that differ can't be merged if they support any of the same architectures.
+    assert!(!run_merge(
+        &[
+            "tests/merge_x86.rdl",
+            "tests/merge_x64.rdl",
+            "tests/merge_arm64.rdl"
+        ],
+        "tests/merge_overlapping.winmd",
+        &["merge"],
+    ));
+
+    assert!(!std::path::Path::new("tests/merge_overlapping.winmd").exists());
+}
+
+#[test]
+fn apis() {
+    let input = "../../libs/bindgen/default/Windows.Wdk.winmd";
+    let filter = ["Windows.Wdk.System.OfflineRegistry"];
+
+    assert!(riddle(
+        &[input],
+        "tests/merge_apis_single.winmd",
+        &filter,
+        &["standalone"]
+    )
+    .status
+    .success());
+
+    // The functions and constants of both inputs are identical so they're written to a single `Apis` class.
+    assert!(riddle(
+        &[input, input],
+        "tests/merge_apis.winmd",
+        &filter,
+        &["standalone", "merge"]
+    )
+    .status
+    .success());
+
+    let single = read("tests/merge_apis_single.winmd");
+    let merged = read("tests/merge_apis.winmd");
+    assert_eq!(type_defs(&single), type_defs(&merged));
+
+    let apis = |file: &File| {
+        let def = file
+            .table::<TypeDef>()
+            .find(|def| def.name() == "Apis")
+            .unwrap();
+        (def.fields().count(), def.methods().count())
+    };
+
+    let (fields, methods) = apis(&merged);
+    assert!(methods > 0);
+    assert_eq!((fields, methods), apis(&single));
+}
@@ -0,0 +1,45 @@
+use test_riddle::run_riddle;
+use windows_metadata::*;
+
+#[test]
+fn test() {
+    let files = run_riddle("properties", "winrt", &[]);
+    let reader = Reader::new(files);
+
+    let def = reader.get_type_def("Test", "IWidget").next().unwrap();
+
+    let properties: Vec<Property> = def.properties().collect();
+    assert_eq!(properties.len(), 2);
+    assert_eq!(properties[0].name(), "Name");
+    assert_eq!(properties[0].ty(&[]), Type::String);
+    assert_eq!(properties[0].getter().unwrap().name(), "get_Name");
+    assert_eq!(properties[0].setter().unwrap().name(), "put_Name");
+    assert_eq!(properties[1].name(), "Size");
+    assert_eq!(properties[1].ty(&[]), Type::U32);
+    assert_eq!(properties[1].getter().unwrap().name(), "get_Size");
+    assert!(properties[1].setter().is_none());
+
+    let events: Vec<Event> = def.events().collect();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].name(), "Changed");
+    assert_eq!(events[0].add_method().unwrap().name(), "add_Changed");
+    assert_eq!(events[0].remove_method().unwrap().name(), "remove_Changed");
+
+    let Type::TypeDef(handler, _) = events[0].ty(&[]) else {
+        panic!("Type not found");
+    };
+    assert_eq!(handler.name(), "IHandler");
+
+    // Accessors are marked as special names while other methods, including those whose names look like accessors but
+    // whose signatures don't match, are not.
+    for method in def.methods() {
+        assert_eq!(
+            method.flags().contains(MethodAttributes::SpecialName),
+            !matches!(method.name(), "get_At" | "add_Ready" | "Close")
+        );
+    }
+
+    let def = reader.get_type_def("Test", "IHandler").next().unwrap();
+    assert_eq!(def.properties().count(), 0);
+    assert_eq!(def.events().count(), 0);
+}
@@ -0,0 +1,67 @@
+use test_riddle::{riddle, type_defs};
+use windows_metadata::*;
+
+fn run_split(output: &str, config: &str) {
+    assert!(riddle(&["tests/split.rdl"], output, &["Test"], &[config])
+        .status
+        .success());
+}
+
+fn read(path: &str) -> File {
+    File::new(std::fs::read(path).unwrap()).unwrap()
+}
+
+fn type_ref_scope(file: &File, namespace: &str, name: &str) -> String {
+    let type_ref = file
+        .table::<TypeRef>()
+        .find(|type_ref| type_ref.type_name() == TypeName::new(namespace, name))
+        .unwrap();
+
+    let ResolutionScope::AssemblyRef(assembly_ref) = type_ref.resolution_scope() else {
+        panic!("AssemblyRef expected");
+    };
+
+    assembly_ref.name().to_string()
+}
+
+#[test]
+fn namespace() {
+    _ = std::fs::remove_dir_all("tests/split/namespace");
+    run_split("tests/split/namespace/Test.winmd", "split=namespace");
+
+    let test = read("tests/split/namespace/Test.winmd");
+    let geometry = read("tests/split/namespace/Test.Geometry.winmd");
+    assert_eq!(type_defs(&test), ["Test.Rect"]);
+    assert_eq!(type_defs(&geometry), ["Test.Geometry.Point"]);
+    assert_eq!(
+        type_ref_scope(&test, "Test.Geometry", "Point"),
+        "Test.Geometry"
+    );
+
+    // The pieces resolve against each other when read together.
+    let reader = Reader::new(vec![test, geometry]);
+    let def = reader.get_type_def("Test", "Rect").next().unwrap();
+    let field = def.fields().next().unwrap();
+    let Type::TypeDef(point, _) = field.ty(Some(def)) else {
+        panic!("TypeDef expected");
+    };
+    assert_eq!(point.type_name(), TypeName::new("Test.Geometry", "Point"));
+}
+
+#[test]
+fn mapping() {
+    _ = std::fs::remove_dir_all("tests/split/mapping");
+    run_split(
+        "tests/split/mapping/Test.winmd",
+        "split:Test.Geometry=Test.GeometryContract",
+    );
+
+    let test = read("tests/split/mapping/Test.winmd");
+    let geometry = read("tests/split/mapping/Test.GeometryContract.winmd");
+    assert_eq!(type_defs(&test), ["Test.Rect"]);
+    assert_eq!(type_defs(&geometry), ["Test.Geometry.Point"]);
+    assert_eq!(
+        type_ref_scope(&test, "Test.Geometry", "Point"),
+        "Test.GeometryContract"
+    );
+}
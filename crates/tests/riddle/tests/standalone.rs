@@ -0,0 +1,120 @@
+use test_riddle::{riddle, type_defs};
+use windows_metadata::*;
+
+#[test]
+fn types() {
+    assert!(riddle(
+        &["tests/standalone.rdl"],
+        "tests/standalone_filter.winmd",
+        &["Test.Root"],
+        &[],
+    )
+    .status
+    .success());
+    let file = File::new(std::fs::read("tests/standalone_filter.winmd").unwrap()).unwrap();
+    assert_eq!(type_defs(&file), ["Test.Root"]);
+
+    // The types that `Root` depends on, directly or indirectly, are included but `Unused` is not.
+    assert!(riddle(
+        &["tests/standalone.rdl"],
+        "tests/standalone_types.winmd",
+        &["Test.Root"],
+        &["standalone"],
+    )
+    .status
+    .success());
+    let file = File::new(std::fs::read("tests/standalone_types.winmd").unwrap()).unwrap();
+    let mut names = type_defs(&file);
+    names.sort();
+    assert_eq!(names, ["Test.Inner", "Test.Param", "Test.Root"]);
+}
+
+#[test]
+fn functions() {
+    let winmd = "tests/standalone_functions.winmd";
+    assert!(riddle(
+        &["../../libs/bindgen/default/Windows.Wdk.winmd"],
+        winmd,
+        &[
+            "Windows.Wdk.Storage.FileSystem.NtCreateFile",
+            "Windows.Wdk.Foundation.NTSTRSAFE_MAX_CCH",
+        ],
+        &["standalone"],
+    )
+    .status
+    .success());
+
+    let file = File::new(std::fs::read(winmd).unwrap()).unwrap();
+    let names = type_defs(&file);
+    assert!(names.contains(&"Windows.Wdk.Foundation.OBJECT_ATTRIBUTES".to_string()));
+    assert!(
+        names.contains(&"Windows.Wdk.Storage.FileSystem.NTCREATEFILE_CREATE_OPTIONS".to_string())
+    );
+    assert!(!names.contains(&"Windows.Wdk.Storage.FileSystem.FILE_INFORMATION_CLASS".to_string()));
+
+    let reader = Reader::new(vec![file]);
+
+    // Only the selected functions and constants are written to the `Apis` classes.
+    let (method, _) = reader
+        .get_method_def("Windows.Wdk.Storage.FileSystem", "NtCreateFile")
+        .next()
+        .unwrap();
+    let impl_map = method.impl_map().unwrap();
+    assert_eq!(impl_map.scope().name(), "ntdll.dll");
+    assert_eq!(impl_map.import_name(), "NtCreateFile");
+    assert_eq!(method.parent().methods().count(), 1);
+
+    let fields: Vec<_> = reader
+        .namespace_items("Windows.Wdk.Foundation")
+        .filter_map(|item| match item {
+            Item::Const(field) => Some(field),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(fields.len(), 1);
+    assert_eq!(fields[0].name(), "NTSTRSAFE_MAX_CCH");
+    assert!(matches!(
+        fields[0].constant().unwrap().value(),
+        Value::U32(2147483647)
+    ));
+}
+
+#[test]
+fn attributes() {
+    let winmd = "tests/standalone_attributes.winmd";
+    assert!(riddle(
+        &["tests/standalone_attributes.rdl"],
+        winmd,
+        &["Test.Root"],
+        &["standalone"],
+    )
+    .status
+    .success());
+
+    // The attribute applied to `Root` and the enum that its argument refers to are included but `Unused` is not.
+    let file = File::new(std::fs::read(winmd).unwrap()).unwrap();
+    let mut names = type_defs(&file);
+    names.sort();
+    assert_eq!(
+        names,
+        [
+            "Test.Root",
+            "Windows.Win32.Foundation.Metadata.Architecture",
+            "Windows.Win32.Foundation.Metadata.SupportedArchitectureAttribute"
+        ]
+    );
+
+    let reader = Reader::new(vec![file]);
+    let def = reader.get_type_def("Test", "Root").next().unwrap();
+    let args = def
+        .find_attribute("SupportedArchitectureAttribute")
+        .unwrap()
+        .args();
+
+    let [("", Value::EnumDef(architecture, value))] = args.as_slice() else {
+        panic!("Value not found");
+    };
+
+    assert_eq!(architecture.name(), "Architecture");
+    assert!(matches!(**value, Value::I32(2)));
+}
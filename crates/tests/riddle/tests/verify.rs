@@ -0,0 +1,91 @@
+use test_riddle::riddle;
+use windows_metadata::*;
+
+#[test]
+fn errors() {
+    let output = riddle(
+        &["tests/verify_errors.rdl"],
+        "tests/verify_errors.winmd",
+        &["Test"],
+        &[],
+    );
+    assert!(!output.status.success());
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("invalid metadata"));
+    assert!(stderr.contains("duplicate field `Test.Duplicate.value`"));
+    assert!(stderr.contains("WinRT struct field `Test.Pointer.value` can't be a pointer"));
+}
+
+#[test]
+fn warnings() {
+    // An interface without a `#[guid]` attribute has no GUID, which is a warning that isn't reported by default.
+    let output = riddle(
+        &["tests/verify_warnings.rdl"],
+        "tests/verify_warnings.winmd",
+        &["Test"],
+        &[],
+    );
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(!stdout.contains("warning:"));
+
+    let output = riddle(
+        &["tests/verify_warnings.rdl"],
+        "tests/verify_warnings.winmd",
+        &["Test"],
+        &["warnings"],
+    );
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("warning: `Test.IMissing` has no GUID"));
+    assert!(!stdout.contains("`Test.IPresent` has no GUID"));
+
+    // Strict mode treats warnings as errors.
+    let output = riddle(
+        &["tests/verify_warnings.rdl"],
+        "tests/verify_warnings_strict.winmd",
+        &["Test"],
+        &["strict"],
+    );
+    assert!(!output.status.success());
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("`Test.IMissing` has no GUID"));
+}
+
+#[test]
+fn strict() {
+    // Metadata without warnings passes strict mode.
+    let output = riddle(
+        &["tests/verify_strict.rdl"],
+        "tests/verify_strict.winmd",
+        &["Test"],
+        &["strict"],
+    );
+    assert!(output.status.success());
+
+    let file = File::new(std::fs::read("tests/verify_strict.winmd").unwrap()).unwrap();
+    let reader = Reader::new(vec![file]);
+    let def = reader.get_type_def("Test", "IPresent").next().unwrap();
+    let args = def.find_attribute("GuidAttribute").unwrap().args();
+    assert!(matches!(args[0], ("", Value::U32(0x8a4d1c2e))));
+    assert!(matches!(args[10], ("", Value::U8(0x5d))));
+
+    // The GUID is written back to .rdl as well.
+    assert!(riddle(
+        &["tests/verify_strict.winmd"],
+        "tests/verify_strict_out.rdl",
+        &["Test"],
+        &["type=winrt"],
+    )
+    .status
+    .success());
+
+    assert_eq!(
+        std::fs::read_to_string("tests/verify_strict_out.rdl").unwrap(),
+        std::fs::read_to_string("tests/verify_strict.rdl").unwrap()
+    );
+}
@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use windows::{core::*, Win32::Foundation::*};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+struct Config {
+    name: HSTRING,
+    description: BSTR,
+    clsid: GUID,
+    status: HRESULT,
+}
+
+#[test]
+fn round_trip() {
+    let config = Config {
+        name: "name".into(),
+        description: "description".into(),
+        clsid: GUID::from_u128(0x1fd63fef_c0d2_42fe_823a_53a4052b8c8f),
+        status: E_INVALIDARG,
+    };
+
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(
+        json,
+        r#"{"name":"name","description":"description","clsid":"1FD63FEF-C0D2-42FE-823A-53A4052B8C8F","status":-2147024809}"#
+    );
+
+    let back: Config = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, config);
+}
+
+#[test]
+fn guid() {
+    let guid: GUID = serde_json::from_str(r#""1fd63fef-c0d2-42fe-823a-53a4052b8c8f""#).unwrap();
+    assert_eq!(
+        guid,
+        GUID::from_u128(0x1fd63fef_c0d2_42fe_823a_53a4052b8c8f)
+    );
+
+    assert!(serde_json::from_str::<GUID>(r#""1FD63FEF-C0D2-42FE-823A""#).is_err());
+    assert!(serde_json::from_str::<GUID>(r#""1FD63FEF+C0D2-42FE-823A-53A4052B8C8F""#).is_err());
+    assert!(serde_json::from_str::<GUID>(r#""XFD63FEF-C0D2-42FE-823A-53A4052B8C8F""#).is_err());
+    assert!(serde_json::from_str::<GUID>("123").is_err());
+
+    assert_eq!(
+        "1FD63FEF-C0D2-42FE-823A-53A4052B8C8F"
+            .parse::<GUID>()
+            .unwrap(),
+        guid
+    );
+    assert_eq!("invalid".parse::<GUID>().unwrap_err().code(), E_INVALIDARG);
+}
+
+#[test]
+fn strings() {
+    let value: HSTRING = serde_json::from_str(r#""α & ω""#).unwrap();
+    assert_eq!(value, "α & ω");
+    assert_eq!(serde_json::to_string(&value).unwrap(), r#""α & ω""#);
+    assert_eq!(serde_json::to_string(&HSTRING::new()).unwrap(), r#""""#);
+
+    // Unpaired surrogates cannot be represented.
+    let invalid = HSTRING::from_wide(&[0xD834]).unwrap();
+    assert!(serde_json::to_string(&invalid).is_err());
+}
+
+#[test]
+fn hash() {
+    let mut map = HashMap::new();
+    map.insert(HSTRING::from("one"), 1);
+    map.insert(HSTRING::from("two"), 2);
+    assert_eq!(map[&HSTRING::from("one")], 1);
+    assert_eq!(map.get(h!("two")), Some(&2));
+
+    let mut map = HashMap::new();
+    map.insert(BSTR::from("one"), 1);
+    assert_eq!(map[&BSTR::from("one")], 1);
+    assert_eq!(map.get(&BSTR::new()), None);
+}
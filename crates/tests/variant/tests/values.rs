@@ -0,0 +1,204 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use windows::Win32::Foundation::{E_FAIL, TYPE_E_TYPEMISMATCH};
+use windows_core::*;
+
+#[test]
+fn currency() -> Result<()> {
+    let value = Currency(123_4567);
+    assert_eq!(f64::from(value), 123.4567);
+
+    let v = VARIANT::from(value);
+    assert_eq!(Currency::try_from(&v)?, value);
+    assert_eq!(v.to_value()?, VariantValue::Currency(value));
+
+    let pv = PROPVARIANT::from(value);
+    assert_eq!(Currency::try_from(&pv)?, value);
+    assert_eq!(pv.to_value()?, VariantValue::Currency(value));
+
+    Ok(())
+}
+
+#[test]
+fn date() -> Result<()> {
+    assert_eq!(Date::from(UNIX_EPOCH), Date(25569.0));
+    assert_eq!(
+        SystemTime::try_from(Date(25569.5))?,
+        UNIX_EPOCH + Duration::from_secs(43200)
+    );
+
+    // 29 December 1899 at 06:00 is stored as -1.25 rather than -0.75.
+    let before = UNIX_EPOCH - Duration::from_secs(25570 * 86400 - 6 * 3600);
+    assert_eq!(Date::from(before), Date(-1.25));
+    assert_eq!(SystemTime::try_from(Date(-1.25))?, before);
+
+    let now = UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+    assert_eq!(SystemTime::try_from(Date::from(now))?, now);
+    assert_eq!(
+        SystemTime::try_from(Date(f64::NAN)).unwrap_err().code(),
+        windows::Win32::Foundation::E_INVALIDARG
+    );
+
+    let v = VARIANT::from(Date(45000.25));
+    assert_eq!(Date::try_from(&v)?, Date(45000.25));
+    assert_eq!(
+        PROPVARIANT::from(Date(45000.25)).to_value()?,
+        VariantValue::Date(Date(45000.25))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn decimal() -> Result<()> {
+    let value = Decimal::new(-12345, 3)?;
+    assert_eq!(value.mantissa(), -12345);
+    assert_eq!(value.scale(), 3);
+    assert_eq!(value.to_string(), "-12.345");
+    assert_eq!(Decimal::new(5, 3)?.to_string(), "0.005");
+    assert_eq!(Decimal::new(42, 0)?.to_string(), "42");
+    assert_eq!(f64::from(value), -12.345);
+
+    assert!(Decimal::new(1 << 96, 0).is_err());
+    assert!(Decimal::new(1, 29).is_err());
+
+    let max = Decimal::new((1 << 96) - 1, 28)?;
+    assert_eq!(Decimal::try_from(&VARIANT::from(max))?, max);
+
+    let v = VARIANT::from(value);
+    assert_eq!(Decimal::try_from(&v)?, value);
+    assert_eq!(v.to_value()?, VariantValue::Decimal(value));
+
+    let pv = PROPVARIANT::from(value);
+    assert_eq!(Decimal::try_from(&pv)?, value);
+
+    Ok(())
+}
+
+#[test]
+fn error() -> Result<()> {
+    let v = VARIANT::from(E_FAIL);
+    assert_eq!(HRESULT::try_from(&v)?, E_FAIL);
+    assert_eq!(v.to_value()?, VariantValue::Error(E_FAIL));
+
+    let pv = PROPVARIANT::from(E_FAIL);
+    assert_eq!(HRESULT::try_from(&pv)?, E_FAIL);
+
+    assert_eq!(
+        HRESULT::try_from(&VARIANT::from(1i32)).unwrap_err().code(),
+        TYPE_E_TYPEMISMATCH
+    );
+
+    Ok(())
+}
+
+#[test]
+fn safe_array() -> Result<()> {
+    let v = VARIANT::from(vec![1i32, 2, 3]);
+    assert_eq!(Vec::<i32>::try_from(&v)?, [1, 2, 3]);
+    assert_eq!(
+        v.to_value()?,
+        VariantValue::Array(vec![
+            VariantValue::I32(1),
+            VariantValue::I32(2),
+            VariantValue::I32(3)
+        ])
+    );
+    assert_eq!(
+        Vec::<u32>::try_from(&v).unwrap_err().code(),
+        TYPE_E_TYPEMISMATCH
+    );
+
+    let v = VARIANT::from(&[1.5f64, 2.5][..]);
+    assert_eq!(Vec::<f64>::try_from(&v)?, [1.5, 2.5]);
+
+    let v = VARIANT::from(vec![true, false]);
+    assert_eq!(Vec::<bool>::try_from(&v)?, [true, false]);
+
+    let v = VARIANT::from(vec![BSTR::from("hello"), BSTR::from("world")]);
+    assert_eq!(
+        Vec::<BSTR>::try_from(&v)?,
+        [BSTR::from("hello"), BSTR::from("world")]
+    );
+    assert_eq!(v.clone(), v);
+
+    let v = VARIANT::from(Vec::<u8>::new());
+    assert!(Vec::<u8>::try_from(&v)?.is_empty());
+
+    let v = VARIANT::from(vec![
+        VARIANT::from(1u8),
+        VARIANT::from("two"),
+        VARIANT::from(vec![3i16]),
+    ]);
+    assert_eq!(
+        v.to_value()?,
+        VariantValue::Array(vec![
+            VariantValue::U8(1),
+            VariantValue::String("two".into()),
+            VariantValue::Array(vec![VariantValue::I16(3)])
+        ])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn vector() -> Result<()> {
+    let pv = PROPVARIANT::from(vec![1u64, 2, 3]);
+    assert_eq!(Vec::<u64>::try_from(&pv)?, [1, 2, 3]);
+    assert_eq!(
+        pv.to_value()?,
+        VariantValue::Array(vec![
+            VariantValue::U64(1),
+            VariantValue::U64(2),
+            VariantValue::U64(3)
+        ])
+    );
+    assert_eq!(
+        Vec::<i64>::try_from(&pv).unwrap_err().code(),
+        TYPE_E_TYPEMISMATCH
+    );
+
+    let pv = PROPVARIANT::from(vec![BSTR::from("hello")]);
+    assert_eq!(Vec::<BSTR>::try_from(&pv)?, [BSTR::from("hello")]);
+    assert_eq!(pv.clone(), pv);
+
+    let pv = PROPVARIANT::from(Vec::<f32>::new());
+    assert!(Vec::<f32>::try_from(&pv)?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn value_round_trip() -> Result<()> {
+    let values = [
+        VariantValue::Empty,
+        VariantValue::Null,
+        VariantValue::Bool(true),
+        VariantValue::I8(-8),
+        VariantValue::U8(8),
+        VariantValue::I16(-16),
+        VariantValue::U16(16),
+        VariantValue::I32(-32),
+        VariantValue::U32(32),
+        VariantValue::I64(-64),
+        VariantValue::U64(64),
+        VariantValue::F32(3.5),
+        VariantValue::F64(-2.25),
+        VariantValue::Currency(Currency(10_000)),
+        VariantValue::Date(Date(45000.5)),
+        VariantValue::Decimal(Decimal::new(31415, 4)?),
+        VariantValue::Error(E_FAIL),
+        VariantValue::String("hello".into()),
+        VariantValue::Array(vec![
+            VariantValue::I32(1),
+            VariantValue::String("two".into()),
+        ]),
+    ];
+
+    for value in values {
+        assert_eq!(VARIANT::from(value.clone()).to_value()?, value);
+        assert_eq!(PROPVARIANT::from(value.clone()).to_value()?, value);
+    }
+
+    Ok(())
+}